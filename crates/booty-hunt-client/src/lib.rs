@@ -0,0 +1,122 @@
+//! Thin async wrapper around the Booty Hunt server HTTP API. Retries
+//! idempotent requests with backoff and stamps mutating requests with an
+//! idempotency key, so callers (the game client, community tools) stop
+//! hand-rolling `reqwest` calls against undocumented JSON.
+
+use std::time::Duration;
+
+use booty_hunt_core::{
+    EquipCosmeticRequest, InventoryEntry, LeaderboardEntry, RunSubmission, RunSubmissionResult,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("server returned an error status: {0}")]
+    Status(reqwest::StatusCode),
+}
+
+pub struct Client {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+const MAX_RETRIES: u32 = 3;
+
+impl Client {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Client { http: reqwest::Client::new(), base_url: base_url.into() }
+    }
+
+    pub async fn submit_run(&self, submission: &RunSubmission) -> Result<RunSubmissionResult, ClientError> {
+        let idempotency_key = uuid::Uuid::new_v4().to_string();
+        self.post_with_retry("/api/runs", submission, &idempotency_key).await
+    }
+
+    pub async fn get_leaderboard(&self, week_key: Option<&str>) -> Result<Vec<LeaderboardEntry>, ClientError> {
+        let mut url = format!("{}/api/leaderboard", self.base_url);
+        if let Some(week_key) = week_key {
+            url = format!("{url}?week_key={week_key}");
+        }
+        self.get_with_retry(&url).await
+    }
+
+    pub async fn list_cosmetics(&self, player_id: &str) -> Result<Vec<InventoryEntry>, ClientError> {
+        let url = format!("{}/api/players/{player_id}/cosmetics", self.base_url);
+        self.get_with_retry(&url).await
+    }
+
+    pub async fn equip_cosmetic(&self, player_id: &str, item_id: &str) -> Result<(), ClientError> {
+        let url = format!("{}/api/players/{player_id}/cosmetics/equip", self.base_url);
+        let idempotency_key = uuid::Uuid::new_v4().to_string();
+        let body = EquipCosmeticRequest { item_id: item_id.to_string() };
+        let _: serde_json::Value = self.put_with_retry(&url, &body, &idempotency_key).await?;
+        Ok(())
+    }
+
+    async fn get_with_retry<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, ClientError> {
+        self.with_retry(|| self.http.get(url)).await
+    }
+
+    async fn post_with_retry<B: serde::Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+        idempotency_key: &str,
+    ) -> Result<T, ClientError> {
+        let url = format!("{}{path}", self.base_url);
+        self.with_retry(|| {
+            self.http
+                .post(&url)
+                .header("Idempotency-Key", idempotency_key)
+                .json(body)
+        })
+        .await
+    }
+
+    async fn put_with_retry<B: serde::Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &B,
+        idempotency_key: &str,
+    ) -> Result<T, ClientError> {
+        self.with_retry(|| {
+            self.http
+                .put(url)
+                .header("Idempotency-Key", idempotency_key)
+                .json(body)
+        })
+        .await
+    }
+
+    /// Retries transport errors and 5xx responses with exponential backoff.
+    /// 4xx responses are not retried since the request is assumed malformed.
+    async fn with_retry<T: serde::de::DeserializeOwned>(
+        &self,
+        make_request: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<T, ClientError> {
+        let mut attempt = 0;
+        loop {
+            let result = make_request().send().await;
+            match result {
+                Ok(response) if response.status().is_server_error() && attempt < MAX_RETRIES => {
+                    attempt += 1;
+                    tokio::time::sleep(Duration::from_millis(100 * 2u64.pow(attempt))).await;
+                    continue;
+                }
+                Ok(response) if !response.status().is_success() => {
+                    return Err(ClientError::Status(response.status()));
+                }
+                Ok(response) => return Ok(response.json::<T>().await?),
+                Err(err) if attempt < MAX_RETRIES => {
+                    attempt += 1;
+                    let _ = err;
+                    tokio::time::sleep(Duration::from_millis(100 * 2u64.pow(attempt))).await;
+                    continue;
+                }
+                Err(err) => return Err(ClientError::from(err)),
+            }
+        }
+    }
+}
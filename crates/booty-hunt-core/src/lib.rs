@@ -0,0 +1,1109 @@
+//! Serde models shared between `booty-hunt-server`, the Rust client SDK, and
+//! any other consumer of the HTTP API. Keeping these in one crate means the
+//! server and its clients can never drift on field names or types the way
+//! hand-copied structs used to.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSubmission {
+    pub player_id: String,
+    pub seed: i64,
+    pub ship_class: String,
+    pub doctrine_id: String,
+    pub score: i64,
+    pub waves: i64,
+    pub damage_dealt: i64,
+    pub max_combo: i64,
+    pub time_played: i64,
+    pub max_heat: i64,
+    pub victory: bool,
+    /// Base64-encoded ghost tape event stream. Optional so clients can retry a
+    /// submission without the tape and attach it later.
+    pub ghost_tape: Option<String>,
+    /// Lowercase hex SHA-256 of the decoded `ghost_tape` bytes, checked
+    /// server-side after decode so a truncated or corrupted upload is
+    /// rejected instead of silently accepted. Optional like `ghost_tape`
+    /// itself — omit it (or `ghost_tape`) and the server just doesn't have
+    /// anything to verify against.
+    pub ghost_tape_sha256: Option<String>,
+    /// Per-wave time/score checkpoints, in wave order. Optional — older
+    /// clients and non-speedrun submissions simply omit it.
+    pub splits: Option<Vec<WaveSplit>>,
+    /// Submits this run against a server-defined ruleset's own leaderboard
+    /// instead of the standard weekly one. `None` is the standard board.
+    pub ruleset_id: Option<String>,
+    /// Tags this run as an entry in a specific concurrent regatta track
+    /// (e.g. the sloop sprint vs. the galleon marathon) rather than the
+    /// week's default board. `None` is the standard board.
+    pub regatta_id: Option<String>,
+    /// The run whose ghost tape this run raced, if any — lets the client
+    /// opt into the "beat the ghost" meta-game by reporting which download
+    /// it loaded before the run started.
+    pub raced_run_id: Option<String>,
+    /// A single-use, expiring nonce obtained from a prior nonce-issuance
+    /// request for this `player_id`/`seed`, proving the client actually
+    /// started a session for this seed before submitting a result rather
+    /// than fabricating one from a script. Required only when the server has
+    /// `Config::submission_nonce_required` enabled; omitted, it's ignored.
+    pub submission_nonce: Option<String>,
+}
+
+/// A single run's public detail view: the base leaderboard fields plus its
+/// ghost-race ancestry — whether it raced a ghost itself, and how many
+/// other runs have raced *this* run's ghost since.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunDetail {
+    pub run_id: String,
+    pub player_id: String,
+    pub ship_class: String,
+    pub doctrine_id: String,
+    pub score: i64,
+    pub victory: bool,
+    pub created_at: String,
+    pub raced_run_id: Option<String>,
+    /// `None` if `raced_run_id` is `None`; otherwise whether this run's
+    /// score beat the ghost it raced.
+    pub beat_ghost: Option<bool>,
+    /// How many runs have raced this run's ghost.
+    pub ghost_races_count: i64,
+    /// Of those, how many beat it.
+    pub ghost_beats_count: i64,
+    /// This run's moderation appeal, if the player has ever filed one — see
+    /// `appeal_service`. `None` if the run was never hidden or was hidden
+    /// but never appealed.
+    pub appeal: Option<RunAppeal>,
+    /// Omen ids that were active when this run was submitted — see
+    /// `run_service::submit_run`'s modifier snapshot.
+    pub modifier_omen_ids: Vec<String>,
+    /// Community event ids whose window was open when this run was
+    /// submitted.
+    pub modifier_event_ids: Vec<String>,
+    /// The overall tuning version in effect when this run was submitted —
+    /// see `tuning_service::current_version_conn`.
+    pub modifier_tuning_version: i64,
+    /// A freshly-issued, short-lived signed URL for this run's ghost tape —
+    /// see `ghost_signed_url_service::issue`. Reissued on every fetch of
+    /// this detail view rather than stored, so it's never stale.
+    pub ghost_url: SignedGhostUrl,
+}
+
+/// A time-boxed, HMAC-signed download link for a ghost tape — see
+/// `ghost_signed_url_service`. `url` embeds its own expiry and signature, so
+/// whatever ends up serving the bytes (this server today, a CDN or blob
+/// store later) can validate access without a database round trip.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedGhostUrl {
+    pub url: String,
+    pub expires_at: String,
+}
+
+/// Status of a `RunAppeal` — see `appeal_service::resolve_appeal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppealStatus {
+    /// Filed, awaiting a moderator's decision.
+    Pending,
+    /// A moderator reviewed the appeal and the hide stands.
+    Upheld,
+    /// A moderator reviewed the appeal and un-hid the run.
+    Reinstated,
+}
+
+/// A player's statement contesting a hidden run, and its review status —
+/// see `appeal_service`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunAppeal {
+    pub run_id: String,
+    pub player_id: String,
+    pub statement: String,
+    pub status: AppealStatus,
+    pub created_at: String,
+    pub resolved_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubmitAppealRequest {
+    pub player_id: String,
+    pub statement: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResolveAppealRequest {
+    pub status: AppealStatus,
+}
+
+/// A server-defined challenge ruleset: a set of constraints a run must
+/// satisfy to count for that ruleset's leaderboard, generalizing one-off
+/// daily/weekly/lobby modes into a single table and query surface.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ruleset {
+    pub id: String,
+    pub name: String,
+    /// Rejects submissions with no ghost tape — used for rulesets where
+    /// replay verification matters more than usual (e.g. record attempts).
+    pub require_ghost_tape: bool,
+    /// Only runs on this ship class count, if set.
+    pub ship_class_lock: Option<String>,
+    /// Only runs on this doctrine count, if set.
+    pub doctrine_lock: Option<String>,
+    /// Advisory tide omen id a client should apply for this ruleset's runs
+    /// to be comparable; not enforced server-side until omens are tracked
+    /// per submission.
+    pub omen_override: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CreateRulesetRequest {
+    pub name: String,
+    #[serde(default)]
+    pub require_ghost_tape: bool,
+    #[serde(default)]
+    pub ship_class_lock: Option<String>,
+    #[serde(default)]
+    pub doctrine_lock: Option<String>,
+    #[serde(default)]
+    pub omen_override: Option<String>,
+}
+
+/// An admin-scheduled limited-time event — a banner and a bag of
+/// client-defined modifiers active only between `starts_at` and `ends_at`.
+/// Unlike weekly omens, `modifiers` is opaque JSON the server never
+/// interprets — see `community_event_service::active`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityEvent {
+    pub id: String,
+    pub name: String,
+    pub banner_text: String,
+    pub modifiers: serde_json::Value,
+    pub starts_at: String,
+    pub ends_at: String,
+    /// Cosmetic item id granted to every participant once the event ends.
+    /// `None` runs the event with no commemorative reward.
+    pub reward_item_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateCommunityEventRequest {
+    pub name: String,
+    pub banner_text: String,
+    pub modifiers: serde_json::Value,
+    pub starts_at: String,
+    pub ends_at: String,
+    #[serde(default)]
+    pub reward_item_id: Option<String>,
+}
+
+/// One event a player submitted a qualifying run during — "qualifying"
+/// just means "submitted while the event's window was open"; see
+/// `community_event_service::record_participation`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventParticipation {
+    pub event_id: String,
+    pub event_name: String,
+    pub run_id: String,
+    pub created_at: String,
+}
+
+/// How prominently a `NewsItem` should be surfaced client-side — the server
+/// doesn't interpret this beyond storing and returning it, same as
+/// `CommunityEvent::modifiers`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NewsSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+/// An admin-authored in-game news/MOTD entry — maintenance notices, event
+/// callouts, that sort of thing. `body` is free-form (markdown or
+/// structured blocks are both just opaque text to the server, same as
+/// `CommunityEvent::modifiers` is opaque JSON). Only visible via
+/// `GET /api/news` while `publish_at <= now` and (if set) `now < expires_at`
+/// — see `news_service::active`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsItem {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub severity: NewsSeverity,
+    pub publish_at: String,
+    pub expires_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateNewsItemRequest {
+    pub title: String,
+    pub body: String,
+    pub severity: NewsSeverity,
+    pub publish_at: String,
+    #[serde(default)]
+    pub expires_at: Option<String>,
+}
+
+/// Full-resource replacement for a `NewsItem`, same shape as
+/// `CreateNewsItemRequest` — mirrors `PUT /api/runs/:run_id/ghost`'s
+/// full-replace convention rather than a partial patch.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateNewsItemRequest {
+    pub title: String,
+    pub body: String,
+    pub severity: NewsSeverity,
+    pub publish_at: String,
+    #[serde(default)]
+    pub expires_at: Option<String>,
+}
+
+/// One balance constant a client reads at startup (enemy HP multipliers,
+/// loot rates, ...) — opaque `serde_json::Value` on the server side, same
+/// as `CommunityEvent::modifiers`, since the meaning of any given key is
+/// entirely a client-side concern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuningValue {
+    pub key: String,
+    pub value: serde_json::Value,
+    pub version: i64,
+    pub updated_at: String,
+}
+
+/// `GET /api/tuning`'s response — every current key/value plus an overall
+/// `version` (the highest per-key version among them) a client can compare
+/// against what it last cached before re-parsing the whole set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuningSnapshot {
+    pub version: i64,
+    pub values: Vec<TuningValue>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetTuningValueRequest {
+    pub value: serde_json::Value,
+}
+
+/// One past value a tuning key held, for `GET /api/admin/tuning/:key/history`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuningHistoryEntry {
+    pub value: serde_json::Value,
+    pub version: i64,
+    pub changed_at: String,
+}
+
+/// Everything needed to reproduce or dispute a run in one document — for
+/// `GET /api/runs/:run_id/bundle`, so a bug report or tournament dispute
+/// doesn't require a back-and-forth to collect the seed, the tape, and the
+/// submission's own numbers separately.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunBundle {
+    pub run_id: String,
+    pub player_id: String,
+    pub week_key: String,
+    pub seed: i64,
+    pub ship_class: String,
+    pub doctrine_id: String,
+    pub score: i64,
+    pub waves: i64,
+    pub damage_dealt: i64,
+    pub max_combo: i64,
+    pub time_played: i64,
+    pub max_heat: i64,
+    pub victory: bool,
+    pub created_at: String,
+    pub ruleset_id: Option<String>,
+    pub regatta_id: Option<String>,
+    pub splits: Option<Vec<WaveSplit>>,
+    /// Base64-encoded ghost tape event stream, `None` if this run has no
+    /// tape attached.
+    pub ghost_tape_base64: Option<String>,
+    pub ghost_tape_sha256: Option<String>,
+}
+
+/// One wave's checkpoint within a run: elapsed time since run start and the
+/// score total as of clearing that wave.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaveSplit {
+    pub wave: i64,
+    pub time_ms: i64,
+    pub score: i64,
+}
+
+/// The fastest recorded split for a single wave on a seed, and which run set
+/// it — used to build the "sum of best" theoretical run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BestSplitEntry {
+    pub wave: i64,
+    pub time_ms: i64,
+    pub run_id: String,
+    pub player_name: String,
+}
+
+/// Best-known split for every wave of a seed, plus their sum — the
+/// theoretical fastest possible clear if one run hit every best segment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SumOfBest {
+    pub seed: i64,
+    pub splits: Vec<BestSplitEntry>,
+    pub sum_of_best_ms: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSubmissionResult {
+    pub run_id: String,
+    pub rank: i64,
+    pub week_key: String,
+    /// Set when score recomputation is on and the server-recomputed score
+    /// from the ghost tape didn't match the client-reported score.
+    pub score_mismatch: bool,
+    /// Pass as `since_token` to `/api/leaderboard` to guarantee the response
+    /// reflects this submission.
+    pub consistency_token: u64,
+    /// Server-signed proof of acceptance over `(run_id, score, week_key)`.
+    /// Hand this to a third-party tournament organizer along with those
+    /// three values; they confirm it against `POST /api/receipts/verify`
+    /// without needing any other access to this server.
+    pub receipt: String,
+}
+
+/// Body of `PUT /api/runs/:run_id/ghost`, for attaching a ghost tape after
+/// the run itself was already accepted. `receipt` is the one that run's
+/// `RunSubmissionResult` returned, standing in for a submission token so a
+/// client can retry a failed tape upload without re-authenticating as the
+/// player.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachGhostTapeRequest {
+    pub receipt: String,
+    /// Base64-encoded, same encoding as `RunSubmission::ghost_tape`.
+    pub ghost_tape: String,
+    /// Same meaning and verification as `RunSubmission::ghost_tape_sha256`.
+    pub ghost_tape_sha256: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachGhostTapeResult {
+    pub score_mismatch: bool,
+}
+
+/// Body of `POST /api/runs/nonce`. Requested before a client starts a run,
+/// naming the seed it's about to play; the returned nonce is echoed back as
+/// `RunSubmission::submission_nonce` when that run is submitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueNonceRequest {
+    pub player_id: String,
+    pub seed: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueNonceResponse {
+    pub submission_nonce: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationViolation {
+    /// The `RunSubmission` field the violation is about, e.g. `"ghost_tape"`
+    /// or `"ruleset_id"` — not necessarily a 1:1 map to a single check, since
+    /// one field can fail more than one way.
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationReport {
+    pub valid: bool,
+    /// Every violation found, not just the first — unlike `POST /api/runs`,
+    /// which stops at whichever check fails first since it has to reject the
+    /// request anyway.
+    pub violations: Vec<ValidationViolation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub rank: i64,
+    pub run_id: String,
+    pub player_id: String,
+    pub player_name: String,
+    pub ship_class: String,
+    pub score: i64,
+    pub victory: bool,
+    pub created_at: String,
+    /// Item ids equipped at submission time, keyed by slot, so ghosts render
+    /// with the flag/figurehead the player had equipped when they set the score.
+    pub equipped_cosmetics: HashMap<String, String>,
+    /// Coarse region the run was submitted from, if geo derivation was on.
+    pub region: Option<String>,
+    /// Count of non-hidden kudos this run has received.
+    pub kudos_count: i64,
+    /// Raw score scaled by the submitting ship class's coefficient. Ranks the
+    /// `unified` leaderboard category; equal to `score` when no multiplier is
+    /// configured for the class.
+    pub normalized_score: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardResponse {
+    /// Pass back as `since_version` on the next request to get a delta
+    /// against this response instead of the full board.
+    pub version: u64,
+    /// `false` means `entries` is only what changed since `since_version`
+    /// (new or re-ranked entries) and `removed_run_ids` lists whoever fell
+    /// out of the tracked window — apply both to the client's cached copy of
+    /// the board rather than replacing it. `true` means `entries` is the
+    /// whole board, either because no `since_version` was given or because
+    /// the server no longer has history back that far.
+    pub full: bool,
+    pub entries: Vec<LeaderboardEntry>,
+    /// Run ids that were in the client's last-seen window but no longer are.
+    /// Always empty when `full` is `true`.
+    pub removed_run_ids: Vec<String>,
+    /// Seconds the server would like the client to wait before polling
+    /// `/api/leaderboard` again. The official client treats this as a floor,
+    /// not a suggestion — it's how the server can back clients off without a
+    /// version bump.
+    pub poll_interval_hint_secs: u64,
+}
+
+/// One entry in the skill-rating leaderboard. The rating itself is an
+/// incrementally-updated Elo score derived from same-seed head-to-head
+/// comparisons, not from raw score, so it stays meaningful across ship
+/// classes and doctrines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatingEntry {
+    pub rank: i64,
+    pub player_id: String,
+    pub player_name: String,
+    pub rating: f64,
+}
+
+/// A player's public profile: identity plus derived stats that don't belong
+/// on every leaderboard row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    pub player_id: String,
+    pub display_name: String,
+    pub rating: f64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GiveKudosRequest {
+    pub player_id: String,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AttachBottleNoteRequest {
+    pub player_id: String,
+    pub text: String,
+}
+
+/// A short player-authored note attached to a seed, e.g. "beware wave 7
+/// kraken" — surfaced to other players who load the same seed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BottleNote {
+    pub id: i64,
+    pub seed: String,
+    pub player_id: String,
+    pub text: String,
+    pub created_at: String,
+    pub report_count: i64,
+}
+
+/// A finalized "last week at sea" summary for a single week, generated once
+/// by the scheduler at rollover and served read-only afterward.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyDigest {
+    pub week_key: String,
+    pub podium: Vec<LeaderboardEntry>,
+    /// Top low-heat ("stealth") clears of the week — a separate podium
+    /// since it ranks by ascending `max_heat` rather than score.
+    pub stealth_podium: Vec<LeaderboardEntry>,
+    /// Approximated as the number of victorious runs this week — there's no
+    /// per-engagement combat log to sum actual sinkings from yet.
+    pub ships_destroyed: i64,
+    /// Populated once regattas track expected-vs-actual placement; `None`
+    /// until then rather than a fabricated number.
+    pub biggest_upset: Option<String>,
+    pub generated_at: String,
+}
+
+/// The default board's final standings for a week, frozen by the scheduler
+/// shortly after rollover and never regenerated afterward — even if a
+/// clock-skewed or late-arriving submission lands for that `week_key` later,
+/// it can't change ranks that rewards have already been paid out against.
+/// Unlike `WeeklyDigest`'s top-3 podium, this covers the whole ranked board.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalizedLeaderboard {
+    pub week_key: String,
+    pub entries: Vec<LeaderboardEntry>,
+    pub finalized_at: String,
+}
+
+/// One entry in the "most watched replays this week" list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PopularReplay {
+    pub run_id: String,
+    pub player_name: String,
+    pub ship_class: String,
+    pub score: i64,
+    pub download_count: i64,
+}
+
+/// A player's own run, as returned to community tools authenticated with a
+/// read-only API key — a trimmed view of `runs` with no other player's data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerRunSummary {
+    pub run_id: String,
+    pub week_key: String,
+    pub ship_class: String,
+    pub score: i64,
+    pub victory: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CosmeticItem {
+    pub id: String,
+    pub slot: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryEntry {
+    pub item: CosmeticItem,
+    pub granted_at: String,
+    pub source: String,
+    pub equipped: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EquipCosmeticRequest {
+    pub item_id: String,
+}
+
+/// One bucket of `GET /api/stats/timeseries`, backed by the `hourly_stats`
+/// rollup rather than a live scan of `runs`. `bucket` is an hour
+/// (`2026-08-08T14:00:00Z`) or a day (`2026-08-08`) depending on the
+/// request's `interval`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeseriesPoint {
+    pub bucket: String,
+    pub submissions: i64,
+    pub victories: i64,
+    pub unique_players: i64,
+    pub redemptions: i64,
+}
+
+/// One accepted event kind on `POST /api/telemetry`, from the server-side
+/// catalog `Config::telemetry_event_schemas`. A submitted event's `payload`
+/// must include every key in `required_fields`; anything else in the
+/// payload is stored as-is and unchecked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetryEventSchema {
+    pub event_type: String,
+    pub required_fields: Vec<String>,
+}
+
+/// One bucket of `POST /api/telemetry/aggregate`'s grouped counts — e.g. one
+/// row per wave number for a `deaths_per_wave` query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetryAggregateBucket {
+    pub group_value: String,
+    pub event_count: i64,
+}
+
+/// A named A/B experiment over omen modifiers (or any other weekly variable
+/// designers want to test), from the server-side catalog `Config::experiments`.
+/// `variants` must have at least 2 entries; `assign` deterministically hashes
+/// a player into one of them per week.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentDefinition {
+    pub key: String,
+    pub description: String,
+    pub variants: Vec<String>,
+}
+
+/// Which variant of an experiment a player is assigned for a given week —
+/// stable for that player/week/experiment combination, since it's a pure
+/// hash of the three, not a stored coin flip.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentAssignment {
+    pub experiment_key: String,
+    pub week_key: String,
+    pub variant: String,
+}
+
+/// One variant's aggregate outcome data for an experiment/week, backing the
+/// admin report endpoint designers use to see which variant is winning.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperimentVariantReport {
+    pub variant: String,
+    pub sample_count: i64,
+    pub metric_sum: f64,
+    pub metric_avg: f64,
+}
+
+/// A weekly tide (weather/economy) modifier, published by
+/// `GET /api/tide/omens`. `name`/`description` are resolved server-side from
+/// the request's `Accept-Language` against an embedded translation catalog;
+/// `name_key`/`description_key` are the catalog keys those strings came from,
+/// so a client can re-render in a locale it fetched separately, or fall back
+/// to its own bundled strings if it doesn't like the server's pick.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TideOmen {
+    pub id: String,
+    pub name_key: String,
+    pub name: String,
+    pub description_key: String,
+    pub description: String,
+}
+
+/// One accepted metric on `/api/tide/contribute`, from the server-side
+/// catalog `GET /api/tide/metrics` describes. Lets clients discover valid
+/// metric strings, their units, and the current week's goal instead of
+/// guessing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TideMetricDefinition {
+    pub key: String,
+    pub label: String,
+    pub unit: String,
+    /// Largest amount one contribution call may report at once, to keep a
+    /// single bad or malicious submission from dominating the week's total.
+    pub per_contribution_cap: i64,
+    /// The community-wide total this metric is aiming for this week.
+    pub weekly_goal: i64,
+}
+
+/// Result of a single accepted contribution: the community's running total
+/// for that metric this week, so a client can render progress toward
+/// `TideMetricDefinition::weekly_goal` without a second request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TideContributionResult {
+    pub metric: String,
+    pub week_key: String,
+    pub week_total: i64,
+    pub weekly_goal: i64,
+}
+
+/// A redeemable signal fire code. Fleshed out fully alongside the signal fire
+/// redemption endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignalFire {
+    pub code: String,
+    pub aid_type: String,
+    pub aid_amount: i64,
+    pub redeemed: bool,
+}
+
+/// Aggregate redemption flow for one aid type in one week. Empty of any
+/// entries until signal fire redemption is tracked server-side — see
+/// `EconomyAudit`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AidTypeFlow {
+    pub aid_type: String,
+    pub redeemed_count: i64,
+    pub redeemed_amount: i64,
+}
+
+/// Redemption analytics for one bulk-minted campaign (a giveaway, a stream
+/// event), backing the admin-facing per-campaign report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignAnalytics {
+    pub campaign: String,
+    pub minted: i64,
+    pub redeemed: i64,
+    pub redeemed_amount: i64,
+}
+
+/// A player's standing offer to trade an unredeemed signal fire for one of a
+/// different aid type. Both codes sit in trade escrow (see
+/// `signal_fire_trade_service`) from the moment an offer is posted until it's
+/// accepted or cancelled, so neither side can redeem out from under the
+/// other mid-trade.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeOffer {
+    pub id: String,
+    pub offering_player_id: String,
+    pub offering_code: String,
+    pub offering_aid_type: String,
+    pub wanted_aid_type: String,
+    pub status: String,
+    pub accepted_by_player_id: Option<String>,
+    pub accepted_code: Option<String>,
+    pub created_at: String,
+    pub resolved_at: Option<String>,
+}
+
+/// Designer-facing view of the signal fire economy for one week, backing
+/// `GET /api/admin/economy`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EconomyAudit {
+    pub week_key: String,
+    /// Redemptions per aid type. Always empty today — signal fires can be
+    /// bulk-minted (see `signal_fire_service::mint_bulk`) but nothing
+    /// redeems them yet, only the wire format (`SignalFire`) and the
+    /// `SignalFireRedeemed` notification event exist so far. Populate this
+    /// once redemption lands.
+    pub aid_flows: Vec<AidTypeFlow>,
+    /// Sum of `runs.max_heat` for the week — the closest existing proxy for
+    /// "heat pressure" until a real heat-spend ledger exists to total.
+    pub heat_spent_total: i64,
+    /// Aid created but never redeemed before expiring. Always 0 until
+    /// signal fire generation exists to expire against.
+    pub aid_expiry_waste: i64,
+}
+
+/// The seed everyone races one week's regatta track on. Hash-derived from
+/// the week key and track by default; an operator can blacklist a
+/// degenerate one and roll a replacement via
+/// `POST /api/admin/regatta/:track/reroll`. Several tracks (e.g. a sloop
+/// sprint alongside a galleon marathon) run concurrently each week, each
+/// with its own seed and optional ruleset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Regatta {
+    pub id: String,
+    pub week_key: String,
+    pub track: String,
+    pub seed: i64,
+    pub ruleset_id: Option<String>,
+    pub blacklisted: bool,
+    pub created_at: String,
+}
+
+/// One entry in the regatta event feed — a simple append-only log clients
+/// can poll, since this server has no push/broadcast transport yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegattaEvent {
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub created_at: String,
+}
+
+/// One rung of a season's reward track: reach `xp_required` total XP to
+/// unlock `reward_item_id`, then claim it explicitly to add it to inventory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonTier {
+    pub tier: i64,
+    pub xp_required: i64,
+    pub reward_item_id: String,
+}
+
+/// A single tier's status for one player, combining the season's static
+/// tier definition with that player's progress against it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonTierStatus {
+    pub tier: i64,
+    pub xp_required: i64,
+    pub reward_item_id: String,
+    pub unlocked: bool,
+    pub claimed: bool,
+}
+
+/// A player's progress through the current season pass.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonProgress {
+    pub season_id: String,
+    pub xp: i64,
+    pub tiers: Vec<SeasonTierStatus>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ClaimTierRequest {
+    pub tier: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaimTierResult {
+    pub reward_item_id: String,
+}
+
+/// Recorded when a run submission pushes another player's best out of the
+/// top `overtake_notify_top_n` for a week, so the displaced player's client
+/// can render a "you've been overtaken" nudge from `previous_rank` even
+/// after the triggering notification has come and gone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OvertakeEvent {
+    pub player_id: String,
+    pub displaced_by_player_id: String,
+    pub week_key: String,
+    pub previous_rank: i64,
+    pub created_at: String,
+}
+
+/// One track's current regatta and how many runs have been submitted
+/// against it, part of `LiveOpsOverview`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegattaParticipation {
+    pub track: String,
+    pub regatta_id: String,
+    pub participant_runs: i64,
+}
+
+/// One tide metric's running total against its weekly goal, part of
+/// `LiveOpsOverview`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TideProgress {
+    pub metric: String,
+    pub week_total: i64,
+    pub weekly_goal: i64,
+}
+
+/// This week's cooperative weekly boss target: total `damage_dealt` on the
+/// configured raid seed accumulates toward `boss_hp` community-wide, and
+/// contributors get a reward once it's felled — see `raid_service`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RaidStatus {
+    pub week_key: String,
+    pub seed: i64,
+    pub boss_hp: i64,
+    pub damage_dealt: i64,
+    pub contributors_count: i64,
+    pub felled: bool,
+    pub felled_at: Option<String>,
+}
+
+/// One week's promotion/relegation division assignment for a player, part of
+/// their division history — see `division_service`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerDivisionRecord {
+    pub week_key: String,
+    pub division: i64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RequestCoachingRequest {
+    /// Optional context for reviewers, e.g. "struggling with wave 9 kiting".
+    pub note: Option<String>,
+}
+
+/// One replay flagged by its own player as "seeking feedback", part of the
+/// coaching queue volunteer reviewers pull from — see `coaching_service`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoachingQueueEntry {
+    pub run_id: String,
+    pub player_name: String,
+    pub ship_class: String,
+    pub score: i64,
+    pub note: Option<String>,
+    pub created_at: String,
+}
+
+/// One submission auto-flagged for tripping a canary seed or score — see
+/// `moderation_queue_service`. `resolved_at` is `None` while the flag is
+/// still open for review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlaggedSubmission {
+    pub id: String,
+    pub run_id: String,
+    pub player_id: String,
+    pub reason: String,
+    pub suspicion_score: i64,
+    pub created_at: String,
+    pub resolved_at: Option<String>,
+}
+
+/// What to do to every run a `BulkRunActionRequest` selects — see
+/// `admin_action_service::apply_bulk_action`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkRunAction {
+    /// Removes the run from leaderboards without deleting its row.
+    Hide,
+    /// Permanently deletes the run row.
+    Delete,
+    /// Hides the run and marks its player `banned`, rejecting their future
+    /// submissions.
+    Ban,
+}
+
+/// Selects the runs a bulk moderation action applies to: either an explicit
+/// list, or every run in `week_key` scoring at or above `min_score`. Exactly
+/// one of `run_ids` or `week_key` must be set — a request naming neither
+/// would otherwise select the whole table.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkRunActionRequest {
+    pub action: BulkRunAction,
+    pub run_ids: Option<Vec<String>>,
+    pub week_key: Option<String>,
+    pub min_score: Option<i64>,
+    /// When true, resolves the selection and reports it without mutating
+    /// anything, so a moderator can sanity-check the blast radius first.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// The runs a bulk action selected (whether or not it actually ran).
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkRunActionResult {
+    pub action: BulkRunAction,
+    pub affected_run_ids: Vec<String>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AttachCoachingFeedbackRequest {
+    pub reviewer_player_id: String,
+    /// The reviewer's main note, e.g. "watch your broadside timing on turns".
+    pub text: String,
+    /// Optional single-word tag for what the note is about, e.g.
+    /// `positioning` or `upgrade_choice` — the "structured" part of an
+    /// otherwise freeform note.
+    pub focus_area: Option<String>,
+}
+
+/// A single structured feedback note a volunteer reviewer left on a replay
+/// in the coaching queue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoachingFeedbackNote {
+    pub id: String,
+    pub run_id: String,
+    pub reviewer_player_id: String,
+    pub text: String,
+    pub focus_area: Option<String>,
+    pub created_at: String,
+    pub report_count: i64,
+}
+
+/// A player-authored request to create a personal goal — see
+/// `goal_service::create`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateGoalRequest {
+    /// One of `goal_service::GOAL_TYPES`, e.g. `reach_wave`.
+    pub goal_type: String,
+    /// Restricts progress to runs on this ship class. `None` counts any
+    /// ship class toward the goal.
+    pub ship_class: Option<String>,
+    pub target: i64,
+}
+
+/// A server-tracked personal goal a player set for themselves, evaluated on
+/// every run submission until it completes. Unlike the weekly leaderboard,
+/// tide, and raid systems, a goal's progress carries across week boundaries
+/// — it only resets if the player deletes it and creates a new one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalGoal {
+    pub id: String,
+    pub player_id: String,
+    pub goal_type: String,
+    pub ship_class: Option<String>,
+    pub target: i64,
+    pub progress: i64,
+    pub completed: bool,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+}
+
+/// A page of results from a cursor-paginated list endpoint. Shared across
+/// every list endpoint that adopts cursor pagination (`server::pagination`
+/// builds these) so client-side paging code is the same regardless of which
+/// endpoint it's paging through, and `total` never requires a client to run
+/// its own `COUNT` query.
+///
+/// `next_cursor` is opaque — pass it back as the `cursor` query parameter to
+/// fetch the next page, and treat `None` as "no more results" rather than
+/// parsing it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub total: i64,
+}
+
+/// Everything an internal live-ops dashboard wants on one screen, assembled
+/// from counters that are already being kept for other reasons rather than
+/// scanned fresh for this endpoint — see `GET /api/admin/overview`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveOpsOverview {
+    pub submissions_per_minute: f64,
+    pub total_requests: u64,
+    pub error_rate: f64,
+    pub active_signal_fires: i64,
+    /// Always `0` — there's no player-report/moderation subsystem in this
+    /// server yet. Reserved so a future reporting endpoint has a home to
+    /// report into without another dashboard schema change.
+    pub pending_reports: i64,
+    pub regattas: Vec<RegattaParticipation>,
+    pub tide_progress: Vec<TideProgress>,
+    pub raid: RaidStatus,
+}
+
+/// A crash or desync report from `POST /api/client-errors`. Reports sharing
+/// a `stack_hash` within the same tenant collapse into one row —
+/// `occurrence_count` and `last_seen_at` track the repeats rather than
+/// storing a row per report, since what an operator wants out of this is
+/// "which failures are widespread," not a full log of every occurrence.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientErrorReport {
+    pub id: String,
+    pub client_version: String,
+    pub seed: Option<String>,
+    pub wave: Option<i64>,
+    pub stack_hash: String,
+    pub message: String,
+    pub occurrence_count: i64,
+    pub first_seen_at: String,
+    pub last_seen_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportClientErrorRequest {
+    pub client_version: String,
+    pub seed: Option<String>,
+    pub wave: Option<i64>,
+    pub stack_hash: String,
+    pub message: String,
+}
+
+/// A client's report that a downloaded ghost diverged from the recorded
+/// outcome during playback — see `ghost_desync_service`. `divergence` is
+/// opaque, client-supplied diagnostic detail (e.g. `{"expected_score": ...,
+/// "actual_score": ...}`), same simplification `CommunityEvent::modifiers`
+/// makes: the server aggregates and thresholds on report *volume*, not on
+/// what's inside any one report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportGhostDesyncRequest {
+    pub frame: i64,
+    pub divergence: serde_json::Value,
+}
+
+/// One notable moment in a run, derived from its already-recorded metadata
+/// rather than parsed out of the tape's byte stream — the server has no
+/// frame-level schema for the client's tape format, so this is a summary
+/// snippet a client can show without downloading the full tape, not an
+/// extracted sub-tape. `wave`/`time_ms`/`score` are `None` when the
+/// underlying data point (e.g. a `WaveSplit`) isn't available for this run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GhostHighlight {
+    /// e.g. `biggest_combo`, `final_wave`.
+    pub label: String,
+    pub wave: Option<i64>,
+    pub time_ms: Option<i64>,
+    pub score: Option<i64>,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GhostHighlights {
+    pub run_id: String,
+    pub highlights: Vec<GhostHighlight>,
+}
+
+/// A stable, checksummed snapshot of one week's public-facing standings —
+/// see `public_dump_service`. Generated once, after the week ends, so a
+/// wiki or stats-site maintainer can fetch it directly at
+/// `GET /api/public/dumps/:week_key` instead of paginating the live
+/// leaderboard API over and over for data that stopped changing the moment
+/// the week rolled over.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicWeeklyDump {
+    pub week_key: String,
+    /// Top 1000 (or fewer) entries of the week's default board.
+    pub top_runs: Vec<LeaderboardEntry>,
+    pub regattas: Vec<Regatta>,
+    /// The admin-configured omen catalog at generation time — see
+    /// `Config::omens`.
+    pub omens: Vec<String>,
+    pub generated_at: String,
+}
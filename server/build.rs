@@ -0,0 +1,8 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Vendor and build protoc instead of relying on a system install — without
+    // this, `cargo build` fails on any machine that hasn't separately
+    // installed protoc, which is every machine but a maintainer's.
+    std::env::set_var("PROTOC", protobuf_src::protoc());
+    tonic_build::compile_protos("proto/booty_hunt.proto")?;
+    Ok(())
+}
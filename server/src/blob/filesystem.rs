@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use tokio::fs;
+
+use super::{BlobError, BlobStore};
+
+/// Stores each blob as a single file under `dir`, named by key. Keys are
+/// always server-generated run ids, never client input, so no path
+/// traversal sanitization is needed beyond joining the two components.
+pub struct FilesystemBlobStore {
+    dir: PathBuf,
+}
+
+impl FilesystemBlobStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        FilesystemBlobStore { dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl BlobStore for FilesystemBlobStore {
+    fn name(&self) -> &'static str {
+        "filesystem"
+    }
+
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), BlobError> {
+        fs::create_dir_all(&self.dir).await.map_err(|e| BlobError::Io(e.to_string()))?;
+        fs::write(self.dir.join(key), bytes).await.map_err(|e| BlobError::Io(e.to_string()))
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BlobError> {
+        match fs::read(self.dir.join(key)).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(BlobError::Io(e.to_string())),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Pluggable storage for ghost tapes. The default is nothing — tapes stay in
+//! the `runs.ghost_tape` BLOB column, as they always have — but a deployment
+//! whose DB file is ballooning can point `BOOTY_HUNT_TAPE_STORAGE_BACKEND` at
+//! a filesystem directory or an S3-compatible bucket instead, keeping only a
+//! `ghost_tape_ref` key in SQLite.
+
+mod filesystem;
+mod s3;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+pub use filesystem::FilesystemBlobStore;
+pub use s3::S3BlobStore;
+
+use crate::config::Config;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BlobError {
+    #[error("blob store io error: {0}")]
+    Io(String),
+}
+
+/// `key` is opaque to callers — today it's always a run id — so an
+/// implementation only needs bytes-in, bytes-out semantics.
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), BlobError>;
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, BlobError>;
+}
+
+/// Builds the configured tape blob store, if any. `None` means "keep storing
+/// ghost tapes inline in `runs.ghost_tape`", the historical default.
+pub async fn from_config(config: &Config) -> Option<Arc<dyn BlobStore>> {
+    match config.tape_storage_backend.as_str() {
+        "filesystem" => Some(Arc::new(FilesystemBlobStore::new(config.tape_storage_dir.clone()))),
+        "s3" => {
+            let bucket = config
+                .tape_s3_bucket
+                .clone()
+                .expect("BOOTY_HUNT_TAPE_S3_BUCKET is required when BOOTY_HUNT_TAPE_STORAGE_BACKEND=s3");
+            Some(Arc::new(
+                S3BlobStore::new(bucket, config.tape_s3_endpoint.clone(), config.tape_s3_region.clone()).await,
+            ))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,647 @@
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, RwLock};
+
+/// Server tunables loaded from the environment at startup. Fields grow as new
+/// subsystems need their own knobs; keep defaults sane for a single self-hosted
+/// instance so `Config::from_env()` never panics on an empty environment.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub bind_addr: String,
+    pub grpc_bind_addr: String,
+    pub db_path: String,
+    /// Recompute each submitted run's score from its ghost tape and flag
+    /// mismatches against the client-reported score. Off by default while
+    /// the scoring rules here are still catching up to the client.
+    pub recompute_scores: bool,
+    /// Per-handler timeout applied to every route.
+    pub request_timeout_secs: u64,
+    /// Db operations slower than this are logged (SQL/params redacted) and
+    /// counted, so we can spot what's locking up the single connection.
+    pub slow_query_threshold_ms: u64,
+    /// How many times `Db::with_read_conn`/`Db::with_tx` retry a query that
+    /// fails with `SQLITE_BUSY`/`SQLITE_LOCKED` before giving up and
+    /// returning `AppError::Busy`. `busy_timeout` (fixed at 5s on the
+    /// connection) already covers most contention; this is a second,
+    /// coarser layer on top for whatever's left over.
+    pub db_busy_retry_max_attempts: u32,
+    /// Base delay between busy retries, before jitter — see
+    /// `db::retry_busy`.
+    pub db_busy_retry_base_delay_ms: u64,
+    /// Whether to derive and store a coarse region with each run submission.
+    /// Off switches regional leaderboards entirely for privacy-sensitive
+    /// deployments that don't want to persist anything IP-derived.
+    pub geo_derivation_enabled: bool,
+    /// Serves the bundled static leaderboard viewer at `/` when enabled.
+    /// Self-hosters running their own frontend should leave this off.
+    pub static_ui_enabled: bool,
+    /// Per-ship-class multiplier applied to a run's raw score to produce its
+    /// `normalized_score`, so the unified leaderboard isn't dominated by
+    /// whichever class racks up the highest raw numbers. A class missing from
+    /// the map gets a multiplier of 1.0.
+    pub class_score_multipliers: HashMap<String, f64>,
+    /// The season id XP is currently being banked against. Changing this
+    /// starts a fresh `season_progress` row per player without touching the
+    /// previous season's history — old rows just stop accruing.
+    pub current_season_id: String,
+    /// The current season's reward track, ordered by `tier` ascending.
+    pub season_tiers: Vec<booty_hunt_core::SeasonTier>,
+    /// Track ids that run concurrently each week (e.g. a sloop sprint
+    /// alongside a galleon marathon), each getting its own seed under
+    /// `GET /api/regatta`. A deployment with no configured tracks still gets
+    /// one `"default"` track, matching the single-regatta-per-week behavior
+    /// this replaced.
+    pub regatta_tracks: Vec<String>,
+    /// Where new ghost tapes are written: `"sqlite"` (default, inline BLOB
+    /// in `runs.ghost_tape`), `"filesystem"`, or `"s3"`. Existing tapes keep
+    /// working regardless — `ghost_service` falls back to the BLOB column
+    /// when a run has no `ghost_tape_ref`.
+    pub tape_storage_backend: String,
+    /// Directory new tapes are written under when the backend is
+    /// `"filesystem"`.
+    pub tape_storage_dir: String,
+    /// Bucket new tapes are written to when the backend is `"s3"`. Required
+    /// in that case; panics at startup if unset rather than silently
+    /// dropping tapes.
+    pub tape_s3_bucket: Option<String>,
+    /// Endpoint URL for an S3-compatible service (MinIO, R2, B2). `None`
+    /// uses AWS's default endpoint resolution for `tape_s3_region`.
+    pub tape_s3_endpoint: Option<String>,
+    pub tape_s3_region: String,
+    /// Periodically snapshots the whole database to `replication_dest_dir`
+    /// for disaster recovery. Off by default — self-hosters on a single VPS
+    /// opt in once they've picked a destination (ideally a different disk).
+    pub replication_enabled: bool,
+    /// Directory snapshots are written to. Should live outside `db_path`'s
+    /// disk to be useful as a recovery target.
+    pub replication_dest_dir: String,
+    pub replication_interval_secs: u64,
+    /// A submission that pushes a player out of the top N for the week
+    /// records an overtake event and, if they've enabled it, notifies them.
+    /// `0` disables the feature entirely — a deployment with no real device
+    /// registrations yet can skip the extra write on every submission.
+    pub overtake_notify_top_n: i64,
+    /// Caps how many trade offers one player can have open at once on the
+    /// signal fire trading board, so a single account can't tie up the
+    /// whole aid-type supply in escrowed offers it never intends to complete.
+    pub max_open_trade_offers_per_player: i64,
+    /// The server-side catalog `GET /api/tide/metrics` publishes and
+    /// `POST /api/tide/contribute` validates against, so clients discover
+    /// accepted metric keys instead of guessing strings.
+    pub tide_metrics: Vec<booty_hunt_core::TideMetricDefinition>,
+    /// Omen ids `GET /api/tide/omens` publishes this week. Each id must have
+    /// `omen.<id>.name`/`omen.<id>.description` entries in `i18n`'s catalog —
+    /// an id with no catalog entry still gets published, just with the bare
+    /// key standing in for text no one's translated yet.
+    pub omens: Vec<String>,
+    /// A/B experiments over omen modifiers (or any other weekly variable)
+    /// available to `experiment_service`. Empty by default — a deployment
+    /// opts in per-experiment via `BOOTY_HUNT_EXPERIMENTS`.
+    pub experiments: Vec<booty_hunt_core::ExperimentDefinition>,
+    /// Accepted `POST /api/telemetry` event kinds and their required payload
+    /// fields — see `telemetry_service::validate_event`.
+    pub telemetry_event_schemas: Vec<booty_hunt_core::TelemetryEventSchema>,
+    /// How long a telemetry event is kept before the scheduler's retention
+    /// job deletes it. Kept short by default since this is high-volume,
+    /// low-per-event-value data, unlike `runs`.
+    pub telemetry_retention_days: i64,
+    /// Caps how many events one `POST /api/telemetry` call may batch, so a
+    /// single oversized request can't monopolize the write connection.
+    pub telemetry_max_batch_size: usize,
+    /// Periodically dumps `runs` and `telemetry_events` to CSV under
+    /// `analytics_export_dest_dir` for offline analysis. Off by default —
+    /// see `analytics_export_service` for why this is CSV, not Parquet/
+    /// ClickHouse.
+    pub analytics_export_enabled: bool,
+    pub analytics_export_dest_dir: String,
+    pub analytics_export_interval_secs: u64,
+    /// Max `GET /api/leaderboard` requests one client key (bearer token, or
+    /// source IP for anonymous callers) may make per `leaderboard_poll_window_secs`
+    /// before getting a 429. See `rate_limit::PollLimiter`.
+    pub leaderboard_poll_budget: u32,
+    pub leaderboard_poll_window_secs: u64,
+    /// Sent back as `poll_interval_hint_secs` on every leaderboard response,
+    /// telling well-behaved clients how often to poll before they'd ever hit
+    /// the budget above.
+    pub leaderboard_poll_interval_hint_secs: u64,
+    /// HMAC key used to sign and verify run submission receipts (see
+    /// `receipt.rs`). The default is fine for local development but is
+    /// public in this repository — any deployment that hands receipts to
+    /// third parties as proof of acceptance must override it, or anyone can
+    /// forge one.
+    pub receipt_signing_secret: String,
+    /// Shared secret `/api/admin/*` routes require as a `Bearer` token — see
+    /// `extractors::AdminAuth`. `None` (the default) rejects every admin
+    /// request rather than leaving the surface open, so a deployment has to
+    /// opt into admin access rather than opt out of it.
+    pub admin_api_token: Option<String>,
+    /// How long after a run is submitted its ghost tape may be attached via
+    /// `PUT /api/runs/:run_id/ghost`, in seconds. Bounds how long a run can
+    /// sit tapeless waiting on a retried upload, and how long a leaked
+    /// receipt stays useful for attaching a tape to someone else's run.
+    pub ghost_attach_window_secs: u64,
+    /// Discord-compatible webhook URL the scheduler posts regatta countdown,
+    /// new-week, and omen-reveal announcements to. `None` (the default)
+    /// disables all three — see `announcement_service`.
+    pub announcement_webhook_url: Option<String>,
+    /// Lowercase substrings rejected from bottle notes (and any future
+    /// player-authored text that reuses `moderation::contains_blocked_word`).
+    pub blocked_words: Vec<String>,
+    /// Max `POST /api/seeds/:seed/notes` calls one player may make per
+    /// `bottle_note_rate_limit_window_secs`, so the board can't be flooded.
+    pub bottle_note_rate_limit_budget: u32,
+    pub bottle_note_rate_limit_window_secs: u64,
+    /// Max `POST /api/client-errors` calls one source IP may make per
+    /// `client_error_rate_limit_window_secs`, so a crash-looping client
+    /// can't flood the table.
+    pub client_error_rate_limit_budget: u32,
+    pub client_error_rate_limit_window_secs: u64,
+    /// A ghost tape needs at least this many `replay_downloads` before its
+    /// desync report rate is trusted enough to auto-flag it corrupt — below
+    /// this, one report on a barely-downloaded tape would flag it on noise.
+    pub ghost_desync_min_downloads: i64,
+    /// Fraction of downloads that must report a desync (`reports /
+    /// downloads`) before `ghost_desync_service::report` auto-flags the
+    /// tape as `ghost_corrupt`.
+    pub ghost_desync_flag_ratio: f64,
+    /// `Cache-Control: max-age` on `GET /api/runs/:run_id/card.svg`, in
+    /// seconds — safe to set high since a run's card content never changes
+    /// after submission.
+    pub run_card_cache_max_age_secs: u64,
+    /// Where `GET /r/:run_id` redirects a human browser after serving its
+    /// Open Graph tags — `{run_id}` is substituted in. `None` (the default,
+    /// since no web viewer client ships in this repo) falls back to the
+    /// JSON run detail endpoint, so the redirect always lands somewhere
+    /// real.
+    pub run_viewer_url_template: Option<String>,
+    /// This deployment's externally-reachable origin (e.g.
+    /// `https://api.bootyhunt.example`), used to build absolute URLs for
+    /// `og:image` and the default `run_viewer_url_template` fallback. `None`
+    /// (the default) falls back to relative paths, which most crawlers
+    /// still resolve fine against the page they fetched.
+    pub public_base_url: Option<String>,
+    /// Max `GET /api/runs/:run_id/ghost` downloads one source IP may make per
+    /// `ghost_download_ip_rate_limit_window_secs` before getting a 429 — see
+    /// `routes/ghost.rs::download`. A caller presenting a valid API key
+    /// bypasses this, since a bulk mirroring tool identifying itself is the
+    /// intended escape hatch, not the abuse this guards against.
+    pub ghost_download_ip_rate_limit_budget: u32,
+    pub ghost_download_ip_rate_limit_window_secs: u64,
+    /// Secret `ghost_signed_url_service` HMACs signed tape-download URLs
+    /// with, separate from `receipt_signing_secret` so rotating one doesn't
+    /// invalidate the other.
+    pub ghost_signed_url_secret: String,
+    /// How long a `GET /api/runs/:run_id` response's `ghost_url` stays
+    /// valid before the signature expires — see `ghost_signed_url_service::issue`.
+    pub ghost_signed_url_ttl_secs: u64,
+    /// A bottle note auto-hides once it collects this many reports, so a bad
+    /// note stops surfacing without waiting on an admin to act on it.
+    pub bottle_note_hide_after_reports: i64,
+    /// The seed a run must report to have its `damage_dealt` counted toward
+    /// this week's cooperative raid boss, rather than the standard board.
+    pub raid_seed: i64,
+    /// The boss's total HP pool for the week — once community `damage_dealt`
+    /// on `raid_seed` reaches this, the boss falls and contributors are
+    /// rewarded. See `raid_service` and `scheduler::spawn_raid_finalization`.
+    pub raid_boss_hp: i64,
+    /// Cosmetic item id granted to every contributor once the boss falls.
+    /// `None` disables the reward grant while leaving progress tracking on.
+    pub raid_reward_item_id: Option<String>,
+    /// Target headcount per promotion/relegation division — see
+    /// `division_service::ensure_assigned_for_week`.
+    pub division_size: i64,
+    /// A coaching feedback note auto-hides once it collects this many
+    /// reports, same "hide, don't delete" pattern as
+    /// `bottle_note_hide_after_reports`.
+    pub coaching_feedback_hide_after_reports: i64,
+    /// Clients reporting an `x-client-version` at or above this value get
+    /// strict `deny_unknown_fields` validation on run submission bodies —
+    /// see `extractors::RunSubmissionBody`. `None` (the default) leaves
+    /// strict mode opt-in only, via the `x-strict-fields` header.
+    pub strict_fields_min_client_version: Option<String>,
+    /// Plausibility ceilings on submission fields other than `score` (which
+    /// is checked per-wave by `max_submission_score_per_wave` instead, since
+    /// a flat ceiling would either be too loose for a one-wave death or too
+    /// tight for a full 15-wave clear). Beyond these, and below zero, a run
+    /// is rejected outright rather than allowed to skew leaderboard and
+    /// stats aggregates.
+    pub max_submission_waves: i64,
+    pub max_submission_damage_dealt: i64,
+    pub max_submission_combo: i64,
+    pub max_submission_time_played_secs: i64,
+    pub max_submission_heat: i64,
+    /// A submission's `score` divided by its `waves` (floor 1) beyond this
+    /// is rejected as implausible — see `hooks::anti_cheat::ImplausibleScoreHook`,
+    /// which `hooks::from_config` always constructs so this check runs on
+    /// every submission regardless of deployment configuration.
+    pub max_submission_score_per_wave: i64,
+    /// Posts run-pipeline events (submission accepted, leaderboard top
+    /// changed) as JSON to this webhook URL — see `hooks::webhook::WebhookRunHook`.
+    /// `None` (the default) skips constructing it.
+    pub run_hook_webhook_url: Option<String>,
+    /// Posts player-facing notification events (overtaken in top, goal
+    /// completed, signal fire redeemed) as JSON to this webhook URL — see
+    /// `notifications::webhook::WebhookProvider`. Distinct from
+    /// `run_hook_webhook_url`: this is player notification delivery, not a
+    /// pipeline-observability hook. `None` (the default) skips constructing
+    /// it, so `notification_service::dispatch` has no provider to hand
+    /// events to.
+    pub notification_webhook_url: Option<String>,
+    /// Steam Web API key used to verify Steam auth session tickets via
+    /// `identity::SteamIdentityProvider`. `None` (the default) skips
+    /// constructing it, so `POST /api/players/:player_id/identities/link`
+    /// has no `steam` provider to hand proofs to.
+    pub steam_web_api_key: Option<String>,
+    /// A run submission whose fingerprint (seed, score, waves, time_played,
+    /// tape hash, player) matches one already accepted within this many
+    /// seconds is rejected as a duplicate instead of creating a twin
+    /// leaderboard row — see `run_service::submission_fingerprint`.
+    pub duplicate_submission_window_secs: i64,
+    /// When set, `POST /api/runs` rejects any submission that doesn't carry a
+    /// valid, unexpired, unused `submission_nonce` from `nonce_service::issue`
+    /// for that player/seed — see `nonce_service`. Off by default so existing
+    /// clients that predate the nonce flow keep working; deployments that
+    /// want the stronger guarantee turn it on once their client has shipped
+    /// nonce support.
+    pub submission_nonce_required: bool,
+    /// Seeds the real client never produces — reserved for honeypot testing
+    /// and third-party client detection. A submission reporting one of these
+    /// is auto-flagged into the moderation queue rather than rejected, so
+    /// the submitter doesn't learn they tripped a canary. Empty by default;
+    /// an operator seeds this once they've picked values worth watching.
+    pub canary_seeds: Vec<i64>,
+    /// Scores the real client never produces (e.g. round numbers past the
+    /// plausible ceiling), checked the same way as `canary_seeds` — see
+    /// `suspicion_service::detect_canary`.
+    pub canary_scores: Vec<i64>,
+    /// Points added to a run's suspicion score when `recompute_scores` finds
+    /// its ghost tape doesn't support the reported score. See
+    /// `suspicion_service`.
+    pub suspicion_weight_score_mismatch: i64,
+    /// Points added when the run reports a canary seed or score.
+    pub suspicion_weight_canary_hit: i64,
+    /// Points added when the submitting player has posted more than
+    /// `suspicion_rate_threshold` runs in the trailing
+    /// `suspicion_rate_window_secs`.
+    pub suspicion_weight_high_rate: i64,
+    /// Window, in seconds, `suspicion_service` counts a player's recent
+    /// submissions over for the high-rate signal.
+    pub suspicion_rate_window_secs: i64,
+    /// Submissions within `suspicion_rate_window_secs` at or above this
+    /// count trip the high-rate signal.
+    pub suspicion_rate_threshold: i64,
+    /// A run whose total suspicion score reaches this value is auto-flagged
+    /// into the moderation queue, same as a canary hit — see
+    /// `suspicion_service::compute_and_record`.
+    pub suspicion_flag_threshold: i64,
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        Config {
+            bind_addr: env::var("BOOTY_HUNT_BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:8080".into()),
+            grpc_bind_addr: env::var("BOOTY_HUNT_GRPC_BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:8081".into()),
+            db_path: env::var("BOOTY_HUNT_DB_PATH").unwrap_or_else(|_| "booty-hunt.db".into()),
+            recompute_scores: env::var("BOOTY_HUNT_RECOMPUTE_SCORES")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            request_timeout_secs: env::var("BOOTY_HUNT_REQUEST_TIMEOUT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(10),
+            slow_query_threshold_ms: env::var("BOOTY_HUNT_SLOW_QUERY_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(200),
+            db_busy_retry_max_attempts: env::var("BOOTY_HUNT_DB_BUSY_RETRY_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3),
+            db_busy_retry_base_delay_ms: env::var("BOOTY_HUNT_DB_BUSY_RETRY_BASE_DELAY_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(25),
+            geo_derivation_enabled: env::var("BOOTY_HUNT_GEO_DERIVATION_ENABLED")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(true),
+            static_ui_enabled: env::var("BOOTY_HUNT_STATIC_UI_ENABLED")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            class_score_multipliers: env::var("BOOTY_HUNT_CLASS_SCORE_MULTIPLIERS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_else(default_class_score_multipliers),
+            current_season_id: env::var("BOOTY_HUNT_CURRENT_SEASON_ID").unwrap_or_else(|_| "season-1".into()),
+            season_tiers: env::var("BOOTY_HUNT_SEASON_TIERS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_else(default_season_tiers),
+            regatta_tracks: env::var("BOOTY_HUNT_REGATTA_TRACKS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_else(|| vec!["default".to_string()]),
+            tape_storage_backend: env::var("BOOTY_HUNT_TAPE_STORAGE_BACKEND").unwrap_or_else(|_| "sqlite".into()),
+            tape_storage_dir: env::var("BOOTY_HUNT_TAPE_STORAGE_DIR").unwrap_or_else(|_| "ghost-tapes".into()),
+            tape_s3_bucket: env::var("BOOTY_HUNT_TAPE_S3_BUCKET").ok(),
+            tape_s3_endpoint: env::var("BOOTY_HUNT_TAPE_S3_ENDPOINT").ok(),
+            tape_s3_region: env::var("BOOTY_HUNT_TAPE_S3_REGION").unwrap_or_else(|_| "us-east-1".into()),
+            replication_enabled: env::var("BOOTY_HUNT_REPLICATION_ENABLED")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            replication_dest_dir: env::var("BOOTY_HUNT_REPLICATION_DEST_DIR").unwrap_or_else(|_| "replicas".into()),
+            replication_interval_secs: env::var("BOOTY_HUNT_REPLICATION_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(300),
+            overtake_notify_top_n: env::var("BOOTY_HUNT_OVERTAKE_NOTIFY_TOP_N")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(10),
+            max_open_trade_offers_per_player: env::var("BOOTY_HUNT_MAX_OPEN_TRADE_OFFERS_PER_PLAYER")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            tide_metrics: env::var("BOOTY_HUNT_TIDE_METRICS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_else(default_tide_metrics),
+            omens: env::var("BOOTY_HUNT_OMENS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_else(default_omens),
+            experiments: env::var("BOOTY_HUNT_EXPERIMENTS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_default(),
+            telemetry_event_schemas: env::var("BOOTY_HUNT_TELEMETRY_EVENT_SCHEMAS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_else(default_telemetry_event_schemas),
+            telemetry_retention_days: env::var("BOOTY_HUNT_TELEMETRY_RETENTION_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(14),
+            telemetry_max_batch_size: env::var("BOOTY_HUNT_TELEMETRY_MAX_BATCH_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(200),
+            analytics_export_enabled: env::var("BOOTY_HUNT_ANALYTICS_EXPORT_ENABLED")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            analytics_export_dest_dir: env::var("BOOTY_HUNT_ANALYTICS_EXPORT_DEST_DIR").unwrap_or_else(|_| "analytics-exports".into()),
+            analytics_export_interval_secs: env::var("BOOTY_HUNT_ANALYTICS_EXPORT_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3600),
+            leaderboard_poll_budget: env::var("BOOTY_HUNT_LEADERBOARD_POLL_BUDGET")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30),
+            leaderboard_poll_window_secs: env::var("BOOTY_HUNT_LEADERBOARD_POLL_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(60),
+            leaderboard_poll_interval_hint_secs: env::var("BOOTY_HUNT_LEADERBOARD_POLL_INTERVAL_HINT_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            receipt_signing_secret: env::var("BOOTY_HUNT_RECEIPT_SIGNING_SECRET")
+                .unwrap_or_else(|_| "insecure-dev-receipt-secret-override-me".into()),
+            ghost_attach_window_secs: env::var("BOOTY_HUNT_GHOST_ATTACH_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(24 * 3600),
+            announcement_webhook_url: env::var("BOOTY_HUNT_ANNOUNCEMENT_WEBHOOK_URL").ok(),
+            admin_api_token: env::var("BOOTY_HUNT_ADMIN_API_TOKEN").ok(),
+            blocked_words: env::var("BOOTY_HUNT_BLOCKED_WORDS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_else(default_blocked_words),
+            bottle_note_rate_limit_budget: env::var("BOOTY_HUNT_BOTTLE_NOTE_RATE_LIMIT_BUDGET")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            bottle_note_rate_limit_window_secs: env::var("BOOTY_HUNT_BOTTLE_NOTE_RATE_LIMIT_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3600),
+            client_error_rate_limit_budget: env::var("BOOTY_HUNT_CLIENT_ERROR_RATE_LIMIT_BUDGET")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(20),
+            client_error_rate_limit_window_secs: env::var("BOOTY_HUNT_CLIENT_ERROR_RATE_LIMIT_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3600),
+            ghost_desync_min_downloads: env::var("BOOTY_HUNT_GHOST_DESYNC_MIN_DOWNLOADS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(10),
+            ghost_desync_flag_ratio: env::var("BOOTY_HUNT_GHOST_DESYNC_FLAG_RATIO")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0.3),
+            run_card_cache_max_age_secs: env::var("BOOTY_HUNT_RUN_CARD_CACHE_MAX_AGE_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(86400),
+            run_viewer_url_template: env::var("BOOTY_HUNT_RUN_VIEWER_URL_TEMPLATE").ok(),
+            public_base_url: env::var("BOOTY_HUNT_PUBLIC_BASE_URL").ok(),
+            ghost_download_ip_rate_limit_budget: env::var("BOOTY_HUNT_GHOST_DOWNLOAD_IP_RATE_LIMIT_BUDGET")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(500),
+            ghost_download_ip_rate_limit_window_secs: env::var("BOOTY_HUNT_GHOST_DOWNLOAD_IP_RATE_LIMIT_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3600),
+            ghost_signed_url_secret: env::var("BOOTY_HUNT_GHOST_SIGNED_URL_SECRET")
+                .unwrap_or_else(|_| "insecure-dev-ghost-url-secret-override-me".into()),
+            ghost_signed_url_ttl_secs: env::var("BOOTY_HUNT_GHOST_SIGNED_URL_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(900),
+            bottle_note_hide_after_reports: env::var("BOOTY_HUNT_BOTTLE_NOTE_HIDE_AFTER_REPORTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3),
+            raid_seed: env::var("BOOTY_HUNT_RAID_SEED").ok().and_then(|v| v.parse().ok()).unwrap_or(999_999),
+            raid_boss_hp: env::var("BOOTY_HUNT_RAID_BOSS_HP").ok().and_then(|v| v.parse().ok()).unwrap_or(50_000_000),
+            raid_reward_item_id: env::var("BOOTY_HUNT_RAID_REWARD_ITEM_ID").ok(),
+            division_size: env::var("BOOTY_HUNT_DIVISION_SIZE").ok().and_then(|v| v.parse().ok()).unwrap_or(20),
+            coaching_feedback_hide_after_reports: env::var("BOOTY_HUNT_COACHING_FEEDBACK_HIDE_AFTER_REPORTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3),
+            strict_fields_min_client_version: env::var("BOOTY_HUNT_STRICT_FIELDS_MIN_CLIENT_VERSION").ok(),
+            max_submission_waves: env::var("BOOTY_HUNT_MAX_SUBMISSION_WAVES").ok().and_then(|v| v.parse().ok()).unwrap_or(15),
+            max_submission_damage_dealt: env::var("BOOTY_HUNT_MAX_SUBMISSION_DAMAGE_DEALT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(100_000_000),
+            max_submission_combo: env::var("BOOTY_HUNT_MAX_SUBMISSION_COMBO").ok().and_then(|v| v.parse().ok()).unwrap_or(10_000),
+            max_submission_time_played_secs: env::var("BOOTY_HUNT_MAX_SUBMISSION_TIME_PLAYED_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(6 * 60 * 60),
+            max_submission_heat: env::var("BOOTY_HUNT_MAX_SUBMISSION_HEAT").ok().and_then(|v| v.parse().ok()).unwrap_or(1_000),
+            max_submission_score_per_wave: env::var("BOOTY_HUNT_MAX_SUBMISSION_SCORE_PER_WAVE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(1_000_000),
+            run_hook_webhook_url: env::var("BOOTY_HUNT_RUN_HOOK_WEBHOOK_URL").ok(),
+            notification_webhook_url: env::var("BOOTY_HUNT_NOTIFICATION_WEBHOOK_URL").ok(),
+            steam_web_api_key: env::var("BOOTY_HUNT_STEAM_WEB_API_KEY").ok(),
+            duplicate_submission_window_secs: env::var("BOOTY_HUNT_DUPLICATE_SUBMISSION_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(300),
+            submission_nonce_required: env::var("BOOTY_HUNT_SUBMISSION_NONCE_REQUIRED")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(false),
+            canary_seeds: env::var("BOOTY_HUNT_CANARY_SEEDS")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_default(),
+            canary_scores: env::var("BOOTY_HUNT_CANARY_SCORES")
+                .ok()
+                .and_then(|v| serde_json::from_str(&v).ok())
+                .unwrap_or_default(),
+            suspicion_weight_score_mismatch: env::var("BOOTY_HUNT_SUSPICION_WEIGHT_SCORE_MISMATCH")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(40),
+            suspicion_weight_canary_hit: env::var("BOOTY_HUNT_SUSPICION_WEIGHT_CANARY_HIT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(100),
+            suspicion_weight_high_rate: env::var("BOOTY_HUNT_SUSPICION_WEIGHT_HIGH_RATE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(20),
+            suspicion_rate_window_secs: env::var("BOOTY_HUNT_SUSPICION_RATE_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(60),
+            suspicion_rate_threshold: env::var("BOOTY_HUNT_SUSPICION_RATE_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            suspicion_flag_threshold: env::var("BOOTY_HUNT_SUSPICION_FLAG_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(50),
+        }
+    }
+}
+
+/// Galleons carry the most raw firepower and clear waves fastest, so their
+/// runs dwarf sloop/brigantine scores on the same seed; these defaults pull
+/// the classes back toward parity for the unified leaderboard until a
+/// deployment tunes its own coefficients from real submission data.
+fn default_class_score_multipliers() -> HashMap<String, f64> {
+    HashMap::from([("sloop".to_string(), 1.2), ("brigantine".to_string(), 1.0), ("galleon".to_string(), 0.8)])
+}
+
+/// A modest five-tier track so a fresh deployment has something to show
+/// before an operator configures its own season via `BOOTY_HUNT_SEASON_TIERS`.
+fn default_season_tiers() -> Vec<booty_hunt_core::SeasonTier> {
+    vec![
+        booty_hunt_core::SeasonTier { tier: 1, xp_required: 500, reward_item_id: "flag_tattered".to_string() },
+        booty_hunt_core::SeasonTier { tier: 2, xp_required: 1_500, reward_item_id: "figurehead_kraken".to_string() },
+        booty_hunt_core::SeasonTier { tier: 3, xp_required: 3_500, reward_item_id: "sail_crimson".to_string() },
+        booty_hunt_core::SeasonTier { tier: 4, xp_required: 7_000, reward_item_id: "hull_gilded".to_string() },
+        booty_hunt_core::SeasonTier { tier: 5, xp_required: 12_000, reward_item_id: "flag_admiral".to_string() },
+    ]
+}
+
+/// A modest three-metric catalog so a fresh deployment's tide event has
+/// something to accept before an operator tunes `BOOTY_HUNT_TIDE_METRICS`
+/// to their own community goals.
+fn default_tide_metrics() -> Vec<booty_hunt_core::TideMetricDefinition> {
+    vec![
+        booty_hunt_core::TideMetricDefinition {
+            key: "cannonballs_fired".to_string(),
+            label: "Cannonballs Fired".to_string(),
+            unit: "cannonballs".to_string(),
+            per_contribution_cap: 500,
+            weekly_goal: 250_000,
+        },
+        booty_hunt_core::TideMetricDefinition {
+            key: "gold_looted".to_string(),
+            label: "Gold Looted".to_string(),
+            unit: "gold".to_string(),
+            per_contribution_cap: 10_000,
+            weekly_goal: 2_000_000,
+        },
+        booty_hunt_core::TideMetricDefinition {
+            key: "waves_cleared".to_string(),
+            label: "Waves Cleared".to_string(),
+            unit: "waves".to_string(),
+            per_contribution_cap: 15,
+            weekly_goal: 5_000,
+        },
+    ]
+}
+
+/// A modest three-omen rotation so a fresh deployment's tide event has
+/// something to show before an operator tunes `BOOTY_HUNT_OMENS`. Each id
+/// must have a matching entry in `i18n`'s catalog for its name/description
+/// to render as more than the bare key.
+fn default_omens() -> Vec<String> {
+    vec!["fair_winds".to_string(), "kraken_stirring".to_string(), "blood_moon".to_string()]
+}
+
+/// A small starter blocklist so a fresh deployment's bottle notes aren't
+/// entirely unmoderated before an operator tunes `BOOTY_HUNT_BLOCKED_WORDS`
+/// to their own community standards.
+fn default_blocked_words() -> Vec<String> {
+    vec!["fuck".to_string(), "shit".to_string(), "cunt".to_string()]
+}
+
+/// The two event kinds the request that introduced telemetry ingestion named
+/// explicitly — a fresh deployment can accept these out of the box and add
+/// more via `BOOTY_HUNT_TELEMETRY_EVENT_SCHEMAS`.
+fn default_telemetry_event_schemas() -> Vec<booty_hunt_core::TelemetryEventSchema> {
+    vec![
+        booty_hunt_core::TelemetryEventSchema {
+            event_type: "wave_death".to_string(),
+            required_fields: vec!["wave".to_string(), "ship_class".to_string()],
+        },
+        booty_hunt_core::TelemetryEventSchema {
+            event_type: "upgrade_pick".to_string(),
+            required_fields: vec!["upgrade_id".to_string(), "wave".to_string()],
+        },
+    ]
+}
+
+/// Holds the live `Config`, swappable without restarting the process (and
+/// without dropping the SQLite connection, which lives on `Db`, not here).
+///
+/// Reads a whole `Config` snapshot at a time via `current()` rather than
+/// exposing individual fields, so a request that reads several tunables
+/// during one operation sees them all from the same reload epoch instead of
+/// a mix of pre- and post-reload values if a reload lands mid-request.
+pub struct ConfigHandle(RwLock<Arc<Config>>);
+
+impl ConfigHandle {
+    pub fn new(config: Config) -> Self {
+        ConfigHandle(RwLock::new(Arc::new(config)))
+    }
+
+    pub fn current(&self) -> Arc<Config> {
+        self.0.read().expect("config lock poisoned").clone()
+    }
+
+    /// Re-reads every `BOOTY_HUNT_*` environment variable and swaps in the
+    /// result. In-flight requests keep the `Arc<Config>` snapshot they
+    /// already loaded; only requests starting after this call see the new
+    /// values. Triggered by SIGHUP or `POST /api/admin/config/reload` — see
+    /// `main`'s signal handler and `routes::admin::reload_config`.
+    pub fn reload_from_env(&self) -> Arc<Config> {
+        let fresh = Arc::new(Config::from_env());
+        *self.0.write().expect("config lock poisoned") = fresh.clone();
+        fresh
+    }
+}
@@ -0,0 +1,415 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use rusqlite::Connection;
+
+use crate::error::AppError;
+
+const MIGRATIONS: &[(&str, &str)] = &[
+    ("0001_init", include_str!("../migrations/0001_init.sql")),
+    ("0002_cosmetics", include_str!("../migrations/0002_cosmetics.sql")),
+    ("0003_scoring", include_str!("../migrations/0003_scoring.sql")),
+    ("0004_tape_sessions", include_str!("../migrations/0004_tape_sessions.sql")),
+    ("0005_notifications", include_str!("../migrations/0005_notifications.sql")),
+    ("0006_recovery", include_str!("../migrations/0006_recovery.sql")),
+    ("0007_identities", include_str!("../migrations/0007_identities.sql")),
+    ("0008_api_keys", include_str!("../migrations/0008_api_keys.sql")),
+    ("0009_regions", include_str!("../migrations/0009_regions.sql")),
+    ("0010_replay_popularity", include_str!("../migrations/0010_replay_popularity.sql")),
+    ("0011_kudos", include_str!("../migrations/0011_kudos.sql")),
+    ("0012_digests", include_str!("../migrations/0012_digests.sql")),
+    ("0013_tenants", include_str!("../migrations/0013_tenants.sql")),
+    ("0014_normalized_scores", include_str!("../migrations/0014_normalized_scores.sql")),
+    ("0015_ratings", include_str!("../migrations/0015_ratings.sql")),
+    ("0016_splits", include_str!("../migrations/0016_splits.sql")),
+    ("0017_stealth_index", include_str!("../migrations/0017_stealth_index.sql")),
+    ("0018_rulesets", include_str!("../migrations/0018_rulesets.sql")),
+    ("0019_progression", include_str!("../migrations/0019_progression.sql")),
+    ("0020_regattas", include_str!("../migrations/0020_regattas.sql")),
+    ("0021_regatta_tracks", include_str!("../migrations/0021_regatta_tracks.sql")),
+    ("0022_tape_storage", include_str!("../migrations/0022_tape_storage.sql")),
+    ("0023_overtake_events", include_str!("../migrations/0023_overtake_events.sql")),
+    ("0024_signal_fires", include_str!("../migrations/0024_signal_fires.sql")),
+    ("0025_signal_fire_escrow", include_str!("../migrations/0025_signal_fire_escrow.sql")),
+    ("0026_signal_fire_trades", include_str!("../migrations/0026_signal_fire_trades.sql")),
+    ("0027_tide_contributions", include_str!("../migrations/0027_tide_contributions.sql")),
+    ("0028_experiment_outcomes", include_str!("../migrations/0028_experiment_outcomes.sql")),
+    ("0029_telemetry_events", include_str!("../migrations/0029_telemetry_events.sql")),
+    ("0030_analytics_export_watermarks", include_str!("../migrations/0030_analytics_export_watermarks.sql")),
+    ("0031_hourly_stats", include_str!("../migrations/0031_hourly_stats.sql")),
+    ("0032_tape_session_epoch_expiry", include_str!("../migrations/0032_tape_session_epoch_expiry.sql")),
+    ("0033_overview_indexes", include_str!("../migrations/0033_overview_indexes.sql")),
+    ("0034_ghost_tape_checksum", include_str!("../migrations/0034_ghost_tape_checksum.sql")),
+    ("0035_bottle_notes", include_str!("../migrations/0035_bottle_notes.sql")),
+    ("0036_run_ancestry", include_str!("../migrations/0036_run_ancestry.sql")),
+    ("0037_raids", include_str!("../migrations/0037_raids.sql")),
+    ("0038_player_divisions", include_str!("../migrations/0038_player_divisions.sql")),
+    ("0039_personal_goals", include_str!("../migrations/0039_personal_goals.sql")),
+    ("0040_coaching_queue", include_str!("../migrations/0040_coaching_queue.sql")),
+    ("0041_run_fingerprint", include_str!("../migrations/0041_run_fingerprint.sql")),
+    ("0042_leaderboard_finalizations", include_str!("../migrations/0042_leaderboard_finalizations.sql")),
+    ("0043_submission_nonces", include_str!("../migrations/0043_submission_nonces.sql")),
+    ("0044_flagged_submissions", include_str!("../migrations/0044_flagged_submissions.sql")),
+    ("0045_suspicion_scores", include_str!("../migrations/0045_suspicion_scores.sql")),
+    ("0046_moderation_bulk_actions", include_str!("../migrations/0046_moderation_bulk_actions.sql")),
+    ("0047_run_appeals", include_str!("../migrations/0047_run_appeals.sql")),
+    ("0048_community_events", include_str!("../migrations/0048_community_events.sql")),
+    ("0049_event_participation", include_str!("../migrations/0049_event_participation.sql")),
+    ("0050_news_items", include_str!("../migrations/0050_news_items.sql")),
+    ("0051_tuning_values", include_str!("../migrations/0051_tuning_values.sql")),
+    ("0052_run_modifier_snapshot", include_str!("../migrations/0052_run_modifier_snapshot.sql")),
+    ("0053_client_error_reports", include_str!("../migrations/0053_client_error_reports.sql")),
+    ("0054_ghost_desync_reports", include_str!("../migrations/0054_ghost_desync_reports.sql")),
+    ("0055_public_dumps", include_str!("../migrations/0055_public_dumps.sql")),
+    ("0056_scheduler_locks", include_str!("../migrations/0056_scheduler_locks.sql")),
+];
+
+/// Wraps the single SQLite connection the server runs against. Every route
+/// goes through `with_conn` rather than holding the connection directly, so
+/// pooling/read-replica support can be added later without touching callers.
+pub struct Db {
+    conn: Mutex<Connection>,
+    path: String,
+    slow_query_threshold: Duration,
+    slow_query_count: AtomicU64,
+    busy_retry_max_attempts: u32,
+    busy_retry_base_delay: Duration,
+    /// Bumped after every write. Handed back to clients as a "consistency
+    /// token" so a leaderboard fetch can prove it reflects a given write —
+    /// trivial today since every read goes through this same connection, but
+    /// load-bearing once a leaderboard cache/snapshot sits in front of reads.
+    write_version: AtomicU64,
+    /// Populated by the scheduler's weekly `quick_check` run; empty means
+    /// either healthy or not yet checked since startup.
+    last_integrity_problems: Mutex<Vec<String>>,
+    /// How many times `reopen` has replaced the connection after finding it
+    /// broken (poisoned mutex or a fatal error like the db file vanishing).
+    /// Nonzero is itself worth alerting on even though requests recover.
+    reopen_count: AtomicU64,
+    last_reopen_at: Mutex<Option<String>>,
+}
+
+impl Db {
+    pub fn open(
+        path: &str,
+        slow_query_threshold_ms: u64,
+        busy_retry_max_attempts: u32,
+        busy_retry_base_delay_ms: u64,
+    ) -> Result<Self, AppError> {
+        let conn = Self::open_connection(path)?;
+        let db = Db {
+            conn: Mutex::new(conn),
+            path: path.to_string(),
+            slow_query_threshold: Duration::from_millis(slow_query_threshold_ms),
+            slow_query_count: AtomicU64::new(0),
+            busy_retry_max_attempts,
+            busy_retry_base_delay: Duration::from_millis(busy_retry_base_delay_ms),
+            write_version: AtomicU64::new(0),
+            last_integrity_problems: Mutex::new(Vec::new()),
+            reopen_count: AtomicU64::new(0),
+            last_reopen_at: Mutex::new(None),
+        };
+        db.migrate()?;
+        Ok(db)
+    }
+
+    /// Opens a connection at `path` and applies the pragmas every connection
+    /// this process holds needs — shared by `open` (startup) and `reopen`
+    /// (self-healing) so they can't drift apart.
+    fn open_connection(path: &str) -> Result<Connection, AppError> {
+        let conn = Connection::open(path).map_err(|e| AppError::Db(e.to_string()))?;
+        conn.busy_timeout(Duration::from_secs(5))
+            .map_err(|e| AppError::Db(e.to_string()))?;
+        conn.pragma_update(None, "journal_mode", "WAL")
+            .map_err(|e| AppError::Db(e.to_string()))?;
+        Ok(conn)
+    }
+
+    fn migrate(&self) -> Result<(), AppError> {
+        self.with_conn(|conn| {
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS schema_migrations (name TEXT PRIMARY KEY);",
+            )?;
+            for (name, sql) in MIGRATIONS {
+                let already_applied: bool = conn
+                    .query_row(
+                        "SELECT EXISTS(SELECT 1 FROM schema_migrations WHERE name = ?1)",
+                        [name],
+                        |row| row.get(0),
+                    )
+                    .unwrap_or(false);
+                if already_applied {
+                    continue;
+                }
+                conn.execute_batch(sql)?;
+                conn.execute("INSERT INTO schema_migrations (name) VALUES (?1)", [name])?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn slow_query_count(&self) -> u64 {
+        self.slow_query_count.load(Ordering::Relaxed)
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Round-trips a trivial query and returns how long it took, for the
+    /// health endpoint. Not routed through `with_conn`'s slow-query
+    /// accounting since a health check probing latency isn't itself slow
+    /// application work.
+    pub fn ping(&self) -> Result<Duration, AppError> {
+        let mut conn = self.lock_conn()?;
+        let started = Instant::now();
+        let result = conn.query_row("SELECT 1", [], |_| Ok(()));
+        if let Err(err) = &result {
+            if !crate::error::is_busy_rusqlite_error(err) && Self::is_broken(&conn) {
+                self.reopen(&mut conn);
+            }
+        }
+        result.map_err(AppError::from)?;
+        Ok(started.elapsed())
+    }
+
+    pub fn reopen_count(&self) -> u64 {
+        self.reopen_count.load(Ordering::Relaxed)
+    }
+
+    pub fn last_reopen_at(&self) -> Option<String> {
+        self.last_reopen_at.lock().expect("last_reopen_at mutex poisoned").clone()
+    }
+
+    pub fn current_write_version(&self) -> u64 {
+        self.write_version.load(Ordering::Relaxed)
+    }
+
+    /// Call after a service commits a write that should be reflected by a
+    /// consistency token, and use the returned version as that token.
+    pub fn bump_write_version(&self) -> u64 {
+        self.write_version.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Runs `PRAGMA quick_check` (a fast structural check, not the full
+    /// `integrity_check`) and returns the list of problems reported — empty
+    /// means healthy. Called weekly by the scheduler; the exhaustive
+    /// `integrity_check` is reserved for the admin-triggered repair path
+    /// since it can take a long time on a large database.
+    pub fn quick_check(&self) -> Result<Vec<String>, AppError> {
+        let problems = self.with_conn(|conn| {
+            let mut stmt = conn.prepare("PRAGMA quick_check")?;
+            let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            let problems: Vec<String> =
+                rows.collect::<Result<Vec<_>, _>>()?.into_iter().filter(|line| line != "ok").collect();
+            Ok(problems)
+        })?;
+        *self.last_integrity_problems.lock().map_err(|e| AppError::Internal(e.to_string()))? = problems.clone();
+        Ok(problems)
+    }
+
+    pub fn last_integrity_problems(&self) -> Vec<String> {
+        self.last_integrity_problems.lock().expect("integrity problems mutex poisoned").clone()
+    }
+
+    /// Runs the exhaustive integrity check, then `VACUUM` and a WAL
+    /// checkpoint. Meant for an operator-triggered admin endpoint during low
+    /// traffic, not the periodic scheduler job — it holds the connection for
+    /// the duration and blocks every other request on the single connection.
+    pub fn repair(&self) -> Result<Vec<String>, AppError> {
+        let problems = self.with_conn(|conn| {
+            let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+            let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            let problems: Vec<String> =
+                rows.collect::<Result<Vec<_>, _>>()?.into_iter().filter(|line| line != "ok").collect();
+            conn.execute_batch("VACUUM; PRAGMA wal_checkpoint(TRUNCATE);")?;
+            Ok(problems)
+        })?;
+        Ok(problems)
+    }
+
+    /// Locks the connection mutex, recovering it if a previous holder
+    /// panicked while holding it instead of leaving it poisoned forever — a
+    /// panic mid-query doesn't corrupt the `Connection` value itself, and
+    /// `with_conn`/`with_tx` check the connection's actual health
+    /// separately via `is_broken` before deciding whether to reopen it.
+    fn lock_conn(&self) -> Result<MutexGuard<'_, Connection>, AppError> {
+        match self.conn.lock() {
+            Ok(guard) => Ok(guard),
+            Err(poisoned) => {
+                tracing::error!("database connection mutex was poisoned by a panicked holder; recovering it");
+                Ok(poisoned.into_inner())
+            }
+        }
+    }
+
+    /// True when `result` failed in a way that might mean the connection
+    /// itself is dead, as opposed to an application-level failure
+    /// (validation, a constraint violation, not-found) or transient
+    /// contention (`AppError::Busy`, already handled by `retry_busy` without
+    /// needing a new connection).
+    fn may_indicate_broken_conn<T>(result: &Result<T, AppError>) -> bool {
+        matches!(result, Err(e) if !matches!(e, AppError::Busy(_)))
+    }
+
+    /// A cheap probe distinguishing "this query failed for an application
+    /// reason" (constraint violation, bad SQL) from "this connection itself
+    /// is dead" (the underlying file was deleted, moved, or is no longer a
+    /// valid database) — only the latter calls for `reopen`.
+    fn is_broken(conn: &Connection) -> bool {
+        conn.query_row("SELECT 1", [], |_| Ok(())).is_err()
+    }
+
+    /// Replaces `*conn` with a freshly opened connection at `self.path`,
+    /// retrying with backoff since a vanished db file (an unmounted volume,
+    /// a moved-out-from-under-us path) may reappear within a few hundred
+    /// milliseconds. Logs and gives up silently on exhaustion — callers
+    /// still return their original error either way, this only affects
+    /// whether the *next* request recovers.
+    fn reopen(&self, conn: &mut MutexGuard<Connection>) {
+        let mut delay = Duration::from_millis(100);
+        for attempt in 1..=3 {
+            match Self::open_connection(&self.path) {
+                Ok(fresh) => {
+                    **conn = fresh;
+                    self.reopen_count.fetch_add(1, Ordering::Relaxed);
+                    if let Ok(mut last_reopen_at) = self.last_reopen_at.lock() {
+                        *last_reopen_at = Some(chrono::Utc::now().to_rfc3339());
+                    }
+                    tracing::error!(attempt, "database connection was broken and has been reopened");
+                    return;
+                }
+                Err(err) => {
+                    tracing::error!(attempt, %err, "failed to reopen broken database connection");
+                    std::thread::sleep(delay);
+                    delay *= 2;
+                }
+            }
+        }
+        tracing::error!("giving up on reopening the database connection; it will retry on the next request");
+    }
+
+    /// Runs `f` against the shared connection. Operations slower than
+    /// `slow_query_threshold` are logged (no SQL text or parameters, since
+    /// callers pass closures rather than query strings) and counted so
+    /// operators can see the connection is under contention. If `f` fails
+    /// and the connection itself turns out to be broken (not just this
+    /// query), it's reopened before returning so the *next* call recovers
+    /// instead of every request 500ing until a restart.
+    ///
+    /// Private: administrative paths that are neither a pure read nor a
+    /// service-level write (`migrate`, `quick_check`, `repair`) call this
+    /// directly. `ping` does its own health check since it needs `&mut
+    /// Connection` to reopen. Everything else goes through `with_read_conn`
+    /// or `with_write_conn` below.
+    fn with_conn<T>(&self, f: impl FnOnce(&Connection) -> Result<T, AppError>) -> Result<T, AppError> {
+        let mut conn = self.lock_conn()?;
+        let started = Instant::now();
+        let result = f(&conn);
+        if Self::may_indicate_broken_conn(&result) && Self::is_broken(&conn) {
+            self.reopen(&mut conn);
+        }
+        let elapsed = started.elapsed();
+        if elapsed > self.slow_query_threshold {
+            self.slow_query_count.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(elapsed_ms = elapsed.as_millis() as u64, "slow db operation");
+        }
+        result
+    }
+
+    /// Runs a read-only `f`. There's no reader pool or live replica yet — this
+    /// goes through the same single connection as `with_write_conn` — but
+    /// splitting the API now means services that only read already type-check
+    /// against the boundary a real reader handle will enforce later, instead
+    /// of every call site needing to be re-audited when one shows up.
+    /// `replication_service`'s snapshots are point-in-time backups, not a
+    /// live reader, so that day hasn't come yet.
+    ///
+    /// Not retried on `SQLITE_BUSY`: some callers close over a `&mut`
+    /// accumulator (e.g. pushing onto a shared `Vec` of validation
+    /// violations) and aren't safe to invoke twice. `with_tx` below is,
+    /// since a failed attempt always rolls back before a retry runs `f`
+    /// again from scratch.
+    pub fn with_read_conn<T>(&self, f: impl FnOnce(&Connection) -> Result<T, AppError>) -> Result<T, AppError> {
+        self.with_conn(f)
+    }
+
+    /// Runs `f` against the single writer connection. Every mutation should
+    /// go through here rather than a caller holding the connection directly,
+    /// so pooling/read-replica support can be added later without touching
+    /// call sites again.
+    ///
+    /// Not retried on `SQLITE_BUSY`: a closure here can run several
+    /// statements that each autocommit individually (see `with_tx` below),
+    /// so retrying the whole thing after a partial failure could re-apply
+    /// whatever already succeeded. Flows that need busy-retry safety should
+    /// use `with_tx` instead, which only ever commits all of its writes or
+    /// none of them.
+    pub fn with_write_conn<T>(&self, f: impl FnOnce(&Connection) -> Result<T, AppError>) -> Result<T, AppError> {
+        self.with_conn(f)
+    }
+
+    /// Runs `f` inside a real SQL transaction, committing if it returns `Ok`
+    /// and rolling back if it returns `Err` (or panics — rusqlite rolls back
+    /// on drop if `commit` was never called). Use this instead of
+    /// `with_write_conn` for any flow that makes more than one write and
+    /// needs them to land together, e.g. an insert plus the derived-state
+    /// updates it triggers; `with_write_conn`'s statements each autocommit
+    /// individually, so a failure partway through leaves earlier writes in
+    /// place.
+    ///
+    /// Since every caller already serializes through the single connection
+    /// mutex held for the duration of `f`, this doesn't add any new locking
+    /// behavior against other requests — it only changes what happens to
+    /// *this* request's own writes when one of them fails midway. This is
+    /// also the only `with_*` method retried on `SQLITE_BUSY`/`SQLITE_LOCKED`
+    /// (see `retry_busy`), since it's the only one where a failed attempt is
+    /// guaranteed to roll back everything it did before a retry runs `f`
+    /// again from scratch.
+    pub fn with_tx<T>(&self, f: impl Fn(&rusqlite::Transaction) -> Result<T, AppError>) -> Result<T, AppError> {
+        self.retry_busy(|| {
+            let mut conn = self.lock_conn()?;
+            let started = Instant::now();
+            let result = (|| {
+                let tx = conn.transaction().map_err(|e| AppError::Db(e.to_string()))?;
+                let value = f(&tx)?;
+                tx.commit().map_err(|e| AppError::Db(e.to_string()))?;
+                Ok(value)
+            })();
+            if Self::may_indicate_broken_conn(&result) && Self::is_broken(&conn) {
+                self.reopen(&mut conn);
+            }
+            let elapsed = started.elapsed();
+            if elapsed > self.slow_query_threshold {
+                self.slow_query_count.fetch_add(1, Ordering::Relaxed);
+                tracing::warn!(elapsed_ms = elapsed.as_millis() as u64, "slow db operation");
+            }
+            result
+        })
+    }
+
+    /// Retries `attempt` up to `busy_retry_max_attempts` times (so
+    /// `busy_retry_max_attempts + 1` calls total) while it keeps failing with
+    /// `AppError::Busy`, sleeping `busy_retry_base_delay * attempt_number`
+    /// plus up to that much again in jitter between tries. Any other error,
+    /// or success, returns immediately. Blocks the calling thread — same
+    /// tradeoff `with_conn` already makes by holding a `std::sync::Mutex`
+    /// across a query on an async handler's thread.
+    fn retry_busy<T>(&self, mut attempt: impl FnMut() -> Result<T, AppError>) -> Result<T, AppError> {
+        let mut tried = 0;
+        loop {
+            match attempt() {
+                Err(AppError::Busy(_)) if tried < self.busy_retry_max_attempts => {
+                    tried += 1;
+                    let jitter_ms = rand::thread_rng().gen_range(0..=self.busy_retry_base_delay.as_millis() as u64);
+                    let delay = self.busy_retry_base_delay * tried + Duration::from_millis(jitter_ms);
+                    tracing::warn!(attempt = tried, delay_ms = delay.as_millis() as u64, "retrying busy database");
+                    std::thread::sleep(delay);
+                }
+                other => return other,
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use axum::{http::StatusCode, response::{IntoResponse, Response}, Json};
+use serde_json::json;
+
+/// Error type shared by every route and service. New variants should map to a
+/// specific status code below rather than collapsing into `Internal`, so
+/// clients can branch on the JSON `error` field instead of parsing messages.
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    #[error("not found")]
+    NotFound,
+    #[error("validation failed: {0}")]
+    Validation(String),
+    #[error("database error: {0}")]
+    Db(String),
+    #[error("request body exceeds the limit for this route ({0} bytes)")]
+    PayloadTooLarge(u64),
+    #[error("polling too frequently, retry after {0}s")]
+    RateLimited(u64),
+    #[error("duplicate submission of run {0}")]
+    Duplicate(String),
+    /// The database was locked by another connection and stayed locked
+    /// through every retry — see `db::retry_busy`. Distinct from `Db` so
+    /// clients can tell "try again shortly" apart from a real failure.
+    #[error("database busy, retry after {0}s")]
+    Busy(u64),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+/// Retry-After value handed to a caller when a busy/locked error survives
+/// every retry in `db::retry_busy` and gets turned into a response.
+const BUSY_RETRY_AFTER_SECS: u64 = 1;
+
+/// True for the `rusqlite::Error` variants that mean "the database is
+/// locked right now" rather than a real query/schema/data problem. Shared
+/// by the `From` impl below and `db::Db`'s own broken-connection check,
+/// which needs to tell "transiently busy" apart from "actually dead"
+/// before it decides whether to reopen the connection.
+pub(crate) fn is_busy_rusqlite_error(err: &rusqlite::Error) -> bool {
+    matches!(
+        err,
+        rusqlite::Error::SqliteFailure(e, _)
+            if matches!(e.code, rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked)
+    )
+}
+
+impl From<rusqlite::Error> for AppError {
+    fn from(err: rusqlite::Error) -> Self {
+        if is_busy_rusqlite_error(&err) {
+            AppError::Busy(BUSY_RETRY_AFTER_SECS)
+        } else {
+            AppError::Db(err.to_string())
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let message = self.to_string();
+        let (status, code) = match &self {
+            AppError::NotFound => (StatusCode::NOT_FOUND, "not_found"),
+            AppError::Validation(_) => (StatusCode::BAD_REQUEST, "validation_failed"),
+            AppError::Db(_) => (StatusCode::INTERNAL_SERVER_ERROR, "db_error"),
+            AppError::PayloadTooLarge(_) => (StatusCode::PAYLOAD_TOO_LARGE, "payload_too_large"),
+            AppError::RateLimited(_) => (StatusCode::TOO_MANY_REQUESTS, "rate_limited"),
+            AppError::Duplicate(_) => (StatusCode::CONFLICT, "duplicate_submission"),
+            AppError::Busy(_) => (StatusCode::SERVICE_UNAVAILABLE, "busy"),
+            AppError::Internal(_) => (StatusCode::INTERNAL_SERVER_ERROR, "internal_error"),
+        };
+
+        let error_key = format!("error.{code}");
+
+        if let AppError::RateLimited(retry_after_secs) = self {
+            let body = Json(json!({ "error": code, "message": message, "retry_after_secs": retry_after_secs }));
+            return (status, [("retry-after", retry_after_secs.to_string()), (ERROR_KEY_HEADER, error_key)], body).into_response();
+        }
+
+        if let AppError::Busy(retry_after_secs) = self {
+            let body = Json(json!({ "error": code, "message": message, "retry_after_secs": retry_after_secs }));
+            return (status, [("retry-after", retry_after_secs.to_string()), (ERROR_KEY_HEADER, error_key)], body).into_response();
+        }
+
+        if let AppError::Duplicate(run_id) = self {
+            let body = Json(json!({ "error": code, "message": message, "run_id": run_id }));
+            return (status, [(ERROR_KEY_HEADER, error_key)], body).into_response();
+        }
+
+        let body = Json(json!({ "error": code, "message": message }));
+        (status, [(ERROR_KEY_HEADER, error_key)], body).into_response()
+    }
+}
+
+pub type AppResult<T> = Result<T, AppError>;
+
+/// Carries the i18n catalog key for this error (e.g. `error.validation_failed`)
+/// out to `middleware::localize_error_response`, which strips it back off and
+/// uses it to add a localized `message` to the JSON body. Kept as a header
+/// rather than a body field set here, since this impl has no access to the
+/// request's `Accept-Language` — only the middleware wrapping the whole
+/// response cycle does.
+pub(crate) const ERROR_KEY_HEADER: &str = "x-error-key";
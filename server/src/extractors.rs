@@ -0,0 +1,275 @@
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{FromRequest, FromRequestParts, Request},
+    http::{header, request::Parts, HeaderMap},
+};
+use base64::Engine;
+use booty_hunt_core::{RunSubmission, WaveSplit};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::error::AppError;
+use crate::services::api_key_service;
+use crate::state::AppState;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// `RunSubmission`'s JSON field names, kept in sync by hand — used only to
+/// name unknown fields back to the client in strict mode, so a stray one
+/// doesn't need its own `#[serde(deny_unknown_fields)]` twin of the whole
+/// struct.
+const RUN_SUBMISSION_FIELDS: &[&str] = &[
+    "player_id",
+    "seed",
+    "ship_class",
+    "doctrine_id",
+    "score",
+    "waves",
+    "damage_dealt",
+    "max_combo",
+    "time_played",
+    "max_heat",
+    "victory",
+    "ghost_tape",
+    "ghost_tape_sha256",
+    "splits",
+    "ruleset_id",
+    "regatta_id",
+    "raced_run_id",
+    "submission_nonce",
+];
+
+/// Whether `x-strict-fields: 1` was sent, or the caller's `x-client-version`
+/// is at or above `strict_fields_min_client_version`. New clients can opt
+/// into strict validation immediately via the header; the config threshold
+/// lets a whole client generation be switched over at once without every
+/// build needing the header wired in by hand.
+fn wants_strict_fields(headers: &HeaderMap, min_client_version: Option<&str>) -> bool {
+    let explicit = headers.get("x-strict-fields").and_then(|v| v.to_str().ok()).map(|v| v == "1" || v == "true").unwrap_or(false);
+    if explicit {
+        return true;
+    }
+    let Some(min_version) = min_client_version else { return false };
+    let Some(client_version) = headers.get("x-client-version").and_then(|v| v.to_str().ok()) else { return false };
+    version_at_least(client_version, min_version)
+}
+
+/// Compares dot-separated numeric version strings component-wise (is
+/// `"1.4.0"` at or above `"1.3.9"`?). An unparsable version — an old client
+/// that never sent one, or sent garbage — is treated as below any threshold,
+/// which keeps strict mode opt-in-only for clients this check can't
+/// understand.
+fn version_at_least(version: &str, min_version: &str) -> bool {
+    fn parts(v: &str) -> Option<Vec<u64>> {
+        v.split('.').map(|p| p.parse().ok()).collect()
+    }
+    match (parts(version), parts(min_version)) {
+        (Some(version), Some(min_version)) => version >= min_version,
+        _ => false,
+    }
+}
+
+/// Names the top-level JSON keys on `value` that aren't recognized fields of
+/// `RunSubmission`, for the strict-mode rejection message.
+fn unknown_fields(value: &serde_json::Value) -> Vec<String> {
+    match value.as_object() {
+        Some(map) => map.keys().filter(|key| !RUN_SUBMISSION_FIELDS.contains(&key.as_str())).cloned().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Same fields as `RunSubmission`, but with the ghost tape as raw bytes
+/// instead of a base64 string — used for the msgpack wire format so uploads
+/// skip the ~33% base64 inflation.
+#[derive(Deserialize)]
+struct RunSubmissionBinary {
+    player_id: String,
+    seed: i64,
+    ship_class: String,
+    doctrine_id: String,
+    score: i64,
+    waves: i64,
+    damage_dealt: i64,
+    max_combo: i64,
+    time_played: i64,
+    max_heat: i64,
+    victory: bool,
+    ghost_tape: Option<Vec<u8>>,
+    splits: Option<Vec<WaveSplit>>,
+    ruleset_id: Option<String>,
+    regatta_id: Option<String>,
+    raced_run_id: Option<String>,
+    /// Added after the binary schema's initial release — `#[serde(default)]`
+    /// so a client built before this field existed still decodes.
+    #[serde(default)]
+    ghost_tape_sha256: Option<String>,
+    #[serde(default)]
+    submission_nonce: Option<String>,
+}
+
+/// Accepts a run submission as either `application/json` (ghost tape
+/// base64-encoded, the historical format) or `application/msgpack` (ghost
+/// tape as raw bytes), negotiated on the request's `Content-Type` header.
+///
+/// Strict unknown-field rejection (`wants_strict_fields`) only applies to
+/// the JSON path — `RunSubmissionBinary` is a fixed msgpack schema clients
+/// generate from a shared IDL rather than hand-typed JSON, so the class of
+/// bug this guards against (a typo'd field silently dropped) doesn't apply
+/// there the same way.
+pub struct RunSubmissionBody(pub RunSubmission);
+
+#[async_trait]
+impl FromRequest<AppState> for RunSubmissionBody {
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &AppState) -> Result<Self, Self::Rejection> {
+        let headers = req.headers().clone();
+        let content_type = headers.get(header::CONTENT_TYPE).and_then(|v| v.to_str().ok()).unwrap_or("application/json").to_string();
+        let min_client_version = state.config.current().strict_fields_min_client_version.clone();
+        let strict = wants_strict_fields(&headers, min_client_version.as_deref());
+
+        let bytes = Bytes::from_request(req, state)
+            .await
+            .map_err(|e| AppError::Validation(e.to_string()))?;
+
+        if content_type.starts_with("application/msgpack") {
+            let binary: RunSubmissionBinary = rmp_serde::from_slice(&bytes)
+                .map_err(|e| AppError::Validation(format!("invalid msgpack body: {e}")))?;
+            Ok(RunSubmissionBody(RunSubmission {
+                player_id: binary.player_id,
+                seed: binary.seed,
+                ship_class: binary.ship_class,
+                doctrine_id: binary.doctrine_id,
+                score: binary.score,
+                waves: binary.waves,
+                damage_dealt: binary.damage_dealt,
+                max_combo: binary.max_combo,
+                time_played: binary.time_played,
+                max_heat: binary.max_heat,
+                victory: binary.victory,
+                ghost_tape: binary
+                    .ghost_tape
+                    .map(|raw| base64::engine::general_purpose::STANDARD.encode(raw)),
+                ghost_tape_sha256: binary.ghost_tape_sha256,
+                splits: binary.splits,
+                ruleset_id: binary.ruleset_id,
+                regatta_id: binary.regatta_id,
+                raced_run_id: binary.raced_run_id,
+                submission_nonce: binary.submission_nonce,
+            }))
+        } else {
+            let value: serde_json::Value =
+                serde_json::from_slice(&bytes).map_err(|e| AppError::Validation(format!("invalid json body: {e}")))?;
+            if strict {
+                let unknown = unknown_fields(&value);
+                if !unknown.is_empty() {
+                    return Err(AppError::Validation(format!("unknown field(s), check for a client serialization bug: {}", unknown.join(", "))));
+                }
+            }
+            let submission: RunSubmission =
+                serde_json::from_value(value).map_err(|e| AppError::Validation(format!("invalid json body: {e}")))?;
+            Ok(RunSubmissionBody(submission))
+        }
+    }
+}
+
+/// Authenticates a community-tool request via `Authorization: Bearer <key>`,
+/// verifying the key against `api_keys` and requiring read-only scope. Route
+/// handlers that want a specific scope should check `.scope` themselves;
+/// there's only one scope today so this always succeeds for a valid key.
+pub struct ApiKeyAuth(pub api_key_service::AuthenticatedKey);
+
+#[async_trait]
+impl FromRequestParts<AppState> for ApiKeyAuth {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let header_value = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| AppError::Validation("missing authorization header".into()))?;
+
+        let key = header_value
+            .strip_prefix("Bearer ")
+            .ok_or_else(|| AppError::Validation("authorization header must be a bearer token".into()))?;
+
+        let authenticated = api_key_service::verify(&state.db, key)?;
+        Ok(ApiKeyAuth(authenticated))
+    }
+}
+
+/// Authenticates an operator request via `Authorization: Bearer <token>`
+/// against `config.admin_api_token`, gating every `/api/admin/*` route.
+/// Unlike `ApiKeyAuth` there's no per-caller identity to carry — admin access
+/// isn't tied to a player account, so this extractor carries no payload —
+/// and unlike a missing API key, a missing `admin_api_token` fails closed:
+/// an unconfigured deployment rejects every admin request rather than
+/// leaving the surface open until an operator gets around to setting one.
+pub struct AdminAuth;
+
+#[async_trait]
+impl FromRequestParts<AppState> for AdminAuth {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let configured = state
+            .config
+            .current()
+            .admin_api_token
+            .clone()
+            .ok_or_else(|| AppError::Validation("admin API is not configured on this deployment".into()))?;
+
+        let header_value = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| AppError::Validation("missing authorization header".into()))?;
+
+        let presented = header_value
+            .strip_prefix("Bearer ")
+            .ok_or_else(|| AppError::Validation("authorization header must be a bearer token".into()))?;
+
+        if !tokens_match(&configured, presented) {
+            return Err(AppError::Validation("invalid admin token".into()));
+        }
+        Ok(AdminAuth)
+    }
+}
+
+/// Constant-time comparison of the configured admin token against the one a
+/// caller presented, so a timing side-channel can't be used to guess it a
+/// byte at a time. There's no dedicated constant-time-compare crate in this
+/// tree, so this reuses the `hmac` crate already pulled in for `receipt.rs`:
+/// MAC both sides of a fixed message under each string as the key, then let
+/// `verify_slice` do the actual constant-time comparison of the two digests.
+fn tokens_match(configured: &str, presented: &str) -> bool {
+    let Ok(mut expected_mac) = HmacSha256::new_from_slice(configured.as_bytes()) else {
+        return false;
+    };
+    expected_mac.update(b"admin-auth");
+    let expected = expected_mac.finalize().into_bytes();
+
+    let Ok(mut presented_mac) = HmacSha256::new_from_slice(presented.as_bytes()) else {
+        return false;
+    };
+    presented_mac.update(b"admin-auth");
+    presented_mac.verify_slice(&expected).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_match_accepts_the_same_token() {
+        assert!(tokens_match("super-secret", "super-secret"));
+    }
+
+    #[test]
+    fn tokens_match_rejects_a_different_token() {
+        assert!(!tokens_match("super-secret", "guess"));
+    }
+}
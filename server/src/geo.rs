@@ -0,0 +1,45 @@
+//! Coarse region derivation for regional leaderboards. There's no bundled
+//! GeoIP database in this repo (and no network access to fetch one at
+//! runtime), so IP-based bucketing here is a rough octet-range heuristic —
+//! good enough to split a leaderboard into a handful of regions, not a
+//! substitute for a real GeoIP lookup. Self-hosters who want better
+//! accuracy should send the `X-Region` header from a reverse proxy that has
+//! one.
+
+use std::net::IpAddr;
+
+use crate::config::Config;
+
+const KNOWN_REGIONS: &[&str] = &["na", "eu", "sa", "as", "oc", "af"];
+
+/// Buckets an IPv4 address into a coarse region by its first octet. IPv6 and
+/// anything unparsable falls back to `None` rather than guessing.
+fn bucket_ip(ip: IpAddr) -> Option<&'static str> {
+    match ip {
+        IpAddr::V4(v4) => match v4.octets()[0] {
+            0..=99 => Some("na"),
+            100..=149 => Some("eu"),
+            150..=179 => Some("as"),
+            180..=199 => Some("sa"),
+            200..=219 => Some("oc"),
+            _ => Some("af"),
+        },
+        IpAddr::V6(_) => None,
+    }
+}
+
+/// Derives the region to record with a run submission. An explicit
+/// `X-Region` header always wins (trusted deployments — tournament clients,
+/// modded servers — can set it directly); otherwise the caller's IP is
+/// bucketed. Returns `None` if geo derivation is disabled, no signal is
+/// available, or the header value isn't a region this server recognizes.
+pub fn derive_region(config: &Config, region_header: Option<&str>, remote_ip: Option<IpAddr>) -> Option<String> {
+    if !config.geo_derivation_enabled {
+        return None;
+    }
+    if let Some(header) = region_header {
+        let normalized = header.to_ascii_lowercase();
+        return KNOWN_REGIONS.contains(&normalized.as_str()).then_some(normalized);
+    }
+    remote_ip.and_then(bucket_ip).map(str::to_string)
+}
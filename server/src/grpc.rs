@@ -0,0 +1,129 @@
+//! gRPC transport for the run/leaderboard services. Handlers here do no
+//! business logic themselves — they translate between protobuf messages and
+//! `booty_hunt_core` types and delegate to the same `services::*` functions
+//! the REST routes call, so behavior never diverges between transports.
+
+use tonic::{Request, Response, Status};
+
+use crate::services::leaderboard_service::{LeaderboardFilters, LeaderboardSort};
+use crate::services::run_service::RunPipelineExtensions;
+use crate::services::{leaderboard_service, run_service};
+use crate::state::AppState;
+use crate::tenant::DEFAULT_TENANT;
+
+pub mod proto {
+    tonic::include_proto!("booty_hunt");
+}
+
+use proto::run_service_server::{RunService, RunServiceServer};
+use proto::{LeaderboardReply, LeaderboardRequest, RunSubmissionReply, RunSubmissionRequest};
+
+pub struct GrpcRunService {
+    pub state: AppState,
+}
+
+#[tonic::async_trait]
+impl RunService for GrpcRunService {
+    async fn submit_run(
+        &self,
+        request: Request<RunSubmissionRequest>,
+    ) -> Result<Response<RunSubmissionReply>, Status> {
+        let req = request.into_inner();
+        let ghost_tape = (!req.ghost_tape.is_empty())
+            .then(|| base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &req.ghost_tape));
+
+        let submission = booty_hunt_core::RunSubmission {
+            player_id: req.player_id,
+            seed: req.seed,
+            ship_class: req.ship_class,
+            doctrine_id: req.doctrine_id,
+            score: req.score,
+            waves: req.waves,
+            damage_dealt: req.damage_dealt,
+            max_combo: req.max_combo,
+            time_played: req.time_played,
+            max_heat: req.max_heat,
+            victory: req.victory,
+            ghost_tape,
+            // Split times, ruleset submission, regatta tagging, checksum
+            // verification, and ghost ancestry aren't in the proto yet — the
+            // C++ client doesn't report them over gRPC.
+            ghost_tape_sha256: None,
+            splits: None,
+            ruleset_id: None,
+            regatta_id: None,
+            raced_run_id: None,
+            submission_nonce: None,
+        };
+
+        // gRPC clients don't go through the HTTP `X-Region`/`X-Tenant-Id`
+        // header path yet; a future pass can pull these from gRPC metadata.
+        let result = run_service::submit_run(
+            &self.state.db,
+            &self.state.config.current(),
+            submission,
+            None,
+            DEFAULT_TENANT,
+            RunPipelineExtensions {
+                run_hooks: &self.state.run_hooks,
+                tape_blob_store: self.state.tape_blob_store.as_ref(),
+                notification_providers: &self.state.notification_providers,
+            },
+        )
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(RunSubmissionReply {
+            run_id: result.run_id,
+            rank: result.rank,
+            week_key: result.week_key,
+            score_mismatch: result.score_mismatch,
+            consistency_token: result.consistency_token,
+            receipt: result.receipt,
+        }))
+    }
+
+    async fn get_leaderboard(
+        &self,
+        request: Request<LeaderboardRequest>,
+    ) -> Result<Response<LeaderboardReply>, Status> {
+        let req = request.into_inner();
+        let week_key = if req.week_key.is_empty() { run_service::current_week_key() } else { req.week_key };
+        let limit = if req.limit > 0 { req.limit.clamp(1, 500) } else { 100 };
+
+        let region = (!req.region.is_empty()).then_some(req.region.as_str());
+        // The unified (normalized-score) category isn't exposed over gRPC
+        // yet — the C++ client only asks for raw-score boards today.
+        let entries = leaderboard_service::fetch_leaderboard(
+            &self.state.db,
+            DEFAULT_TENANT,
+            &week_key,
+            limit,
+            // Rulesets, divisions, and omens aren't selectable over gRPC yet
+            // — the C++ client only asks for the tenant's default board
+            // today.
+            LeaderboardFilters { region, ..Default::default() },
+            LeaderboardSort::Score,
+        )
+        .map_err(|e| Status::internal(e.to_string()))?
+            .into_iter()
+            .map(|e| proto::LeaderboardEntry {
+                rank: e.rank,
+                run_id: e.run_id,
+                player_id: e.player_id,
+                player_name: e.player_name,
+                ship_class: e.ship_class,
+                score: e.score,
+                victory: e.victory,
+                created_at: e.created_at,
+                region: e.region.unwrap_or_default(),
+            })
+            .collect();
+
+        Ok(Response::new(LeaderboardReply { entries }))
+    }
+}
+
+pub fn service(state: AppState) -> RunServiceServer<GrpcRunService> {
+    RunServiceServer::new(GrpcRunService { state })
+}
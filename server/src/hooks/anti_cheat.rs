@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use booty_hunt_core::RunSubmission;
+
+use super::RunHook;
+use crate::error::{AppError, AppResult};
+
+/// A coarse sanity check ahead of the tape-recompute anti-cheat in
+/// `run_service` (which needs a ghost tape to work at all): rejects scores
+/// that no plausible run could reach, so obviously-forged submissions
+/// without a tape don't even make it to the leaderboard for a hook or human
+/// to review later.
+pub struct ImplausibleScoreHook {
+    pub max_score_per_wave: i64,
+}
+
+#[async_trait]
+impl RunHook for ImplausibleScoreHook {
+    fn name(&self) -> &'static str {
+        "implausible_score"
+    }
+
+    async fn pre_validate(&self, submission: &RunSubmission) -> AppResult<()> {
+        let ceiling = self.max_score_per_wave * submission.waves.max(1);
+        if submission.score > ceiling {
+            return Err(AppError::Validation(format!(
+                "score {} exceeds plausible ceiling {ceiling} for {} waves",
+                submission.score, submission.waves
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(waves: i64, score: i64) -> RunSubmission {
+        RunSubmission {
+            player_id: "player-1".into(),
+            seed: 1,
+            ship_class: "sloop".into(),
+            doctrine_id: "boarding".into(),
+            score,
+            waves,
+            damage_dealt: 0,
+            max_combo: 0,
+            time_played: 600,
+            max_heat: 0,
+            victory: false,
+            ghost_tape: None,
+            ghost_tape_sha256: None,
+            splits: None,
+            ruleset_id: None,
+            regatta_id: None,
+            raced_run_id: None,
+            submission_nonce: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn accepts_a_score_within_the_per_wave_ceiling() {
+        let hook = ImplausibleScoreHook { max_score_per_wave: 1_000 };
+        hook.pre_validate(&submission(10, 10_000)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_a_score_beyond_the_per_wave_ceiling() {
+        let hook = ImplausibleScoreHook { max_score_per_wave: 1_000 };
+        let result = hook.pre_validate(&submission(10, 10_001)).await;
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn floors_waves_at_one_so_a_zero_wave_submission_still_gets_a_ceiling() {
+        let hook = ImplausibleScoreHook { max_score_per_wave: 1_000 };
+        let result = hook.pre_validate(&submission(0, 1_001)).await;
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
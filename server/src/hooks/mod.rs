@@ -0,0 +1,102 @@
+//! Plugin hooks for the run submission pipeline. Deployments that need
+//! custom logic — extra anti-cheat validation, posting to chat, whatever —
+//! implement `RunHook` instead of forking `run_service`. Every method has a
+//! default no-op so a hook only needs to override what it cares about.
+
+mod anti_cheat;
+mod webhook;
+
+use async_trait::async_trait;
+use booty_hunt_core::{LeaderboardEntry, RunSubmission, RunSubmissionResult};
+
+pub use anti_cheat::ImplausibleScoreHook;
+pub use webhook::WebhookRunHook;
+
+use crate::config::Config;
+use crate::error::AppResult;
+
+#[async_trait]
+pub trait RunHook: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Runs before a submission is persisted. Returning `Err` rejects the
+    /// submission entirely — this is the extension point for custom
+    /// anti-cheat rules beyond the built-in tape-recompute check.
+    async fn pre_validate(&self, _submission: &RunSubmission) -> AppResult<()> {
+        Ok(())
+    }
+
+    /// Runs after a submission is persisted and its rank computed. Errors
+    /// are logged and otherwise ignored — a hook failing here must never
+    /// undo an already-committed run.
+    async fn post_insert(&self, _submission: &RunSubmission, _result: &RunSubmissionResult) -> AppResult<()> {
+        Ok(())
+    }
+
+    /// Runs when a submission takes the top rank on its leaderboard.
+    /// Approximates "the leaderboard changed" without diffing the whole
+    /// board on every submission.
+    async fn on_leaderboard_change(&self, _entry: &LeaderboardEntry) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+/// Runs every hook's `pre_validate` in order, stopping at the first
+/// rejection.
+pub async fn run_pre_validate(hooks: &[Box<dyn RunHook>], submission: &RunSubmission) -> AppResult<()> {
+    for hook in hooks {
+        hook.pre_validate(submission).await?;
+    }
+    Ok(())
+}
+
+/// Runs every hook's `post_insert`, logging (not propagating) failures so
+/// one broken hook can't roll back a committed submission.
+pub async fn run_post_insert(hooks: &[Box<dyn RunHook>], submission: &RunSubmission, result: &RunSubmissionResult) {
+    for hook in hooks {
+        if let Err(err) = hook.post_insert(submission, result).await {
+            tracing::warn!(hook = hook.name(), %err, "run hook post_insert failed");
+        }
+    }
+}
+
+pub async fn run_on_leaderboard_change(hooks: &[Box<dyn RunHook>], entry: &LeaderboardEntry) {
+    for hook in hooks {
+        if let Err(err) = hook.on_leaderboard_change(entry).await {
+            tracing::warn!(hook = hook.name(), %err, "run hook on_leaderboard_change failed");
+        }
+    }
+}
+
+/// Builds the hooks every deployment runs on the submission pipeline.
+/// `ImplausibleScoreHook` is unconditional — a bare score ceiling costs
+/// nothing to run and is the only thing standing between an untaped
+/// submission and an unbounded `score` — while `WebhookRunHook` only joins
+/// the list when a deployment has actually configured a URL to post to.
+pub fn from_config(config: &Config) -> Vec<Box<dyn RunHook>> {
+    let mut hooks: Vec<Box<dyn RunHook>> = vec![Box::new(ImplausibleScoreHook { max_score_per_wave: config.max_submission_score_per_wave })];
+    if let Some(url) = &config.run_hook_webhook_url {
+        hooks.push(Box::new(WebhookRunHook::new(url.clone())));
+    }
+    hooks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn always_includes_the_implausible_score_hook() {
+        let hooks = from_config(&Config::from_env());
+        assert!(hooks.iter().any(|h| h.name() == "implausible_score"));
+        assert!(!hooks.iter().any(|h| h.name() == "webhook"));
+    }
+
+    #[test]
+    fn includes_the_webhook_hook_only_when_a_url_is_configured() {
+        let mut config = Config::from_env();
+        config.run_hook_webhook_url = Some("https://example.invalid/hook".into());
+        let hooks = from_config(&config);
+        assert!(hooks.iter().any(|h| h.name() == "webhook"));
+    }
+}
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use booty_hunt_core::{LeaderboardEntry, RunSubmission, RunSubmissionResult};
+
+use super::RunHook;
+use crate::error::AppResult;
+
+/// Posts run-pipeline events as JSON to a fixed URL — the same delivery shape
+/// as `notifications::webhook::WebhookProvider`, but a separate type since
+/// this fires on pipeline events for operators/chat bots rather than on
+/// per-player `NotificationEvent`s for a specific device.
+pub struct WebhookRunHook {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl WebhookRunHook {
+    pub fn new(url: String) -> Self {
+        WebhookRunHook { http: reqwest::Client::new(), url }
+    }
+
+    async fn post(&self, payload: serde_json::Value) -> AppResult<()> {
+        if let Err(err) = self.http.post(&self.url).json(&payload).send().await {
+            // A broken webhook shouldn't take down the pipeline; log and
+            // swallow rather than surfacing through `AppResult`.
+            tracing::warn!(%err, url = %self.url, "webhook hook delivery failed");
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RunHook for WebhookRunHook {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn post_insert(&self, submission: &RunSubmission, result: &RunSubmissionResult) -> AppResult<()> {
+        self.post(serde_json::json!({
+            "kind": "run_submitted",
+            "player_id": submission.player_id,
+            "ship_class": submission.ship_class,
+            "score": submission.score,
+            "victory": submission.victory,
+            "run_id": result.run_id,
+            "rank": result.rank,
+            "week_key": result.week_key,
+        }))
+        .await
+    }
+
+    async fn on_leaderboard_change(&self, entry: &LeaderboardEntry) -> AppResult<()> {
+        self.post(serde_json::json!({
+            "kind": "leaderboard_top_changed",
+            "run_id": entry.run_id,
+            "player_name": entry.player_name,
+            "ship_class": entry.ship_class,
+            "score": entry.score,
+        }))
+        .await
+    }
+}
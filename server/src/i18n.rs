@@ -0,0 +1,102 @@
+//! Small embedded translation catalog for message keys used by omens and
+//! error responses. Deliberately not loaded from disk or a database — a
+//! locale addition or wording tweak still goes through code review like any
+//! other copy change, and the catalog is tiny enough that compiling it in
+//! costs nothing.
+//!
+//! Catalog entries for error keys are generic per-error-kind phrases. The
+//! runtime detail some `AppError` variants interpolate (which field failed,
+//! the underlying db message) is only available in the English default —
+//! translating arbitrary interpolated content isn't practical for a static
+//! catalog, so non-English callers get the general phrase without it.
+
+/// Locales this catalog has translations for. Anything else in
+/// `Accept-Language` falls back to `"en"`.
+const SUPPORTED_LOCALES: &[&str] = &["en", "es", "fr"];
+
+const EN: &[(&str, &str)] = &[
+    ("error.not_found", "Not found"),
+    ("error.validation_failed", "Validation failed"),
+    ("error.db_error", "A database error occurred"),
+    ("error.payload_too_large", "Request body exceeds the limit for this route"),
+    ("error.rate_limited", "Polling too frequently"),
+    ("error.duplicate_submission", "This run was already submitted"),
+    ("error.busy", "The server is busy, please try again"),
+    ("error.internal_error", "Internal error"),
+    ("omen.fair_winds.name", "Fair Winds"),
+    ("omen.fair_winds.description", "Following seas favor speedrunners this week — time-played thresholds are forgiving."),
+    ("omen.kraken_stirring.name", "Kraken Stirring"),
+    ("omen.kraken_stirring.description", "Something big is circling below — expect tougher waves and richer loot."),
+    ("omen.blood_moon.name", "Blood Moon"),
+    ("omen.blood_moon.description", "Night falls red over the fleet — stealth runs are the ones worth bragging about."),
+];
+
+const ES: &[(&str, &str)] = &[
+    ("error.not_found", "No encontrado"),
+    ("error.validation_failed", "Validación fallida"),
+    ("error.db_error", "Se produjo un error de base de datos"),
+    ("error.payload_too_large", "El cuerpo de la solicitud excede el límite de esta ruta"),
+    ("error.rate_limited", "Sondeo demasiado frecuente"),
+    ("error.duplicate_submission", "Esta partida ya fue enviada"),
+    ("error.busy", "El servidor está ocupado, inténtalo de nuevo"),
+    ("error.internal_error", "Error interno"),
+    ("omen.fair_winds.name", "Vientos Favorables"),
+    ("omen.fair_winds.description", "Los mares en calma favorecen a los speedrunners esta semana."),
+    ("omen.kraken_stirring.name", "El Kraken Despierta"),
+    ("omen.kraken_stirring.description", "Algo grande merodea bajo las olas: esperen oleadas más duras y mejor botín."),
+    ("omen.blood_moon.name", "Luna de Sangre"),
+    ("omen.blood_moon.description", "La noche cae roja sobre la flota: las corridas sigilosas son las que valen la pena."),
+];
+
+const FR: &[(&str, &str)] = &[
+    ("error.not_found", "Introuvable"),
+    ("error.validation_failed", "Échec de la validation"),
+    ("error.db_error", "Une erreur de base de données est survenue"),
+    ("error.payload_too_large", "Le corps de la requête dépasse la limite de cette route"),
+    ("error.rate_limited", "Interrogation trop fréquente"),
+    ("error.duplicate_submission", "Cette partie a déjà été envoyée"),
+    ("error.busy", "Le serveur est occupé, veuillez réessayer"),
+    ("error.internal_error", "Erreur interne"),
+    ("omen.fair_winds.name", "Vents Favorables"),
+    ("omen.fair_winds.description", "Des mers calmes favorisent les speedrunners cette semaine."),
+    ("omen.kraken_stirring.name", "Le Kraken s'Éveille"),
+    ("omen.kraken_stirring.description", "Quelque chose de gros rôde sous la surface — vagues plus dures, butin plus riche."),
+    ("omen.blood_moon.name", "Lune de Sang"),
+    ("omen.blood_moon.description", "La nuit tombe rouge sur la flotte — les runs furtifs sont ceux qui comptent."),
+];
+
+fn catalog(locale: &str) -> &'static [(&'static str, &'static str)] {
+    match locale {
+        "es" => ES,
+        "fr" => FR,
+        _ => EN,
+    }
+}
+
+/// Looks up `key` in `locale`'s catalog, falling back to English if the key
+/// isn't translated there yet, and to the bare key itself if even English is
+/// missing it — a visible gap in the response is a better failure mode than
+/// a panic from a typo at a call site.
+pub fn lookup(key: &str, locale: &str) -> String {
+    catalog(locale)
+        .iter()
+        .find(|(k, _)| *k == key)
+        .or_else(|| EN.iter().find(|(k, _)| *k == key))
+        .map(|(_, v)| v.to_string())
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Picks the best supported locale from an `Accept-Language` header value
+/// (e.g. `"fr-CA,fr;q=0.9,en;q=0.8"`): the first comma-separated tag whose
+/// primary subtag matches a locale this catalog covers. Defaults to `"en"`
+/// when the header is missing, unparsable, or names nothing we support.
+pub fn negotiate(accept_language: Option<&str>) -> &'static str {
+    let Some(header) = accept_language else { return "en" };
+    for tag in header.split(',') {
+        let primary = tag.split(';').next().unwrap_or("").trim().split('-').next().unwrap_or("").to_ascii_lowercase();
+        if let Some(&supported) = SUPPORTED_LOCALES.iter().find(|&&locale| locale == primary) {
+            return supported;
+        }
+    }
+    "en"
+}
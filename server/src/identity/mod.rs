@@ -0,0 +1,38 @@
+mod steam;
+
+use async_trait::async_trait;
+
+pub use steam::SteamIdentityProvider;
+
+use crate::config::Config;
+
+pub struct ExternalIdentity {
+    pub external_id: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum IdentityError {
+    #[error("identity proof rejected: {0}")]
+    Rejected(String),
+}
+
+/// Verifies an external identity proof (a Steam auth ticket, an OIDC id
+/// token) and returns the stable external id to anchor a player's account
+/// to, without the server needing to know provider-specific verification
+/// details anywhere else.
+#[async_trait]
+pub trait IdentityProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn verify(&self, proof: &str) -> Result<ExternalIdentity, IdentityError>;
+}
+
+/// Builds the identity providers a deployment has configured.
+/// `SteamIdentityProvider` only joins the list when `steam_web_api_key` is
+/// set — like `hooks::from_config`, there's no unconditional provider here.
+pub fn from_config(config: &Config) -> Vec<Box<dyn IdentityProvider>> {
+    let mut providers: Vec<Box<dyn IdentityProvider>> = Vec::new();
+    if let Some(web_api_key) = &config.steam_web_api_key {
+        providers.push(Box::new(SteamIdentityProvider { web_api_key: web_api_key.clone() }));
+    }
+    providers
+}
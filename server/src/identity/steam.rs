@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use super::{ExternalIdentity, IdentityError, IdentityProvider};
+
+/// Verifies a Steam auth session ticket against Steam's `ISteamUserAuth` web
+/// API. The actual HTTP call is intentionally left for whoever wires in a
+/// Steam Web API key for their deployment — this crate ships the trait and
+/// plumbing, not a hosted secret.
+pub struct SteamIdentityProvider {
+    pub web_api_key: String,
+}
+
+#[async_trait]
+impl IdentityProvider for SteamIdentityProvider {
+    fn name(&self) -> &'static str {
+        "steam"
+    }
+
+    async fn verify(&self, proof: &str) -> Result<ExternalIdentity, IdentityError> {
+        if proof.is_empty() {
+            return Err(IdentityError::Rejected("empty auth ticket".into()));
+        }
+        // TODO: call ISteamUserAuth/AuthenticateUserTicket with self.web_api_key
+        // once a deployment supplies one; until then this is a structural stub.
+        let _ = &self.web_api_key;
+        Err(IdentityError::Rejected("steam verification not configured".into()))
+    }
+}
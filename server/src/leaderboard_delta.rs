@@ -0,0 +1,86 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+
+use booty_hunt_core::LeaderboardEntry;
+
+/// How many past snapshots one scope keeps. A poller further behind than
+/// this just gets a full board back — see `delta_since`.
+const HISTORY_LEN: usize = 64;
+
+struct Snapshot {
+    version: u64,
+    entries: Vec<LeaderboardEntry>,
+}
+
+/// Opportunistic history of top-N leaderboard snapshots, keyed by a scope
+/// string (tenant/week/limit — see `routes::leaderboard::delta_scope`), so
+/// `GET /api/leaderboard?since_version=` can return only what changed rather
+/// than the whole board. Snapshots are recorded from whatever a `GET`
+/// happens to fetch, not on every write — a scope nobody is polling costs
+/// nothing to track, and a scope under heavy finale polling ends up with
+/// fine-grained history for free.
+pub struct LeaderboardDeltaLog {
+    history: Mutex<HashMap<String, VecDeque<Snapshot>>>,
+}
+
+impl LeaderboardDeltaLog {
+    pub fn new() -> Self {
+        LeaderboardDeltaLog { history: Mutex::new(HashMap::new()) }
+    }
+
+    /// Records `entries` as the board's state as of `version`. A no-op if
+    /// the scope's most recent snapshot is already at this version.
+    pub fn record(&self, scope: &str, version: u64, entries: &[LeaderboardEntry]) {
+        let mut history = self.history.lock().expect("leaderboard delta mutex poisoned");
+        let log = history.entry(scope.to_string()).or_default();
+        if log.back().is_some_and(|s| s.version >= version) {
+            return;
+        }
+        log.push_back(Snapshot { version, entries: entries.to_vec() });
+        while log.len() > HISTORY_LEN {
+            log.pop_front();
+        }
+    }
+
+    /// Diffs the scope's latest recorded snapshot against the newest one at
+    /// or before `since_version`. Returns `(changed, removed_run_ids)` where
+    /// `changed` is every entry whose `(run_id, rank)` differs from the
+    /// baseline (new entries into the window and re-ranked ones alike).
+    /// Returns `None` when the scope has no history back to `since_version`
+    /// — its oldest retained snapshot is already newer — so the caller
+    /// should fall back to a full fetch.
+    pub fn delta_since(&self, scope: &str, since_version: u64) -> Option<(Vec<LeaderboardEntry>, Vec<String>)> {
+        let history = self.history.lock().expect("leaderboard delta mutex poisoned");
+        let log = history.get(scope)?;
+        let oldest = log.front()?;
+        if oldest.version > since_version {
+            return None;
+        }
+        let baseline = log.iter().rev().find(|s| s.version <= since_version)?;
+        let latest = log.back()?;
+
+        let baseline_by_id: HashMap<&str, i64> =
+            baseline.entries.iter().map(|e| (e.run_id.as_str(), e.rank)).collect();
+        let latest_ids: HashSet<&str> = latest.entries.iter().map(|e| e.run_id.as_str()).collect();
+
+        let changed: Vec<LeaderboardEntry> = latest
+            .entries
+            .iter()
+            .filter(|e| baseline_by_id.get(e.run_id.as_str()) != Some(&e.rank))
+            .cloned()
+            .collect();
+        let removed: Vec<String> = baseline_by_id
+            .keys()
+            .filter(|id| !latest_ids.contains(*id))
+            .map(|id| id.to_string())
+            .collect();
+
+        Some((changed, removed))
+    }
+}
+
+impl Default for LeaderboardDeltaLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,118 @@
+mod blob;
+mod config;
+mod db;
+mod error;
+mod extractors;
+mod geo;
+mod grpc;
+mod hooks;
+mod i18n;
+mod identity;
+mod leaderboard_delta;
+mod middleware;
+mod moderation;
+mod notifications;
+mod pagination;
+mod popularity;
+mod presence;
+mod rate_limit;
+mod receipt;
+mod request_metrics;
+mod routes;
+mod scheduler;
+mod scheduler_status;
+mod scoring;
+mod services;
+mod startup_check;
+mod state;
+mod tenant;
+#[cfg(test)]
+mod test_support;
+mod transfer_metrics;
+
+use std::sync::Arc;
+
+use config::{Config, ConfigHandle};
+use db::Db;
+use state::AppState;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt::init();
+
+    let config = Config::from_env();
+    let db = Db::open(
+        &config.db_path,
+        config.slow_query_threshold_ms,
+        config.db_busy_retry_max_attempts,
+        config.db_busy_retry_base_delay_ms,
+    )
+    .expect("failed to open database");
+
+    let startup_problems = startup_check::run(&db, &config);
+    for problem in &startup_problems {
+        tracing::warn!(problem, "startup check found a problem");
+    }
+
+    let config_handle = Arc::new(ConfigHandle::new(config));
+
+    let mut state = AppState::new(Arc::new(db), config_handle.clone());
+    state.startup_problems = Arc::new(startup_problems);
+    state.tape_blob_store = blob::from_config(&state.config.current()).await;
+    if let Some(store) = &state.tape_blob_store {
+        tracing::info!(backend = store.name(), "ghost tape blob storage backend configured");
+    }
+    state.run_hooks = Arc::new(hooks::from_config(&state.config.current()));
+    state.notification_providers = Arc::new(notifications::from_config(&state.config.current()));
+    state.identity_providers = Arc::new(identity::from_config(&state.config.current()));
+    scheduler::spawn_all(state.clone());
+    spawn_config_reload_signal(config_handle);
+    let app = routes::router(state.clone());
+
+    // Bind addresses are read once at startup — rebinding a listening socket
+    // isn't a "hot reload" of a tunable, it's effectively a restart, so a
+    // deployment that needs to change these still needs to restart the
+    // process. Everything else on `Config` can change under a running server.
+    let config = state.config.current();
+    let grpc_addr = config.grpc_bind_addr.parse().expect("invalid grpc bind addr");
+    let grpc_server = tonic::transport::Server::builder()
+        .add_service(grpc::service(state))
+        .serve(grpc_addr);
+    tokio::spawn(async move {
+        tracing::info!(addr = %grpc_addr, "booty-hunt-server grpc listening");
+        if let Err(err) = grpc_server.await {
+            tracing::error!(%err, "grpc server exited");
+        }
+    });
+
+    let listener = tokio::net::TcpListener::bind(&config.bind_addr)
+        .await
+        .expect("failed to bind");
+    tracing::info!(addr = %config.bind_addr, "booty-hunt-server listening");
+    axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>())
+        .await
+        .expect("server error");
+}
+
+/// Reloads config on SIGHUP so an operator can change rate limits, aid
+/// pricing, feature flags, CORS origins, and the like on a busy regatta
+/// evening without restarting the process and dropping the SQLite
+/// connection. The same reload is also reachable via
+/// `POST /api/admin/config/reload` for deployments that can't send signals.
+/// Unix-only — `signal::unix` doesn't exist on Windows, and self-hosters
+/// running this in a container are the target audience anyway.
+#[cfg(unix)]
+fn spawn_config_reload_signal(config: Arc<ConfigHandle>) {
+    tokio::spawn(async move {
+        let mut hangup =
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()).expect("failed to register SIGHUP handler");
+        loop {
+            hangup.recv().await;
+            config.reload_from_env();
+            tracing::info!("config reloaded from environment (SIGHUP)");
+        }
+    });
+}
+
+#[cfg(not(unix))]
+fn spawn_config_reload_signal(_config: Arc<ConfigHandle>) {}
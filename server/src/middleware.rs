@@ -0,0 +1,78 @@
+use axum::{
+    body::Body,
+    extract::{Extension, Request, State},
+    http::header,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Per-route body size ceiling, attached via `.layer(Extension(BodyLimit(n)))`.
+/// Chunked-tape endpoints use a much larger limit than signal fire/tide
+/// endpoints, which never need more than a few hundred bytes.
+#[derive(Clone, Copy)]
+pub struct BodyLimit(pub u64);
+
+/// Rejects a request up front (via `Content-Length`) if it declares a body
+/// larger than the route's configured `BodyLimit`, returning a structured
+/// JSON 413 instead of the bare connection reset a streaming-only limit
+/// would produce.
+pub async fn enforce_body_limit(
+    Extension(limit): Extension<BodyLimit>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, AppError> {
+    if let Some(len) = req
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+    {
+        if len > limit.0 {
+            return Err(AppError::PayloadTooLarge(limit.0));
+        }
+    }
+    Ok(next.run(req).await)
+}
+
+/// Bumps `AppState::request_metrics` with every response's status code.
+/// Layered on the whole router (unlike `enforce_body_limit`, which is
+/// per-route), so `/api/admin/overview`'s error rate covers every endpoint.
+pub async fn record_request_metrics(State(state): State<AppState>, req: Request<Body>, next: Next) -> Response {
+    let response = next.run(req).await;
+    state.request_metrics.record(response.status());
+    response
+}
+
+/// Adds a localized `message` (plus its `message_key`) to any error body
+/// tagged with `crate::error::ERROR_KEY_HEADER`. Reads `Accept-Language` up
+/// front, since `AppError::into_response` runs with no view of the request
+/// and can only leave the key behind in a header for this layer to resolve.
+/// Layered on the whole router next to `record_request_metrics`, not
+/// per-route, so every error response gets the same treatment.
+pub async fn localize_error_response(req: Request<Body>, next: Next) -> Response {
+    let locale = crate::i18n::negotiate(req.headers().get(header::ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok()));
+    let response = next.run(req).await;
+
+    let Some(key) = response.headers().get(crate::error::ERROR_KEY_HEADER).and_then(|v| v.to_str().ok()).map(str::to_string) else {
+        return response;
+    };
+
+    let (mut parts, body) = response.into_parts();
+    parts.headers.remove(crate::error::ERROR_KEY_HEADER);
+
+    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+        return Response::from_parts(parts, Body::empty());
+    };
+    let Ok(mut json) = serde_json::from_slice::<serde_json::Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    if let Some(obj) = json.as_object_mut() {
+        obj.insert("message_key".to_string(), serde_json::Value::String(key.clone()));
+        obj.insert("message".to_string(), serde_json::Value::String(crate::i18n::lookup(&key, locale)));
+    }
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(serde_json::to_vec(&json).expect("json value always serializes")))
+}
@@ -0,0 +1,12 @@
+//! Shared profanity filtering for player-authored free text (bottle notes,
+//! and any future channel that needs the same check). Deliberately simple —
+//! a lowercase substring scan against a config-driven blocklist rather than
+//! a stemming/leetspeak-aware library, since the repo has no NLP dependency
+//! and this only needs to catch the obvious cases before a human moderator
+//! sees a report.
+
+/// True if `text` contains any blocked word as a substring, case-insensitive.
+pub fn contains_blocked_word(text: &str, blocked_words: &[String]) -> bool {
+    let lowered = text.to_lowercase();
+    blocked_words.iter().any(|word| !word.is_empty() && lowered.contains(&word.to_lowercase()))
+}
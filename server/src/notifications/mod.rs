@@ -0,0 +1,48 @@
+mod webhook;
+
+use async_trait::async_trait;
+
+pub use webhook::WebhookProvider;
+
+use crate::config::Config;
+
+/// Events the rest of the server can fan out to a player's registered
+/// devices. New event kinds get a new variant rather than a free-form string,
+/// so providers/templates can match exhaustively.
+#[derive(Debug, Clone)]
+pub enum NotificationEvent {
+    OvertakenInTop { rank: i64, week_key: String },
+    /// Not constructed from anywhere yet — there's no signal fire redemption
+    /// endpoint in this server — but the variant is in place for that
+    /// endpoint to fire when it lands, the same "columns/plumbing ahead of
+    /// the feature" pattern as `stats_service::record_redemption`.
+    #[allow(dead_code)]
+    SignalFireRedeemed { code: String },
+    GoalCompleted { goal_type: String, target: i64 },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NotifyError {
+    #[error("delivery failed: {0}")]
+    Delivery(String),
+}
+
+/// Implemented per push backend (Firebase, APNs, a generic webhook). The
+/// dispatcher is agnostic to which providers are configured.
+#[async_trait]
+pub trait NotificationProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn send(&self, device_token: &str, event: &NotificationEvent) -> Result<(), NotifyError>;
+}
+
+/// Builds the notification providers a deployment has configured.
+/// `WebhookProvider` only joins the list when `notification_webhook_url` is
+/// set — like `hooks::from_config`, there's no unconditional provider here,
+/// since unlike a run hook, delivering to nowhere isn't useful on its own.
+pub fn from_config(config: &Config) -> Vec<Box<dyn NotificationProvider>> {
+    let mut providers: Vec<Box<dyn NotificationProvider>> = Vec::new();
+    if let Some(url) = &config.notification_webhook_url {
+        providers.push(Box::new(WebhookProvider::new(url.clone())));
+    }
+    providers
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+
+use super::{NotificationEvent, NotificationProvider, NotifyError};
+
+/// Delivers events as a JSON POST to a fixed webhook URL (Discord, Slack, a
+/// self-hoster's own bot) rather than a per-device push token.
+pub struct WebhookProvider {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl WebhookProvider {
+    pub fn new(url: String) -> Self {
+        WebhookProvider { http: reqwest::Client::new(), url }
+    }
+}
+
+#[async_trait]
+impl NotificationProvider for WebhookProvider {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn send(&self, device_token: &str, event: &NotificationEvent) -> Result<(), NotifyError> {
+        let payload = match event {
+            NotificationEvent::OvertakenInTop { rank, week_key } => {
+                serde_json::json!({ "player": device_token, "kind": "overtaken", "rank": rank, "week_key": week_key })
+            }
+            NotificationEvent::SignalFireRedeemed { code } => {
+                serde_json::json!({ "player": device_token, "kind": "signal_fire_redeemed", "code": code })
+            }
+            NotificationEvent::GoalCompleted { goal_type, target } => {
+                serde_json::json!({ "player": device_token, "kind": "goal_completed", "goal_type": goal_type, "target": target })
+            }
+        };
+        self.http
+            .post(&self.url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| NotifyError::Delivery(e.to_string()))?;
+        Ok(())
+    }
+}
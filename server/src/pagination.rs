@@ -0,0 +1,34 @@
+//! Cursor helpers backing `booty_hunt_core::Page` — see that type's doc
+//! comment for the envelope shape. Endpoints that adopt paging encode a
+//! `(created_at, id)` keyset into an opaque cursor rather than exposing an
+//! offset, so paging stays correct even as new rows are inserted ahead of
+//! the cursor.
+//!
+//! Not every list endpoint has moved onto `Page` — `leaderboard` and
+//! `rating_service` keep their own envelope (`LeaderboardResponse`), which
+//! already carries a write-version and delta history that a generic pager
+//! doesn't model. Wrapping it in `Page` on top would mean keeping two
+//! versioning schemes in sync for no client-visible benefit.
+
+use base64::Engine;
+
+use crate::error::{AppError, AppResult};
+
+/// Encodes a page's resume point. `id` is a tiebreaker for rows that share a
+/// `created_at` timestamp — without it, two same-second rows could be
+/// skipped or repeated across a page boundary.
+pub fn encode_cursor(created_at: &str, id: &str) -> String {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(format!("{created_at}\u{0}{id}"))
+}
+
+/// Decodes a cursor produced by `encode_cursor`. Any malformed input
+/// (tampered, truncated, or from a different endpoint's keyset) is a
+/// validation error rather than a panic or a silently-wrong page.
+pub fn decode_cursor(cursor: &str) -> AppResult<(String, String)> {
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(cursor)
+        .map_err(|_| AppError::Validation("invalid pagination cursor".into()))?;
+    let text = String::from_utf8(bytes).map_err(|_| AppError::Validation("invalid pagination cursor".into()))?;
+    let (created_at, id) = text.split_once('\u{0}').ok_or_else(|| AppError::Validation("invalid pagination cursor".into()))?;
+    Ok((created_at.to_string(), id.to_string()))
+}
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::db::Db;
+use crate::error::AppResult;
+
+/// Batches ghost tape download counts in memory and flushes them to
+/// `replay_downloads` on the scheduler's interval, so a popular replay
+/// doesn't turn into a row-per-download write storm on the single connection.
+pub struct PopularityCounters {
+    pending: Mutex<HashMap<String, u64>>,
+}
+
+impl PopularityCounters {
+    pub fn new() -> Self {
+        PopularityCounters { pending: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn record_download(&self, run_id: &str) {
+        let mut pending = self.pending.lock().expect("popularity mutex poisoned");
+        *pending.entry(run_id.to_string()).or_insert(0) += 1;
+    }
+
+    /// Drains the pending counts and upserts them into the database. Called
+    /// by the scheduler; also safe to call with nothing pending.
+    pub fn flush(&self, db: &Db) -> AppResult<usize> {
+        let drained: Vec<(String, u64)> = {
+            let mut pending = self.pending.lock().expect("popularity mutex poisoned");
+            pending.drain().collect()
+        };
+        if drained.is_empty() {
+            return Ok(0);
+        }
+        db.with_write_conn(|conn| {
+            for (run_id, count) in &drained {
+                conn.execute(
+                    "INSERT INTO replay_downloads (run_id, download_count) VALUES (?1, ?2)
+                     ON CONFLICT(run_id) DO UPDATE SET download_count = download_count + excluded.download_count",
+                    rusqlite::params![run_id, count],
+                )?;
+            }
+            Ok(())
+        })?;
+        Ok(drained.len())
+    }
+}
+
+impl Default for PopularityCounters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
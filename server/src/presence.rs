@@ -0,0 +1,30 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Lightweight in-memory presence tracking for "N captains sailing this
+/// week's regatta seed" in the lobby. Deliberately not backed by the DB —
+/// heartbeats are frequent and losing presence on restart is fine.
+pub struct PresenceTracker {
+    last_seen: Mutex<HashMap<String, Instant>>,
+    ttl: Duration,
+}
+
+impl PresenceTracker {
+    pub fn new(ttl: Duration) -> Self {
+        PresenceTracker { last_seen: Mutex::new(HashMap::new()), ttl }
+    }
+
+    pub fn heartbeat(&self, player_id: &str) {
+        let mut map = self.last_seen.lock().expect("presence mutex poisoned");
+        map.insert(player_id.to_string(), Instant::now());
+    }
+
+    /// Evicts stale entries and returns the number of players still within TTL.
+    pub fn active_count(&self) -> usize {
+        let mut map = self.last_seen.lock().expect("presence mutex poisoned");
+        let now = Instant::now();
+        map.retain(|_, last_seen| now.duration_since(*last_seen) < self.ttl);
+        map.len()
+    }
+}
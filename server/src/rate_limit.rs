@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Fixed-window poll budget per client key (bearer token, or source IP for
+/// anonymous callers), plus a running count of polls per client version for
+/// the admin poll-rate endpoint. Purely in-memory like `PresenceTracker` —
+/// losing counts on restart is fine, this exists to catch abusive polling,
+/// not to bill anyone.
+pub struct PollLimiter {
+    windows: Mutex<HashMap<String, Window>>,
+    client_versions: Mutex<HashMap<String, u64>>,
+}
+
+struct Window {
+    started_at: Instant,
+    count: u32,
+}
+
+impl PollLimiter {
+    pub fn new() -> Self {
+        PollLimiter { windows: Mutex::new(HashMap::new()), client_versions: Mutex::new(HashMap::new()) }
+    }
+
+    /// Returns `Ok(())` if `key` is still within `budget` requests for the
+    /// current `window`, else `Err(retry_after_secs)`. A window older than
+    /// `window` resets rather than accumulating forever.
+    pub fn check(&self, key: &str, budget: u32, window: Duration) -> Result<(), u64> {
+        let mut windows = self.windows.lock().expect("poll limiter mutex poisoned");
+        let now = Instant::now();
+        let entry = windows.entry(key.to_string()).or_insert_with(|| Window { started_at: now, count: 0 });
+        if now.duration_since(entry.started_at) >= window {
+            entry.started_at = now;
+            entry.count = 0;
+        }
+        entry.count += 1;
+        if entry.count > budget {
+            let retry_after = window.saturating_sub(now.duration_since(entry.started_at)).as_secs().max(1);
+            return Err(retry_after);
+        }
+        Ok(())
+    }
+
+    pub fn record_client_version(&self, version: &str) {
+        let mut versions = self.client_versions.lock().expect("poll limiter mutex poisoned");
+        *versions.entry(version.to_string()).or_insert(0) += 1;
+    }
+
+    /// Snapshot of poll counts by client version since this process started,
+    /// for `GET /api/admin/leaderboard/poll-stats`.
+    pub fn client_version_counts(&self) -> HashMap<String, u64> {
+        self.client_versions.lock().expect("poll limiter mutex poisoned").clone()
+    }
+}
+
+impl Default for PollLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,34 @@
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs `run_id`/`score`/`week_key` with the server's `receipt_signing_secret`,
+/// producing an opaque token a client can hand to a third-party tournament
+/// organizer as proof this run was accepted — without the organizer needing
+/// API access to this server, only `verify`'s inputs and this same secret (or
+/// the `/api/receipts/verify` endpoint below).
+pub fn sign(secret: &str, run_id: &str, score: i64, week_key: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts a key of any length");
+    mac.update(payload(run_id, score, week_key).as_bytes());
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+}
+
+/// Recomputes the signature over the given fields and compares it against
+/// `signature` in constant time. `false` for a malformed (non-base64)
+/// signature as well as a mismatched one.
+pub fn verify(secret: &str, run_id: &str, score: i64, week_key: &str, signature: &str) -> bool {
+    let Ok(sig_bytes) = base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(signature) else {
+        return false;
+    };
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(payload(run_id, score, week_key).as_bytes());
+    mac.verify_slice(&sig_bytes).is_ok()
+}
+
+fn payload(run_id: &str, score: i64, week_key: &str) -> String {
+    format!("{run_id}|{score}|{week_key}")
+}
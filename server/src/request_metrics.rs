@@ -0,0 +1,62 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use axum::http::StatusCode;
+
+/// Process-wide request/error counters, bumped once per response by the
+/// `record` middleware in `middleware.rs`. Backs `GET /api/admin/overview`'s
+/// error rate with a couple of atomic loads instead of parsing access logs
+/// or scanning a request-log table this server doesn't keep. Lost on
+/// restart like every other in-memory tracker here (`PollLimiter`,
+/// `SchedulerStatus`) — an operator restarting the process to look at a
+/// clean rate is a feature, not a bug.
+pub struct RequestMetrics {
+    total: AtomicU64,
+    client_errors: AtomicU64,
+    server_errors: AtomicU64,
+}
+
+pub struct RequestMetricsSnapshot {
+    pub total: u64,
+    pub client_errors: u64,
+    pub server_errors: u64,
+}
+
+impl RequestMetricsSnapshot {
+    /// `(client_errors + server_errors) / total`, or `0.0` before the first
+    /// request has landed.
+    pub fn error_rate(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        (self.client_errors + self.server_errors) as f64 / self.total as f64
+    }
+}
+
+impl RequestMetrics {
+    pub fn new() -> Self {
+        RequestMetrics { total: AtomicU64::new(0), client_errors: AtomicU64::new(0), server_errors: AtomicU64::new(0) }
+    }
+
+    pub fn record(&self, status: StatusCode) {
+        self.total.fetch_add(1, Ordering::Relaxed);
+        if status.is_server_error() {
+            self.server_errors.fetch_add(1, Ordering::Relaxed);
+        } else if status.is_client_error() {
+            self.client_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn snapshot(&self) -> RequestMetricsSnapshot {
+        RequestMetricsSnapshot {
+            total: self.total.load(Ordering::Relaxed),
+            client_errors: self.client_errors.load(Ordering::Relaxed),
+            server_errors: self.server_errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for RequestMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
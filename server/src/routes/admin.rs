@@ -0,0 +1,289 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use booty_hunt_core::{
+    BulkRunActionRequest, BulkRunActionResult, CampaignAnalytics, EconomyAudit, ExperimentVariantReport, FlaggedSubmission,
+    LiveOpsOverview, RegattaParticipation, ResolveAppealRequest,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::error::AppResult;
+use crate::extractors::AdminAuth;
+use crate::services::{
+    admin_action_service, analytics_export_service, appeal_service, economy_service, experiment_service, moderation_queue_service,
+    raid_service, regatta_service, replication_service, run_service, signal_fire_service, stats_service, tide_service,
+};
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Runs a full integrity check plus `VACUUM`/WAL checkpoint. Intended for an
+/// operator to trigger during low traffic.
+pub async fn repair(_admin: AdminAuth, State(state): State<AppState>) -> AppResult<Json<Value>> {
+    let problems = state.db.repair()?;
+    Ok(Json(json!({ "problems": problems })))
+}
+
+#[derive(Deserialize)]
+pub struct EconomyQuery {
+    week_key: Option<String>,
+}
+
+/// Aggregate signal fire economy flows for one week, so designers can tune
+/// `aid_amount` caps and `heat_cost` from data.
+pub async fn economy(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    Query(query): Query<EconomyQuery>,
+) -> AppResult<Json<EconomyAudit>> {
+    let week_key = query.week_key.unwrap_or_else(run_service::current_week_key);
+    let audit = economy_service::audit(&state.db, &week_key)?;
+    Ok(Json(audit))
+}
+
+/// Poll counts per `x-client-version` seen on `GET /api/leaderboard` since
+/// this process started, so we can tell whether an aggressive polling spike
+/// is coming from one bad client build before rolling out a lower
+/// `leaderboard_poll_budget`.
+pub async fn leaderboard_poll_stats(_admin: AdminAuth, State(state): State<AppState>) -> Json<Value> {
+    Json(json!({ "polls_by_client_version": state.poll_limiter.client_version_counts() }))
+}
+
+/// Ghost tape download counts/bytes since this process started, so an
+/// operator can see whether bandwidth is dominated by a few large mirrors
+/// before reaching for `ghost_download_ip_rate_limit_budget`.
+pub async fn ghost_transfer_stats(_admin: AdminAuth, State(state): State<AppState>) -> Json<Value> {
+    let snapshot = state.ghost_transfer_metrics.snapshot();
+    Json(json!({ "downloads": snapshot.downloads, "bytes": snapshot.bytes }))
+}
+
+/// Takes an out-of-band replication snapshot on demand, on top of the
+/// scheduler's periodic ones — useful right before a risky migration or
+/// maintenance window. Works even when `replication_enabled` is off, since an
+/// operator asking for one is consent enough.
+pub async fn replicate_now(_admin: AdminAuth, State(state): State<AppState>) -> AppResult<Json<Value>> {
+    let path = replication_service::snapshot(&state.db, &state.config.current().replication_dest_dir)?;
+    Ok(Json(json!({ "path": path })))
+}
+
+/// Takes an out-of-band incremental analytics export on demand, on top of
+/// the scheduler's periodic ones. Works even when `analytics_export_enabled`
+/// is off, same reasoning as `replicate_now`.
+pub async fn export_analytics_now(_admin: AdminAuth, State(state): State<AppState>) -> AppResult<Json<Value>> {
+    let paths = analytics_export_service::export_all(&state.db, &state.config.current().analytics_export_dest_dir)?;
+    Ok(Json(json!({ "paths": paths })))
+}
+
+/// Reloads config from the environment without restarting — the
+/// non-signal-based path to the same reload `main`'s SIGHUP handler
+/// triggers, for deployments that can't send Unix signals to the process
+/// (e.g. running under a supervisor that only exposes HTTP).
+pub async fn reload_config(_admin: AdminAuth, State(state): State<AppState>) -> AppResult<Json<Value>> {
+    state.config.reload_from_env();
+    Ok(Json(json!({ "reloaded": true })))
+}
+
+#[derive(Deserialize)]
+pub struct ModerationQueueQuery {
+    limit: Option<i64>,
+}
+
+/// Open canary/honeypot and high-suspicion flags awaiting review, highest
+/// suspicion score first — see `suspicion_service`.
+pub async fn moderation_queue(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Query(query): Query<ModerationQueueQuery>,
+) -> AppResult<Json<Vec<FlaggedSubmission>>> {
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    Ok(Json(moderation_queue_service::queue(&state.db, &tenant_id, limit)?))
+}
+
+/// Marks a flagged submission as reviewed, dropping it out of the queue.
+pub async fn resolve_moderation_flag(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(flag_id): Path<String>,
+) -> AppResult<Json<Value>> {
+    moderation_queue_service::resolve(&state.db, &tenant_id, &flag_id)?;
+    Ok(Json(json!({ "resolved": true })))
+}
+
+/// Hides, deletes, or bans the player behind a wave of runs at once —
+/// either an explicit `run_ids` list or every run in `week_key` scoring at
+/// or above `min_score`. Pass `dry_run: true` to see the affected run ids
+/// (and their count) without changing anything.
+pub async fn bulk_run_action(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<BulkRunActionRequest>,
+) -> AppResult<Json<BulkRunActionResult>> {
+    Ok(Json(admin_action_service::apply_bulk_action(&state.db, &tenant_id, req)?))
+}
+
+/// Records a moderator's decision on a filed appeal — `upheld` leaves the
+/// run hidden, `reinstated` un-hides it.
+pub async fn resolve_appeal(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+    Json(req): Json<ResolveAppealRequest>,
+) -> AppResult<Json<Value>> {
+    appeal_service::resolve_appeal(&state.db, &run_id, req.status)?;
+    Ok(Json(json!({ "resolved": true })))
+}
+
+#[derive(Deserialize)]
+pub struct MintSignalFireRequest {
+    /// A creator-chosen human-memorable code, e.g. announced live on stream.
+    /// Validated (charset, length, profanity) and rejected if already taken.
+    /// Omit to get a random 8-character code instead.
+    code: Option<String>,
+    aid_type: String,
+    aid_amount: i64,
+    campaign: Option<String>,
+    /// If given, escrows the fire against that run's verification instead of
+    /// releasing it immediately — see `signal_fire_service::mint_single`.
+    creator_run: Option<String>,
+}
+
+/// Mints a single signal fire, optionally with a caller-chosen vanity code
+/// instead of a random one. There's no separate creator-facing auth in this
+/// server yet, so both admins and streamers requesting a vanity code go
+/// through this same admin-gated endpoint for now.
+pub async fn mint_signal_fire(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<MintSignalFireRequest>,
+) -> AppResult<Json<Value>> {
+    let (code, status) = signal_fire_service::mint_single(
+        &state.db,
+        &tenant_id,
+        req.code.as_deref(),
+        &req.aid_type,
+        req.aid_amount,
+        req.campaign.as_deref(),
+        req.creator_run.as_deref(),
+    )?;
+    Ok(Json(json!({ "code": code, "status": status })))
+}
+
+#[derive(Deserialize)]
+pub struct MintSignalFiresRequest {
+    campaign: String,
+    aid_type: String,
+    aid_amount: i64,
+    count: u32,
+}
+
+/// Mints `count` single-use signal fires tagged with one campaign, for
+/// giveaways/streams that hand out a batch at once rather than one at a
+/// time. Returns the codes as CSV rather than JSON since the typical next
+/// step is dropping the file straight into a spreadsheet or a stream
+/// overlay tool.
+pub async fn mint_signal_fires(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<MintSignalFiresRequest>,
+) -> AppResult<Response> {
+    let codes =
+        signal_fire_service::mint_bulk(&state.db, &tenant_id, &req.campaign, &req.aid_type, req.aid_amount, req.count)?;
+
+    let mut csv = String::from("code\n");
+    for code in codes {
+        csv.push_str(&code);
+        csv.push('\n');
+    }
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/csv"), (header::CONTENT_DISPOSITION, "attachment; filename=\"signal_fires.csv\"")],
+        csv,
+    )
+        .into_response())
+}
+
+/// Redemption analytics for one campaign tag — how many codes were minted
+/// vs. redeemed and how much aid actually went out.
+pub async fn signal_fire_campaign(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(campaign): Path<String>,
+) -> AppResult<Json<CampaignAnalytics>> {
+    Ok(Json(signal_fire_service::campaign_analytics(&state.db, &tenant_id, &campaign)?))
+}
+
+/// One JSON payload for an internal live-ops dashboard: current regatta
+/// participation, a submissions-per-minute rate, the process error rate,
+/// active signal fires, and tide goal progress. Every field is sourced from
+/// a counter or index this server already keeps for its own purposes
+/// (`hourly_stats`, `RequestMetrics`, `idx_signal_fires_status`,
+/// `idx_runs_regatta_id`, `idx_tide_contributions_week_metric`) rather than
+/// an ad-hoc scan built just for this endpoint. `pending_reports` is always
+/// `0` — see its doc comment on `LiveOpsOverview`.
+pub async fn overview(_admin: AdminAuth, State(state): State<AppState>, TenantId(tenant_id): TenantId) -> AppResult<Json<LiveOpsOverview>> {
+    let config = state.config.current();
+    let week_key = run_service::current_week_key();
+
+    let submissions_this_hour = stats_service::current_hour_submissions(&state.db, &tenant_id)?;
+    let elapsed_minutes = {
+        use chrono::Timelike;
+        let now = chrono::Utc::now();
+        now.minute() as f64 + now.second() as f64 / 60.0
+    };
+    let submissions_per_minute = submissions_this_hour as f64 / elapsed_minutes.max(1.0);
+
+    let request_snapshot = state.request_metrics.snapshot();
+
+    let active_signal_fires = signal_fire_service::count_active(&state.db, &tenant_id)?;
+
+    let regattas = regatta_service::list_current(&state.db, &config, &tenant_id, &week_key)?
+        .into_iter()
+        .map(|regatta| {
+            let participant_runs = regatta_service::participation(&state.db, &tenant_id, &regatta.id)?;
+            Ok(RegattaParticipation { track: regatta.track, regatta_id: regatta.id, participant_runs })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+
+    let tide_progress = tide_service::current_progress(&state.db, &config, &tenant_id)?;
+    let raid = raid_service::status(&state.db, &config, &tenant_id)?;
+
+    Ok(Json(LiveOpsOverview {
+        submissions_per_minute,
+        total_requests: request_snapshot.total,
+        error_rate: request_snapshot.error_rate(),
+        active_signal_fires,
+        pending_reports: 0,
+        regattas,
+        tide_progress,
+        raid,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct ExperimentReportQuery {
+    metric: String,
+    week_key: Option<String>,
+}
+
+/// Per-variant sample counts and averages for one experiment, so designers
+/// can see which omen modifier variant is winning instead of guessing.
+pub async fn experiment_report(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(experiment_key): Path<String>,
+    Query(query): Query<ExperimentReportQuery>,
+) -> AppResult<Json<Vec<ExperimentVariantReport>>> {
+    let week_key = query.week_key.unwrap_or_else(run_service::current_week_key);
+    let report = experiment_service::report(&state.db, &tenant_id, &experiment_key, &week_key, &query.metric)?;
+    Ok(Json(report))
+}
@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use booty_hunt_core::{Page, PlayerRunSummary};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppResult;
+use crate::extractors::ApiKeyAuth;
+use crate::services::{api_key_service, run_service};
+use crate::state::AppState;
+
+#[derive(Deserialize)]
+pub struct IssueKeyRequest {
+    label: String,
+}
+
+#[derive(Serialize)]
+pub struct IssueKeyResponse {
+    key_id: String,
+    key: String,
+}
+
+pub async fn issue(
+    State(state): State<AppState>,
+    Path(player_id): Path<String>,
+    Json(req): Json<IssueKeyRequest>,
+) -> AppResult<Json<IssueKeyResponse>> {
+    let issued = api_key_service::issue(&state.db, &player_id, &req.label)?;
+    Ok(Json(IssueKeyResponse { key_id: issued.key_id, key: issued.plaintext_key }))
+}
+
+pub async fn revoke(
+    State(state): State<AppState>,
+    Path((player_id, key_id)): Path<(String, String)>,
+) -> AppResult<StatusCode> {
+    api_key_service::revoke(&state.db, &player_id, &key_id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Deserialize)]
+pub struct MyRunsQuery {
+    limit: Option<i64>,
+    /// A prior page's `next_cursor` — see `booty_hunt_core::Page`. Omitted
+    /// for the first page.
+    cursor: Option<String>,
+}
+
+/// Read-only endpoint for community tools: the caller's own runs, scoped to
+/// whichever player their API key was issued to.
+pub async fn my_runs(
+    State(state): State<AppState>,
+    ApiKeyAuth(auth): ApiKeyAuth,
+    Query(query): Query<MyRunsQuery>,
+) -> AppResult<Json<Page<PlayerRunSummary>>> {
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    let runs = run_service::list_for_player(&state.db, &auth.player_id, limit, query.cursor.as_deref())?;
+    Ok(Json(runs))
+}
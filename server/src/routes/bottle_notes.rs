@@ -0,0 +1,65 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use axum::extract::{ConnectInfo, Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use booty_hunt_core::{AttachBottleNoteRequest, BottleNote, Page};
+use serde::Deserialize;
+
+use crate::error::{AppError, AppResult};
+use crate::extractors::AdminAuth;
+use crate::services::bottle_note_service;
+use crate::state::AppState;
+
+#[derive(Deserialize)]
+pub struct ListNotesQuery {
+    limit: Option<i64>,
+    /// A prior page's `next_cursor` — see `booty_hunt_core::Page`. Omitted
+    /// for the first page.
+    cursor: Option<String>,
+}
+
+/// Notes other players left for this seed, newest first.
+pub async fn list(
+    State(state): State<AppState>,
+    Path(seed): Path<String>,
+    Query(query): Query<ListNotesQuery>,
+) -> AppResult<Json<Page<BottleNote>>> {
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    Ok(Json(bottle_note_service::list(&state.db, &seed, limit, query.cursor.as_deref())?))
+}
+
+/// Attaches a moderated note to a seed. Rate-limited per player the same
+/// way `GET /api/leaderboard` is rate-limited per poller, since both guard
+/// against the same kind of abuse: an unbounded write/poll loop.
+pub async fn attach(
+    State(state): State<AppState>,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    Path(seed): Path<String>,
+    Json(req): Json<AttachBottleNoteRequest>,
+) -> AppResult<StatusCode> {
+    let config = state.config.current();
+    let rate_key = if req.player_id.is_empty() { remote.ip().to_string() } else { req.player_id.clone() };
+    state
+        .poll_limiter
+        .check(&rate_key, config.bottle_note_rate_limit_budget, Duration::from_secs(config.bottle_note_rate_limit_window_secs))
+        .map_err(AppError::RateLimited)?;
+    bottle_note_service::attach(&state.db, &config, &seed, &req.player_id, &req.text)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Reports a note as abusive/spam. Auto-hides once reports reach
+/// `bottle_note_hide_after_reports`, same "hide, don't delete" moderation
+/// pattern as `kudos_service::hide`.
+pub async fn report(State(state): State<AppState>, Path((_seed, note_id)): Path<(String, i64)>) -> AppResult<StatusCode> {
+    let hide_after_reports = state.config.current().bottle_note_hide_after_reports;
+    bottle_note_service::report(&state.db, note_id, hide_after_reports)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Direct admin hide, bypassing the report threshold.
+pub async fn hide(_admin: AdminAuth, State(state): State<AppState>, Path(note_id): Path<i64>) -> AppResult<StatusCode> {
+    bottle_note_service::hide(&state.db, note_id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,55 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use axum::extract::{ConnectInfo, Query, State};
+use axum::Json;
+use booty_hunt_core::{ClientErrorReport, ReportClientErrorRequest};
+use serde::Deserialize;
+
+use crate::error::{AppError, AppResult};
+use crate::extractors::AdminAuth;
+use crate::services::client_error_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Ingests a crash/desync report. Rate-limited per source IP the same way
+/// `routes::bottle_notes::attach` is rate-limited per player, since an
+/// unbounded client error loop is the same kind of abuse as an unbounded
+/// write loop.
+pub async fn report(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    Json(req): Json<ReportClientErrorRequest>,
+) -> AppResult<Json<ClientErrorReport>> {
+    let config = state.config.current();
+    state
+        .poll_limiter
+        .check(
+            &remote.ip().to_string(),
+            config.client_error_rate_limit_budget,
+            Duration::from_secs(config.client_error_rate_limit_window_secs),
+        )
+        .map_err(AppError::RateLimited)?;
+    let report = client_error_service::report(&state.db, &tenant_id, req)?;
+    Ok(Json(report))
+}
+
+#[derive(Deserialize)]
+pub struct AggregateQuery {
+    limit: Option<i64>,
+}
+
+/// Distinct crash/desync signatures for the caller's tenant, most frequent
+/// first, so an operator can spot which client failures correlate with
+/// server-side data.
+pub async fn aggregate(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Query(query): Query<AggregateQuery>,
+) -> AppResult<Json<Vec<ClientErrorReport>>> {
+    let limit = query.limit.unwrap_or(100).clamp(1, 500);
+    let reports = client_error_service::aggregate(&state.db, &tenant_id, limit)?;
+    Ok(Json(reports))
+}
@@ -0,0 +1,68 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use booty_hunt_core::{AttachCoachingFeedbackRequest, CoachingFeedbackNote, CoachingQueueEntry, RequestCoachingRequest};
+use serde::Deserialize;
+
+use crate::error::AppResult;
+use crate::extractors::AdminAuth;
+use crate::services::coaching_service;
+use crate::state::AppState;
+
+/// Flags a replay as seeking feedback, opting it into the coaching queue.
+pub async fn request_coaching(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+    Json(req): Json<RequestCoachingRequest>,
+) -> AppResult<StatusCode> {
+    coaching_service::request_coaching(&state.db, &run_id, req.note.as_deref())?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Opts a replay back out of the coaching queue.
+pub async fn withdraw_coaching(State(state): State<AppState>, Path(run_id): Path<String>) -> AppResult<StatusCode> {
+    coaching_service::withdraw(&state.db, &run_id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Deserialize)]
+pub struct QueueQuery {
+    limit: Option<i64>,
+}
+
+/// Replays currently seeking feedback, for volunteer reviewers to pull from.
+pub async fn queue(State(state): State<AppState>, Query(query): Query<QueueQuery>) -> AppResult<Json<Vec<CoachingQueueEntry>>> {
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    Ok(Json(coaching_service::queue(&state.db, limit)?))
+}
+
+/// Attaches one reviewer's structured feedback note to a replay in the
+/// queue.
+pub async fn attach_feedback(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+    Json(req): Json<AttachCoachingFeedbackRequest>,
+) -> AppResult<StatusCode> {
+    let config = state.config.current();
+    coaching_service::attach_feedback(&state.db, &config, &run_id, &req.reviewer_player_id, &req.text, req.focus_area.as_deref())?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Feedback notes left on a replay, for the run's own player to read.
+pub async fn list_feedback(State(state): State<AppState>, Path(run_id): Path<String>) -> AppResult<Json<Vec<CoachingFeedbackNote>>> {
+    Ok(Json(coaching_service::feedback_for_run(&state.db, &run_id)?))
+}
+
+/// Reports a feedback note as abusive/spam. Auto-hides once reports reach
+/// `coaching_feedback_hide_after_reports`.
+pub async fn report_feedback(State(state): State<AppState>, Path((_run_id, feedback_id)): Path<(String, String)>) -> AppResult<StatusCode> {
+    let hide_after_reports = state.config.current().coaching_feedback_hide_after_reports;
+    coaching_service::report_feedback(&state.db, &feedback_id, hide_after_reports)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Direct admin hide, bypassing the report threshold.
+pub async fn hide_feedback(_admin: AdminAuth, State(state): State<AppState>, Path(feedback_id): Path<String>) -> AppResult<StatusCode> {
+    coaching_service::hide_feedback(&state.db, &feedback_id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
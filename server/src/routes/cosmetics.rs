@@ -0,0 +1,28 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use booty_hunt_core::{EquipCosmeticRequest, InventoryEntry};
+
+use crate::error::AppResult;
+use crate::services::cosmetics_service;
+use crate::state::AppState;
+
+pub async fn list_inventory(
+    State(state): State<AppState>,
+    Path(player_id): Path<String>,
+) -> AppResult<Json<Vec<InventoryEntry>>> {
+    let entries = state
+        .db
+        .with_read_conn(|conn| cosmetics_service::list_inventory(conn, &player_id))?;
+    Ok(Json(entries))
+}
+
+pub async fn equip_cosmetic(
+    State(state): State<AppState>,
+    Path(player_id): Path<String>,
+    Json(req): Json<EquipCosmeticRequest>,
+) -> AppResult<Json<serde_json::Value>> {
+    cosmetics_service::equip_item(&state.db, &player_id, &req.item_id)?;
+    Ok(Json(serde_json::json!({ "equipped": req.item_id })))
+}
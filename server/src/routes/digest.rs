@@ -0,0 +1,17 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use booty_hunt_core::WeeklyDigest;
+
+use crate::error::AppResult;
+use crate::services::digest_service;
+use crate::state::AppState;
+
+pub async fn get_digest(
+    State(state): State<AppState>,
+    Path(week_key): Path<String>,
+) -> AppResult<Json<WeeklyDigest>> {
+    let digest = digest_service::get_or_generate(&state.db, &week_key)?;
+    Ok(Json(digest))
+}
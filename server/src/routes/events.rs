@@ -0,0 +1,26 @@
+use axum::{extract::State, Json};
+use booty_hunt_core::{CommunityEvent, CreateCommunityEventRequest};
+
+use crate::error::AppResult;
+use crate::extractors::AdminAuth;
+use crate::services::community_event_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Schedules a new limited-time event for the caller's tenant.
+pub async fn create(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<CreateCommunityEventRequest>,
+) -> AppResult<Json<CommunityEvent>> {
+    let event = community_event_service::create(&state.db, &tenant_id, req)?;
+    Ok(Json(event))
+}
+
+/// Every event whose window contains right now, for a client to merge into
+/// its effective modifier set alongside this week's omens.
+pub async fn active(State(state): State<AppState>, TenantId(tenant_id): TenantId) -> AppResult<Json<Vec<CommunityEvent>>> {
+    let events = community_event_service::active(&state.db, &tenant_id)?;
+    Ok(Json(events))
+}
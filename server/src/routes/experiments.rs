@@ -0,0 +1,51 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use booty_hunt_core::ExperimentAssignment;
+use serde::Deserialize;
+
+use crate::error::AppResult;
+use crate::services::experiment_service::OutcomeSample;
+use crate::services::{experiment_service, run_service};
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+#[derive(Deserialize)]
+pub struct AssignmentQuery {
+    player_id: String,
+}
+
+/// A player's deterministic variant assignment for the current week —
+/// clients call this to know which omen modifier to apply, rather than
+/// flipping their own coin.
+pub async fn get_assignment(
+    State(state): State<AppState>,
+    Path(experiment_key): Path<String>,
+    Query(query): Query<AssignmentQuery>,
+) -> AppResult<Json<ExperimentAssignment>> {
+    let week_key = run_service::current_week_key();
+    let assignment = experiment_service::assign(&state.config.current(), &experiment_key, &week_key, &query.player_id)?;
+    Ok(Json(assignment))
+}
+
+#[derive(Deserialize)]
+pub struct RecordOutcomeRequest {
+    player_id: String,
+    metric: String,
+    value: f64,
+}
+
+/// Records one outcome sample against whichever variant `player_id` is
+/// actually assigned to this week, for the current week's experiment run.
+pub async fn record_outcome(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(experiment_key): Path<String>,
+    Json(req): Json<RecordOutcomeRequest>,
+) -> AppResult<StatusCode> {
+    let config = state.config.current();
+    let week_key = run_service::current_week_key();
+    let sample = OutcomeSample { player_id: &req.player_id, metric: &req.metric, value: req.value };
+    experiment_service::record_outcome(&state.db, &config, &tenant_id, &experiment_key, &week_key, sample)?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,150 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use axum::{
+    body::Bytes,
+    extract::{ConnectInfo, Path, Query, State},
+    http::{header, header::CONTENT_LENGTH, HeaderMap, HeaderName, StatusCode},
+    response::{AppendHeaders, IntoResponse},
+    Json,
+};
+use booty_hunt_core::{GhostHighlights, PopularReplay, ReportGhostDesyncRequest};
+use serde::Deserialize;
+
+use crate::error::{AppError, AppResult};
+use crate::services::{api_key_service, ghost_desync_service, ghost_highlight_service, ghost_service, ghost_signed_url_service, run_service};
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Header carrying the server-computed checksum of the tape body, so a
+/// client can detect a truncated or corrupted download without re-hashing
+/// against a value it would otherwise have to fetch from a different
+/// endpoint. Absent for runs submitted before checksums existed.
+const GHOST_TAPE_SHA256_HEADER: &str = "x-ghost-tape-sha256";
+
+/// A valid `Authorization: Bearer <key>` identifies a bulk-access caller
+/// (a mirror, an archival bot) and exempts it from the per-IP quota below —
+/// unlike `ApiKeyAuth`, an absent or invalid header just means "anonymous",
+/// not a rejected request, since ordinary players downloading their own
+/// ghost never carry a key.
+fn has_valid_api_key(state: &AppState, headers: &HeaderMap) -> bool {
+    let Some(key) = headers.get(header::AUTHORIZATION).and_then(|v| v.to_str().ok()).and_then(|v| v.strip_prefix("Bearer ")) else {
+        return false;
+    };
+    api_key_service::verify(&state.db, key).is_ok()
+}
+
+/// Headers common to both tape download routes. A checksummed tape is
+/// content-addressed by its own sha256 — the same bytes always hash the
+/// same, so it doubles as an `ETag` and licenses a long, immutable
+/// `Cache-Control` a CDN can absorb reads against. A tape submitted before
+/// checksums existed has no such address, so it's served without either.
+fn tape_headers(tape_len: usize, sha256: &Option<String>) -> Vec<(HeaderName, String)> {
+    let mut headers = vec![(CONTENT_LENGTH, tape_len.to_string())];
+    if let Some(sha256) = sha256 {
+        headers.push((GHOST_TAPE_SHA256_HEADER.parse().unwrap(), sha256.clone()));
+        headers.push((header::ETAG, format!("\"{sha256}\"")));
+        headers.push((header::CACHE_CONTROL, "public, max-age=31536000, immutable".to_string()));
+    }
+    headers
+}
+
+/// Serves a run's ghost tape. Unauthenticated and unbounded downloads let
+/// someone mirror the whole archive and saturate bandwidth, so anonymous
+/// callers are held to `ghost_download_ip_rate_limit_budget` per source IP;
+/// a caller presenting any valid API key is treated as identified bulk
+/// access and skips the quota entirely. Every response, quota-limited or
+/// not, is counted in `ghost_transfer_metrics`.
+pub async fn download(
+    State(state): State<AppState>,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    TenantId(tenant_id): TenantId,
+    headers: HeaderMap,
+    Path(run_id): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    if !has_valid_api_key(&state, &headers) {
+        let config = state.config.current();
+        let window = Duration::from_secs(config.ghost_download_ip_rate_limit_window_secs);
+        state
+            .poll_limiter
+            .check(&remote.ip().to_string(), config.ghost_download_ip_rate_limit_budget, window)
+            .map_err(AppError::RateLimited)?;
+    }
+
+    let (tape, sha256) = ghost_service::fetch_tape(&state.db, state.tape_blob_store.as_ref(), &tenant_id, &run_id).await?;
+    state.popularity.record_download(&run_id);
+    state.ghost_transfer_metrics.record(tape.len() as u64);
+
+    Ok((AppendHeaders(tape_headers(tape.len(), &sha256)), Bytes::from(tape)))
+}
+
+/// Records a client-observed desync between a downloaded ghost and the
+/// outcome it played back. See `ghost_desync_service::report` for the
+/// auto-flagging threshold.
+pub async fn report_desync(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(run_id): Path<String>,
+    Json(req): Json<ReportGhostDesyncRequest>,
+) -> AppResult<StatusCode> {
+    let config = state.config.current();
+    ghost_desync_service::report(&state.db, &config, &tenant_id, &run_id, req.frame, &req.divergence)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// A "best moments" summary for a run's ghost, powering a highlight reel
+/// without downloading the full tape — see `ghost_highlight_service`.
+pub async fn highlights(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(run_id): Path<String>,
+) -> AppResult<Json<GhostHighlights>> {
+    let highlights = ghost_highlight_service::highlights(&state.db, &tenant_id, &run_id)?;
+    Ok(Json(highlights))
+}
+
+#[derive(Deserialize)]
+pub struct SignedDownloadQuery {
+    expires: i64,
+    sig: String,
+}
+
+/// Serves a run's ghost tape via a signature issued by `GET /api/runs/:run_id`
+/// (`ghost_signed_url_service::issue`) instead of the per-IP quota `download`
+/// enforces. Exists so the heavy byte-serving can move behind a CDN or blob
+/// storage later — that layer only needs to check `expires`/`sig` against
+/// the shared secret, not call back into this server for every request.
+pub async fn download_signed(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(run_id): Path<String>,
+    Query(query): Query<SignedDownloadQuery>,
+) -> AppResult<impl IntoResponse> {
+    let config = state.config.current();
+    let now = chrono::Utc::now().timestamp();
+    if !ghost_signed_url_service::verify(&config.ghost_signed_url_secret, &run_id, query.expires, &query.sig, now) {
+        return Err(AppError::Validation("signed url is invalid or expired".into()));
+    }
+
+    let (tape, sha256) = ghost_service::fetch_tape(&state.db, state.tape_blob_store.as_ref(), &tenant_id, &run_id).await?;
+    state.popularity.record_download(&run_id);
+    state.ghost_transfer_metrics.record(tape.len() as u64);
+
+    Ok((AppendHeaders(tape_headers(tape.len(), &sha256)), Bytes::from(tape)))
+}
+
+#[derive(Deserialize)]
+pub struct PopularQuery {
+    week_key: Option<String>,
+    limit: Option<i64>,
+}
+
+pub async fn popular(
+    State(state): State<AppState>,
+    Query(query): Query<PopularQuery>,
+) -> AppResult<Json<Vec<PopularReplay>>> {
+    let week_key = query.week_key.unwrap_or_else(run_service::current_week_key);
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    let replays = ghost_service::most_popular(&state.db, &week_key, limit)?;
+    Ok(Json(replays))
+}
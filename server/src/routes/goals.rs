@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use booty_hunt_core::{CreateGoalRequest, PersonalGoal};
+
+use crate::error::AppResult;
+use crate::services::goal_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+pub async fn create(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(player_id): Path<String>,
+    Json(req): Json<CreateGoalRequest>,
+) -> AppResult<Json<PersonalGoal>> {
+    Ok(Json(goal_service::create(&state.db, &tenant_id, &player_id, req)?))
+}
+
+pub async fn list(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(player_id): Path<String>,
+) -> AppResult<Json<Vec<PersonalGoal>>> {
+    Ok(Json(goal_service::list(&state.db, &tenant_id, &player_id)?))
+}
@@ -0,0 +1,34 @@
+use axum::{extract::State, Json};
+use serde_json::{json, Value};
+
+use crate::state::AppState;
+
+fn wal_size_bytes(db_path: &str) -> Option<u64> {
+    std::fs::metadata(format!("{db_path}-wal")).ok().map(|m| m.len())
+}
+
+fn disk_free_bytes(db_path: &str) -> Option<u64> {
+    let dir = std::path::Path::new(db_path).parent().filter(|p| !p.as_os_str().is_empty())?;
+    fs2::available_space(dir).ok()
+}
+
+pub async fn health(State(state): State<AppState>) -> Json<Value> {
+    let db_latency_ms = state.db.ping().ok().map(|d| d.as_secs_f64() * 1000.0);
+    let db_path = state.db.path();
+
+    let degraded = db_latency_ms.is_none() || !state.startup_problems.is_empty();
+
+    Json(json!({
+        "status": if degraded { "degraded" } else { "ok" },
+        "uptime_seconds": state.started_at.elapsed().as_secs(),
+        "db_latency_ms": db_latency_ms,
+        "db_wal_bytes": wal_size_bytes(db_path),
+        "db_disk_free_bytes": disk_free_bytes(db_path),
+        "slow_query_count": state.db.slow_query_count(),
+        "db_reopen_count": state.db.reopen_count(),
+        "db_last_reopen_at": state.db.last_reopen_at(),
+        "last_integrity_problems": state.db.last_integrity_problems(),
+        "startup_problems": state.startup_problems.as_ref(),
+        "scheduler_last_run_seconds_ago": state.scheduler_status.seconds_since_last_run(),
+    }))
+}
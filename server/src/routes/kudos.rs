@@ -0,0 +1,27 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use booty_hunt_core::GiveKudosRequest;
+
+use crate::error::AppResult;
+use crate::services::kudos_service;
+use crate::state::AppState;
+
+pub async fn give(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+    Json(req): Json<GiveKudosRequest>,
+) -> AppResult<StatusCode> {
+    kudos_service::give(&state.db, &run_id, &req.player_id, req.comment.as_deref())?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn hide(
+    State(state): State<AppState>,
+    Path((run_id, player_id)): Path<(String, String)>,
+) -> AppResult<StatusCode> {
+    kudos_service::hide(&state.db, &run_id, &player_id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
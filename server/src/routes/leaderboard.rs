@@ -0,0 +1,291 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use axum::{
+    extract::{ConnectInfo, Path, Query, State},
+    http::{header, HeaderMap},
+    response::IntoResponse,
+    Json,
+};
+use booty_hunt_core::LeaderboardResponse;
+use serde::Deserialize;
+
+use crate::error::{AppError, AppResult};
+use crate::services::leaderboard_service::{LeaderboardFilters, LeaderboardSort};
+use crate::services::{leaderboard_finalization_service, leaderboard_service, rating_service, run_service};
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Identifies the caller for poll-budget purposes: the bearer token if the
+/// client sent one (community tools, the official client with a linked
+/// account), otherwise the source IP. Not an authentication check — an
+/// unverified token is still a stable-enough key to bucket a poller by.
+fn poll_key(headers: &HeaderMap, remote: SocketAddr) -> String {
+    headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| remote.ip().to_string())
+}
+
+fn client_version_header(headers: &HeaderMap) -> &str {
+    headers.get("x-client-version").and_then(|v| v.to_str().ok()).unwrap_or("unknown")
+}
+
+#[derive(Deserialize)]
+pub struct LeaderboardQuery {
+    week_key: Option<String>,
+    limit: Option<i64>,
+    /// Consistency token from a prior `POST /api/runs` response. Since every
+    /// read and write go through the same connection, any token issued in
+    /// the past is already visible — this only guards against a future
+    /// caching layer serving a stale snapshot.
+    since_token: Option<u64>,
+    /// Restricts the board to a single region (e.g. `eu`), as derived and
+    /// stored on run submission. Omitted returns the global board.
+    region: Option<String>,
+    /// Restricts the board to runs submitted against a single ruleset id.
+    /// Omitted returns the tenant's default board, mixing ruleset-bound and
+    /// freeform runs together as it always has.
+    ruleset_id: Option<String>,
+    /// Restricts the board to players assigned to a single promotion/
+    /// relegation division for this week — see `division_service`. Omitted
+    /// returns the whole tenant's board, unchanged from before divisions
+    /// existed.
+    division: Option<i64>,
+    /// Restricts the board to runs submitted while a given omen id was
+    /// active — see `booty_hunt_core::RunDetail::modifier_omen_ids`. Unlike
+    /// every other filter here, setting this drops the single-`week_key`
+    /// restriction entirely, since the point is comparing scores under the
+    /// same conditions across every week that omen has appeared in.
+    omen_id: Option<String>,
+    /// Set to `unified` to rank by `normalized_score` instead of raw score,
+    /// putting every ship class on one ladder. Omitted keeps raw-score
+    /// ordering, unchanged from before this category existed.
+    category: Option<String>,
+    /// The `version` from a prior response. If the server still has delta
+    /// history back that far for this exact scope, the response contains
+    /// only what changed since then instead of the whole board — see
+    /// `LeaderboardDeltaLog`. Ignored (falls back to a full response) when
+    /// `region`/`ruleset_id`/`category` are set, since delta tracking only
+    /// covers the plain unfiltered board today.
+    since_version: Option<u64>,
+    /// Comma-separated allowlist of entry fields (e.g. `player_name,score`)
+    /// for lightweight pollers — a watch app or OBS overlay that redraws
+    /// every second doesn't need `equipped_cosmetics` on the wire. Omitted
+    /// returns the full entry shape, unchanged from before this existed.
+    fields: Option<String>,
+}
+
+/// Keeps only the requested keys on every object `value` contains, recursing
+/// into arrays so it works on a bare entry list or a whole envelope alike.
+/// Filters after serialization instead of adding a second, slimmer struct per
+/// entry type, so the allowlist always tracks whatever the real entry types
+/// expose without a parallel copy to keep in sync.
+fn project_fields(value: serde_json::Value, fields: Option<&str>) -> serde_json::Value {
+    let wanted: Vec<&str> = match fields {
+        Some(fields) => fields.split(',').map(str::trim).filter(|f| !f.is_empty()).collect(),
+        None => return value,
+    };
+    if wanted.is_empty() {
+        return value;
+    }
+    fn project(value: serde_json::Value, wanted: &[&str]) -> serde_json::Value {
+        match value {
+            serde_json::Value::Array(items) => serde_json::Value::Array(items.into_iter().map(|item| project(item, wanted)).collect()),
+            serde_json::Value::Object(map) => {
+                serde_json::Value::Object(map.into_iter().filter(|(key, _)| wanted.contains(&key.as_str())).collect())
+            }
+            other => other,
+        }
+    }
+    project(value, &wanted)
+}
+
+/// Scope key delta history is tracked under. Deliberately narrower than the
+/// full filter set `fetch_leaderboard` accepts — region/ruleset/category
+/// boards aren't tracked, so a request using any of those always falls back
+/// to a full response.
+fn delta_scope(tenant_id: &str, week_key: &str, limit: i64) -> String {
+    format!("{tenant_id}:{week_key}:{limit}")
+}
+
+pub async fn get_leaderboard(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Query(query): Query<LeaderboardQuery>,
+) -> AppResult<Json<serde_json::Value>> {
+    let config = state.config.current();
+    state
+        .poll_limiter
+        .check(&poll_key(&headers, remote), config.leaderboard_poll_budget, Duration::from_secs(config.leaderboard_poll_window_secs))
+        .map_err(AppError::RateLimited)?;
+    state.poll_limiter.record_client_version(client_version_header(&headers));
+
+    if let Some(token) = query.since_token {
+        if state.db.current_write_version() < token {
+            return Err(AppError::Validation("consistency token is from the future".into()));
+        }
+    }
+    let week_key = query.week_key.unwrap_or_else(run_service::current_week_key);
+    let limit = query.limit.unwrap_or(100).clamp(1, 500);
+    let sort = match query.category.as_deref() {
+        Some("unified") => LeaderboardSort::Unified,
+        Some("speedrun") => LeaderboardSort::Speedrun,
+        Some("stealth") => LeaderboardSort::Stealth,
+        Some(other) => return Err(AppError::Validation(format!("unknown leaderboard category: {other}"))),
+        None => LeaderboardSort::Score,
+    };
+    let entries = leaderboard_service::fetch_leaderboard(
+        &state.db,
+        &tenant_id,
+        &week_key,
+        limit,
+        LeaderboardFilters {
+            region: query.region.as_deref(),
+            ruleset_id: query.ruleset_id.as_deref(),
+            division: query.division,
+            omen_id: query.omen_id.as_deref(),
+        },
+        sort,
+    )?;
+
+    let version = state.db.current_write_version();
+    let deltable = query.region.is_none()
+        && query.ruleset_id.is_none()
+        && query.division.is_none()
+        && query.omen_id.is_none()
+        && matches!(sort, LeaderboardSort::Score);
+    let scope = delta_scope(&tenant_id, &week_key, limit);
+
+    let response = if deltable {
+        state.leaderboard_deltas.record(&scope, version, &entries);
+        match query.since_version.and_then(|since| state.leaderboard_deltas.delta_since(&scope, since)) {
+            Some((changed, removed_run_ids)) => LeaderboardResponse {
+                version,
+                full: false,
+                entries: changed,
+                removed_run_ids,
+                poll_interval_hint_secs: config.leaderboard_poll_interval_hint_secs,
+            },
+            None => LeaderboardResponse {
+                version,
+                full: true,
+                entries,
+                removed_run_ids: Vec::new(),
+                poll_interval_hint_secs: config.leaderboard_poll_interval_hint_secs,
+            },
+        }
+    } else {
+        LeaderboardResponse {
+            version,
+            full: true,
+            entries,
+            removed_run_ids: Vec::new(),
+            poll_interval_hint_secs: config.leaderboard_poll_interval_hint_secs,
+        }
+    };
+    let mut body = serde_json::to_value(&response).expect("LeaderboardResponse always serializes");
+    if let Some(entries) = body.get_mut("entries") {
+        *entries = project_fields(entries.take(), query.fields.as_deref());
+    }
+    Ok(Json(body))
+}
+
+#[derive(Deserialize)]
+pub struct AroundQuery {
+    run_id: String,
+    week_key: Option<String>,
+    /// Entries fetched above and below `run_id`, so `context=5` returns up to
+    /// 11 rows (the run itself plus 5 on each side).
+    context: Option<i64>,
+    region: Option<String>,
+    ruleset_id: Option<String>,
+    /// See `LeaderboardQuery::division`.
+    division: Option<i64>,
+    /// See `LeaderboardQuery::omen_id`.
+    omen_id: Option<String>,
+    category: Option<String>,
+    /// See `LeaderboardQuery::fields`.
+    fields: Option<String>,
+}
+
+/// Returns the entries immediately above and below a given run in its
+/// leaderboard, so a client can show "you are #1,482" without downloading
+/// the whole board. `run_id` must belong to `week_key` (or the current week)
+/// under the same region/ruleset/category filters, or this 404s the same way
+/// a lookup of a run that doesn't exist would.
+pub async fn get_around(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Query(query): Query<AroundQuery>,
+) -> AppResult<Json<serde_json::Value>> {
+    let week_key = query.week_key.unwrap_or_else(run_service::current_week_key);
+    let context = query.context.unwrap_or(5).clamp(1, 50);
+    let sort = match query.category.as_deref() {
+        Some("unified") => LeaderboardSort::Unified,
+        Some("speedrun") => LeaderboardSort::Speedrun,
+        Some("stealth") => LeaderboardSort::Stealth,
+        Some(other) => return Err(AppError::Validation(format!("unknown leaderboard category: {other}"))),
+        None => LeaderboardSort::Score,
+    };
+    let entries = leaderboard_service::fetch_around(
+        &state.db,
+        &tenant_id,
+        &week_key,
+        &query.run_id,
+        context,
+        LeaderboardFilters {
+            region: query.region.as_deref(),
+            ruleset_id: query.ruleset_id.as_deref(),
+            division: query.division,
+            omen_id: query.omen_id.as_deref(),
+        },
+        sort,
+    )?;
+    let body = project_fields(serde_json::to_value(&entries).expect("LeaderboardEntry always serializes"), query.fields.as_deref());
+    Ok(Json(body))
+}
+
+/// Returns `week_key`'s frozen final standings, or 404 if the scheduler
+/// hasn't finalized that week yet (it hasn't ended, or finalization hasn't
+/// run since it did — see `spawn_leaderboard_finalization`). Deliberately
+/// doesn't finalize on demand the way `GET /api/digest/:week_key` generates
+/// its digest lazily: finalizing early, before the week is actually over,
+/// would defeat the point of freezing standings at rollover.
+/// A finalized week's standings never change once written, so this is safe
+/// for a CDN to cache long and immutable — same reasoning as
+/// `GET /api/public/dumps/:week_key`, just for the finalization table
+/// instead of the dump table.
+pub async fn get_finalized_leaderboard(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(week_key): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    let finalized = leaderboard_finalization_service::get_finalized(&state.db, &tenant_id, &week_key)?
+        .ok_or(AppError::NotFound)?;
+    Ok((
+        [(header::CACHE_CONTROL, "public, max-age=31536000, immutable")],
+        Json(finalized),
+    ))
+}
+
+#[derive(Deserialize)]
+pub struct RatingLeaderboardQuery {
+    limit: Option<i64>,
+    /// See `LeaderboardQuery::fields`.
+    fields: Option<String>,
+}
+
+pub async fn get_rating_leaderboard(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Query(query): Query<RatingLeaderboardQuery>,
+) -> AppResult<Json<serde_json::Value>> {
+    let limit = query.limit.unwrap_or(100).clamp(1, 500);
+    let entries = rating_service::fetch_rating_leaderboard(&state.db, &tenant_id, limit)?;
+    let body = project_fields(serde_json::to_value(&entries).expect("RatingEntry always serializes"), query.fields.as_deref());
+    Ok(Json(body))
+}
@@ -0,0 +1,210 @@
+pub mod admin;
+pub mod api_keys;
+pub mod bottle_notes;
+pub mod client_errors;
+pub mod coaching;
+pub mod cosmetics;
+pub mod digest;
+pub mod events;
+pub mod experiments;
+pub mod ghost;
+pub mod goals;
+pub mod health;
+pub mod kudos;
+pub mod leaderboard;
+pub mod news;
+pub mod notifications;
+pub mod og;
+pub mod players;
+pub mod presence;
+pub mod progression;
+pub mod public_dumps;
+pub mod raid;
+pub mod receipts;
+pub mod regatta;
+pub mod rulesets;
+pub mod runs;
+pub mod signal_fires;
+pub mod splits;
+pub mod stats;
+pub mod tide;
+pub mod static_ui;
+pub mod tape_upload;
+pub mod telemetry;
+pub mod tuning;
+
+use std::time::Duration;
+
+use axum::{
+    extract::Extension,
+    http::StatusCode,
+    middleware::{from_fn, from_fn_with_state},
+    routing::{delete, get, post, put},
+    Router,
+};
+use tower::ServiceBuilder;
+
+use crate::middleware::{enforce_body_limit, localize_error_response, record_request_metrics, BodyLimit};
+use crate::state::AppState;
+
+async fn handle_timeout(_err: tower::BoxError) -> (StatusCode, &'static str) {
+    (StatusCode::REQUEST_TIMEOUT, "handler exceeded its timeout")
+}
+
+const RUN_SUBMISSION_LIMIT_BYTES: u64 = 8 * 1024 * 1024;
+const TAPE_CHUNK_LIMIT_BYTES: u64 = 4 * 1024 * 1024;
+const TELEMETRY_BATCH_LIMIT_BYTES: u64 = 256 * 1024;
+const SMALL_BODY_LIMIT_BYTES: u64 = 4 * 1024;
+
+pub fn router(state: AppState) -> Router {
+    let run_routes = Router::new()
+        .route("/api/runs/nonce", post(runs::issue_nonce))
+        .route("/api/runs", post(runs::submit_run))
+        .route("/api/runs/validate", post(runs::validate_run))
+        .route("/api/runs/upload", post(runs::upload_run))
+        .route("/api/runs/:run_id/ghost", put(runs::attach_ghost))
+        .layer(from_fn(enforce_body_limit))
+        .layer(Extension(BodyLimit(RUN_SUBMISSION_LIMIT_BYTES)));
+
+    let tape_chunk_routes = Router::new()
+        .route("/api/runs/:run_id/tape-sessions", post(tape_upload::start_session))
+        .route("/api/tape-sessions/:session_id/chunks", put(tape_upload::put_chunk))
+        .route("/api/tape-sessions/:session_id/finalize", post(tape_upload::finalize))
+        .layer(from_fn(enforce_body_limit))
+        .layer(Extension(BodyLimit(TAPE_CHUNK_LIMIT_BYTES)));
+
+    let telemetry_routes = Router::new()
+        .route("/api/telemetry", post(telemetry::ingest))
+        .layer(from_fn(enforce_body_limit))
+        .layer(Extension(BodyLimit(TELEMETRY_BATCH_LIMIT_BYTES)));
+
+    let small_body_routes = Router::new()
+        .route("/api/players/:player_id/cosmetics", get(cosmetics::list_inventory))
+        .route("/api/players/:player_id/cosmetics/equip", put(cosmetics::equip_cosmetic))
+        .route("/api/players/:player_id/api-keys", post(api_keys::issue))
+        .route("/api/players/:player_id/api-keys/:key_id", delete(api_keys::revoke))
+        .route("/api/runs/:run_id/appeal", post(runs::appeal))
+        .route("/api/runs/:run_id/kudos", post(kudos::give))
+        .route("/api/runs/:run_id/kudos/:player_id/hide", post(kudos::hide))
+        .route("/api/players/:player_id/season-progress/claim", post(progression::claim_tier))
+        .route("/api/players/:player_id/goals", post(goals::create))
+        .route("/api/admin/events", post(events::create))
+        .route("/api/admin/news", post(news::create))
+        .route("/api/admin/news/:news_id", put(news::update).delete(news::delete))
+        .route("/api/admin/tuning/:key", put(tuning::set_value))
+        .route("/api/admin/signal-fires", post(admin::mint_signal_fire))
+        .route("/api/admin/signal-fires/bulk", post(admin::mint_signal_fires))
+        .route("/api/tide/contribute", post(tide::contribute))
+        .route("/api/receipts/verify", post(receipts::verify))
+        .route("/api/experiments/:experiment_key/outcomes", post(experiments::record_outcome))
+        .route("/api/signal-fire/trades", post(signal_fires::create_trade_offer))
+        .route("/api/signal-fire/trades/:offer_id/accept", post(signal_fires::accept_trade_offer))
+        .route("/api/signal-fire/trades/:offer_id/cancel", post(signal_fires::cancel_trade_offer))
+        .route("/api/client-errors", post(client_errors::report))
+        .route("/api/runs/:run_id/ghost/desync-reports", post(ghost::report_desync))
+        .route("/api/seeds/:seed/notes", post(bottle_notes::attach))
+        .route("/api/seeds/:seed/notes/:note_id/report", post(bottle_notes::report))
+        .route("/api/admin/bottle-notes/:note_id/hide", post(bottle_notes::hide))
+        .route(
+            "/api/runs/:run_id/coaching-request",
+            post(coaching::request_coaching).delete(coaching::withdraw_coaching),
+        )
+        .route("/api/runs/:run_id/coaching-feedback", post(coaching::attach_feedback))
+        .route("/api/runs/:run_id/coaching-feedback/:feedback_id/report", post(coaching::report_feedback))
+        .route("/api/admin/coaching-feedback/:feedback_id/hide", post(coaching::hide_feedback))
+        .layer(from_fn(enforce_body_limit))
+        .layer(Extension(BodyLimit(SMALL_BODY_LIMIT_BYTES)));
+
+    let mut router = Router::new();
+    if state.config.current().static_ui_enabled {
+        router = router.route("/", get(static_ui::leaderboard_page));
+    }
+
+    router
+        .route("/api/health", get(health::health))
+        .route("/api/events/active", get(events::active))
+        .route("/api/news", get(news::active))
+        .route("/api/admin/news", get(news::list_all))
+        .route("/api/tuning", get(tuning::get_snapshot))
+        .route("/api/admin/tuning/:key/history", get(tuning::get_history))
+        .route("/api/leaderboard", get(leaderboard::get_leaderboard))
+        .route("/api/leaderboard/around", get(leaderboard::get_around))
+        .route("/api/leaderboard/ratings", get(leaderboard::get_rating_leaderboard))
+        .route("/api/leaderboard/:week_key/final", get(leaderboard::get_finalized_leaderboard))
+        .route("/api/presence/heartbeat", post(presence::heartbeat))
+        .route("/api/presence/count", get(presence::count))
+        .route("/api/notifications/devices", post(notifications::register_device))
+        .route("/api/players/register", post(players::register))
+        .route("/api/players/recover", post(players::recover))
+        .route("/api/players/:player_id/identities/link", post(players::link_identity))
+        .route("/api/players/:player_id/profile", get(players::profile))
+        .route("/api/players/:player_id/overtakes", get(players::overtakes))
+        .route("/api/players/:player_id/divisions", get(players::divisions))
+        .route("/api/players/:player_id/events", get(players::events))
+        .route("/api/players/:player_id/goals", get(goals::list))
+        .route("/api/players/:player_id/season-progress", get(progression::get_progress))
+        .route("/api/community/runs", get(api_keys::my_runs))
+        .route("/api/runs/:run_id", get(runs::detail))
+        .route("/api/runs/:run_id/bundle", get(runs::bundle))
+        .route("/api/runs/:run_id/card.svg", get(runs::card))
+        .route("/r/:run_id", get(og::run_unfurl))
+        .route("/api/runs/:run_id/ghost", get(ghost::download))
+        .route("/api/runs/:run_id/ghost/signed", get(ghost::download_signed))
+        .route("/api/ghost/:run_id/highlights", get(ghost::highlights))
+        .route("/api/ghost/popular", get(ghost::popular))
+        .route("/api/digest/:week_key", get(digest::get_digest))
+        .route("/api/public/dumps/:week_key", get(public_dumps::get_dump))
+        .route("/api/public/sitemap", get(public_dumps::sitemap))
+        .route("/api/splits/:seed/sum-of-best", get(splits::get_sum_of_best))
+        .route("/api/signal-fire/:code/qr", get(signal_fires::qr))
+        .route("/api/signal-fire/trades", get(signal_fires::list_trade_offers))
+        .route("/api/tide/metrics", get(tide::get_metrics))
+        .route("/api/tide/omens", get(tide::get_omens))
+        .route("/api/seeds/:seed/notes", get(bottle_notes::list))
+        .route("/api/coaching-queue", get(coaching::queue))
+        .route("/api/runs/:run_id/coaching-feedback", get(coaching::list_feedback))
+        .route("/api/experiments/:experiment_key/assignment", get(experiments::get_assignment))
+        .route("/api/admin/experiments/:experiment_key/report", get(admin::experiment_report))
+        .route("/api/telemetry/aggregate", get(telemetry::aggregate))
+        .route("/api/admin/client-errors", get(client_errors::aggregate))
+        .route("/api/stats/timeseries", get(stats::timeseries))
+        .route("/api/admin/repair", post(admin::repair))
+        .route("/api/admin/economy", get(admin::economy))
+        .route("/api/admin/leaderboard/poll-stats", get(admin::leaderboard_poll_stats))
+        .route("/api/admin/ghost-transfer-stats", get(admin::ghost_transfer_stats))
+        .route("/api/admin/overview", get(admin::overview))
+        .route("/api/admin/replicate", post(admin::replicate_now))
+        .route("/api/admin/analytics/export", post(admin::export_analytics_now))
+        .route("/api/admin/config/reload", post(admin::reload_config))
+        .route("/api/admin/moderation-queue", get(admin::moderation_queue))
+        .route("/api/admin/moderation-queue/:flag_id/resolve", post(admin::resolve_moderation_flag))
+        .route("/api/admin/runs/bulk-action", post(admin::bulk_run_action))
+        .route("/api/admin/runs/:run_id/appeal/resolve", post(admin::resolve_appeal))
+        .route("/api/admin/signal-fires/campaigns/:campaign", get(admin::signal_fire_campaign))
+        .route("/api/admin/regatta/:track/reroll", post(regatta::reroll))
+        .route("/api/raid", get(raid::get_current))
+        .route("/api/regatta", get(regatta::get_current))
+        .route("/api/regatta/:week_key", get(regatta::get_for_week))
+        .route("/api/regatta/events", get(regatta::get_events))
+        .route("/api/rulesets", get(rulesets::list).post(rulesets::create))
+        .merge(run_routes)
+        .merge(tape_chunk_routes)
+        .merge(telemetry_routes)
+        .merge(small_body_routes)
+        // Wraps every route above, not just one body-limit group, so the
+        // overview's error rate reflects the whole API rather than one slice
+        // of it.
+        .layer(from_fn_with_state(state.clone(), record_request_metrics))
+        // Also wraps every route, same reasoning — a client's preferred
+        // language shouldn't depend on which endpoint it hit.
+        .layer(from_fn(localize_error_response))
+        .layer(
+            ServiceBuilder::new()
+                .layer(axum::error_handling::HandleErrorLayer::new(handle_timeout))
+                // Baked into the layer at router construction time, so unlike
+                // handler-level config reads this one doesn't pick up a
+                // `reload_from_env()` until the process restarts.
+                .timeout(Duration::from_secs(state.config.current().request_timeout_secs)),
+        )
+        .with_state(state)
+}
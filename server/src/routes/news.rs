@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use booty_hunt_core::{CreateNewsItemRequest, NewsItem, UpdateNewsItemRequest};
+
+use crate::error::AppResult;
+use crate::extractors::AdminAuth;
+use crate::services::news_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Publishes a new news/MOTD entry for the caller's tenant.
+pub async fn create(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<CreateNewsItemRequest>,
+) -> AppResult<Json<NewsItem>> {
+    let item = news_service::create(&state.db, &tenant_id, req)?;
+    Ok(Json(item))
+}
+
+pub async fn update(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(news_id): Path<String>,
+    Json(req): Json<UpdateNewsItemRequest>,
+) -> AppResult<Json<NewsItem>> {
+    let item = news_service::update(&state.db, &tenant_id, &news_id, req)?;
+    Ok(Json(item))
+}
+
+pub async fn delete(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(news_id): Path<String>,
+) -> AppResult<StatusCode> {
+    news_service::delete(&state.db, &tenant_id, &news_id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Every news item for the caller's tenant regardless of publish window, for
+/// the admin list view.
+pub async fn list_all(_admin: AdminAuth, State(state): State<AppState>, TenantId(tenant_id): TenantId) -> AppResult<Json<Vec<NewsItem>>> {
+    let items = news_service::list_all(&state.db, &tenant_id)?;
+    Ok(Json(items))
+}
+
+/// News items currently inside their publish/expiry window — what the
+/// client actually shows.
+pub async fn active(State(state): State<AppState>, TenantId(tenant_id): TenantId) -> AppResult<Json<Vec<NewsItem>>> {
+    let items = news_service::active(&state.db, &tenant_id)?;
+    Ok(Json(items))
+}
@@ -0,0 +1,22 @@
+use axum::{extract::State, Json};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::error::AppResult;
+use crate::services::notification_service;
+use crate::state::AppState;
+
+#[derive(Deserialize)]
+pub struct RegisterDeviceRequest {
+    player_id: String,
+    provider: String,
+    token: String,
+}
+
+pub async fn register_device(
+    State(state): State<AppState>,
+    Json(req): Json<RegisterDeviceRequest>,
+) -> AppResult<Json<Value>> {
+    notification_service::register_device(&state.db, &req.player_id, &req.provider, &req.token)?;
+    Ok(Json(json!({ "registered": true })))
+}
@@ -0,0 +1,72 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::Html;
+
+use crate::error::AppResult;
+use crate::services::run_card_service;
+use crate::state::AppState;
+use crate::tenant::DEFAULT_TENANT;
+
+fn tenant_header(headers: &HeaderMap) -> &str {
+    headers.get("x-tenant-id").and_then(|v| v.to_str().ok()).filter(|v| !v.is_empty()).unwrap_or(DEFAULT_TENANT)
+}
+
+fn absolute_or_relative(base: Option<&str>, path: &str) -> String {
+    match base {
+        Some(base) => format!("{}{path}", base.trim_end_matches('/')),
+        None => path.to_string(),
+    }
+}
+
+/// Serves an HTML page carrying Open Graph/Twitter card tags for `run_id`
+/// and meta-refreshes a human browser on to the web viewer, so a pasted run
+/// link unfurls nicely in chat apps that fetch the URL themselves (Discord,
+/// Slack, etc.) rather than rendering the page in a headless browser.
+/// Crawlers read the tags from this response directly — redirecting with a
+/// `3xx` instead would just hand them the viewer's own (tag-less) markup.
+pub async fn run_unfurl(State(state): State<AppState>, headers: HeaderMap, Path(run_id): Path<String>) -> AppResult<Html<String>> {
+    let tenant_id = tenant_header(&headers);
+    let summary = run_card_service::fetch_summary(&state.db, tenant_id, &run_id)?;
+    let config = state.config.current();
+
+    let base = config.public_base_url.as_deref();
+    let image_url = absolute_or_relative(base, &format!("/api/runs/{run_id}/card.svg"));
+    let viewer_url = match &config.run_viewer_url_template {
+        Some(template) => template.replace("{run_id}", &run_id),
+        None => absolute_or_relative(base, &format!("/api/runs/{run_id}")),
+    };
+
+    let title = run_card_service::escape_xml(&format!("{} — {} ({})", summary.player_name, summary.score, summary.ship_class));
+    let description = run_card_service::escape_xml(if summary.victory {
+        "Victory — see the full run."
+    } else {
+        "Fell in battle — see the full run."
+    });
+    let viewer_url_attr = run_card_service::escape_xml(&viewer_url);
+    let image_url_attr = run_card_service::escape_xml(&image_url);
+
+    let html = format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<meta http-equiv="refresh" content="0; url={viewer_url_attr}">
+<meta property="og:type" content="website">
+<meta property="og:title" content="{title}">
+<meta property="og:description" content="{description}">
+<meta property="og:image" content="{image_url_attr}">
+<meta property="og:url" content="{viewer_url_attr}">
+<meta name="twitter:card" content="summary_large_image">
+<meta name="twitter:title" content="{title}">
+<meta name="twitter:description" content="{description}">
+<meta name="twitter:image" content="{image_url_attr}">
+</head>
+<body>
+<p>Redirecting to <a href="{viewer_url_attr}">{viewer_url_attr}</a>…</p>
+</body>
+</html>"#
+    );
+
+    Ok(Html(html))
+}
@@ -0,0 +1,112 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use booty_hunt_core::{EventParticipation, OvertakeEvent, PlayerDivisionRecord, PlayerProfile};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppResult;
+use crate::services::{community_event_service, division_service, identity_service, overtake_service, player_service};
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+#[derive(Serialize)]
+pub struct RegistrationResponse {
+    player_id: String,
+    token: String,
+    recovery_code: String,
+}
+
+impl From<player_service::Registration> for RegistrationResponse {
+    fn from(r: player_service::Registration) -> Self {
+        RegistrationResponse { player_id: r.player_id, token: r.token, recovery_code: r.recovery_code }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RegisterRequest {
+    display_name: String,
+}
+
+pub async fn register(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<RegisterRequest>,
+) -> AppResult<Json<RegistrationResponse>> {
+    let registration = player_service::register(&state.db, &req.display_name, &tenant_id)?;
+    Ok(Json(registration.into()))
+}
+
+#[derive(Deserialize)]
+pub struct RecoverRequest {
+    recovery_code: String,
+}
+
+pub async fn recover(
+    State(state): State<AppState>,
+    Json(req): Json<RecoverRequest>,
+) -> AppResult<Json<RegistrationResponse>> {
+    let registration = player_service::recover(&state.db, &req.recovery_code)?;
+    Ok(Json(registration.into()))
+}
+
+#[derive(Deserialize)]
+pub struct LinkIdentityRequest {
+    provider: String,
+    proof: String,
+}
+
+pub async fn link_identity(
+    State(state): State<AppState>,
+    Path(player_id): Path<String>,
+    Json(req): Json<LinkIdentityRequest>,
+) -> AppResult<StatusCode> {
+    identity_service::link(&state.db, &state.identity_providers, &player_id, &req.provider, &req.proof).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn profile(
+    State(state): State<AppState>,
+    Path(player_id): Path<String>,
+) -> AppResult<Json<PlayerProfile>> {
+    Ok(Json(player_service::profile(&state.db, &player_id)?))
+}
+
+#[derive(Deserialize)]
+pub struct OvertakesQuery {
+    limit: Option<i64>,
+}
+
+/// Polling fallback for the "you've been overtaken" nudge, for a player with
+/// no device registered (or whose push provider is down) to still see what
+/// they missed.
+pub async fn overtakes(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(player_id): Path<String>,
+    Query(query): Query<OvertakesQuery>,
+) -> AppResult<Json<Vec<OvertakeEvent>>> {
+    let limit = query.limit.unwrap_or(20).clamp(1, 100);
+    Ok(Json(overtake_service::recent_for_player(&state.db, &tenant_id, &player_id, limit)?))
+}
+
+/// A player's promotion/relegation division across every week they've been
+/// assigned one, most recent first — see `division_service`.
+pub async fn divisions(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(player_id): Path<String>,
+) -> AppResult<Json<Vec<PlayerDivisionRecord>>> {
+    Ok(Json(division_service::history(&state.db, &tenant_id, &player_id)?))
+}
+
+/// A player's community event participation history, most recent first —
+/// see `community_event_service::history_for_player`.
+pub async fn events(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(player_id): Path<String>,
+) -> AppResult<Json<Vec<EventParticipation>>> {
+    Ok(Json(community_event_service::history_for_player(&state.db, &tenant_id, &player_id)?))
+}
@@ -0,0 +1,19 @@
+use axum::{extract::State, Json};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::state::AppState;
+
+#[derive(Deserialize)]
+pub struct HeartbeatRequest {
+    player_id: String,
+}
+
+pub async fn heartbeat(State(state): State<AppState>, Json(req): Json<HeartbeatRequest>) -> Json<Value> {
+    state.presence.heartbeat(&req.player_id);
+    Json(json!({ "ok": true }))
+}
+
+pub async fn count(State(state): State<AppState>) -> Json<Value> {
+    Json(json!({ "active_captains": state.presence.active_count() }))
+}
@@ -0,0 +1,29 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use booty_hunt_core::{ClaimTierRequest, ClaimTierResult, SeasonProgress};
+
+use crate::error::AppResult;
+use crate::services::progression_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+pub async fn get_progress(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(player_id): Path<String>,
+) -> AppResult<Json<SeasonProgress>> {
+    let progress = progression_service::get_progress(&state.db, &state.config.current(), &tenant_id, &player_id)?;
+    Ok(Json(progress))
+}
+
+pub async fn claim_tier(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(player_id): Path<String>,
+    Json(req): Json<ClaimTierRequest>,
+) -> AppResult<Json<ClaimTierResult>> {
+    let result = progression_service::claim_tier(&state.db, &state.config.current(), &tenant_id, &player_id, req.tier)?;
+    Ok(Json(result))
+}
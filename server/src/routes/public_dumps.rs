@@ -0,0 +1,49 @@
+use axum::extract::{Path, State};
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+
+use crate::error::{AppError, AppResult};
+use crate::services::public_dump_service;
+use crate::state::AppState;
+use crate::tenant::DEFAULT_TENANT;
+
+/// Header carrying the checksum of the dump body — see
+/// `ghost::GHOST_TAPE_SHA256_HEADER` for the same pattern applied to tapes.
+const DUMP_SHA256_HEADER: &str = "x-dump-sha256";
+
+/// Serves `week_key`'s public dump, or 404 if the scheduler hasn't
+/// generated it yet — see `spawn_public_dump_generation`. Never generates
+/// on demand: a dump is only meaningful for a week that's already over,
+/// and `generate_if_missing` runs hourly, so a 404 here just means "check
+/// back within the hour" rather than something worth computing inline.
+/// The body never changes once generated, so it's served with a long,
+/// immutable `Cache-Control` — safe for a wiki or stats site to mirror.
+pub async fn get_dump(State(state): State<AppState>, Path(week_key): Path<String>) -> AppResult<impl IntoResponse> {
+    let (dump_json, checksum) =
+        public_dump_service::get(&state.db, DEFAULT_TENANT, &week_key)?.ok_or(AppError::NotFound)?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (header::CACHE_CONTROL, "public, max-age=31536000, immutable".to_string()),
+            (DUMP_SHA256_HEADER.parse().unwrap(), checksum),
+        ],
+        dump_json,
+    ))
+}
+
+/// A minimal index of stable, mirror-friendly public URLs — the weekly
+/// dumps generated so far, newest first. There's no notion of "pages" in
+/// this API to put in a literal XML sitemap, so this is a small JSON index
+/// instead: enough for a wiki or archival bot to discover what's available
+/// without guessing week keys or paginating the live leaderboard.
+pub async fn sitemap(State(state): State<AppState>) -> AppResult<impl IntoResponse> {
+    let week_keys = public_dump_service::list_available_weeks(&state.db, DEFAULT_TENANT)?;
+    let urls: Vec<String> = week_keys.iter().map(|week_key| format!("/api/public/dumps/{week_key}")).collect();
+
+    Ok((
+        StatusCode::OK,
+        [(header::CACHE_CONTROL, "public, max-age=3600".to_string())],
+        axum::Json(serde_json::json!({ "dumps": urls })),
+    ))
+}
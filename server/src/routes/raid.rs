@@ -0,0 +1,14 @@
+use axum::extract::State;
+use axum::Json;
+use booty_hunt_core::RaidStatus;
+
+use crate::error::AppResult;
+use crate::services::raid_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// This week's cooperative raid boss progress.
+pub async fn get_current(State(state): State<AppState>, TenantId(tenant_id): TenantId) -> AppResult<Json<RaidStatus>> {
+    let config = state.config.current();
+    Ok(Json(raid_service::status(&state.db, &config, &tenant_id)?))
+}
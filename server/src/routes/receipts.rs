@@ -0,0 +1,27 @@
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+
+use crate::receipt;
+use crate::state::AppState;
+
+#[derive(Deserialize)]
+pub struct VerifyReceiptRequest {
+    run_id: String,
+    score: i64,
+    week_key: String,
+    receipt: String,
+}
+
+#[derive(Serialize)]
+pub struct VerifyReceiptResponse {
+    valid: bool,
+}
+
+/// Lets a third-party tournament organizer confirm a receipt a player handed
+/// them actually came from this server, without any other access to it.
+/// Never errors on a bad receipt — an invalid one is a normal, expected
+/// outcome here, not a validation failure.
+pub async fn verify(State(state): State<AppState>, Json(req): Json<VerifyReceiptRequest>) -> Json<VerifyReceiptResponse> {
+    let valid = receipt::verify(&state.config.current().receipt_signing_secret, &req.run_id, req.score, &req.week_key, &req.receipt);
+    Json(VerifyReceiptResponse { valid })
+}
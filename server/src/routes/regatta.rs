@@ -0,0 +1,70 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+    Json,
+};
+use booty_hunt_core::{Regatta, RegattaEvent};
+use serde::Deserialize;
+
+use crate::error::AppResult;
+use crate::extractors::AdminAuth;
+use crate::services::{regatta_service, run_service};
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Returns every configured track's current regatta for this week (e.g. the
+/// sloop sprint alongside the galleon marathon), each with its own seed.
+pub async fn get_current(State(state): State<AppState>, TenantId(tenant_id): TenantId) -> AppResult<Json<Vec<Regatta>>> {
+    let week_key = run_service::current_week_key();
+    let regattas = regatta_service::list_current(&state.db, &state.config.current(), &tenant_id, &week_key)?;
+    Ok(Json(regattas))
+}
+
+/// Returns the regattas actually generated for a past week, without
+/// creating any — see `regatta_service::list_for_week`. Cached long and
+/// immutable for any week other than the current one, since a past week's
+/// regattas never change once that week is over; the current week is still
+/// mutable (a track can be rerolled), so it's served without a cache header.
+pub async fn get_for_week(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(week_key): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    let regattas = regatta_service::list_for_week(&state.db, &tenant_id, &week_key)?;
+    if week_key == run_service::current_week_key() {
+        return Ok(Json(regattas).into_response());
+    }
+    Ok((
+        [(header::CACHE_CONTROL, "public, max-age=31536000, immutable")],
+        Json(regattas),
+    )
+        .into_response())
+}
+
+#[derive(Deserialize)]
+pub struct EventsQuery {
+    limit: Option<i64>,
+}
+
+pub async fn get_events(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Query(query): Query<EventsQuery>,
+) -> AppResult<Json<Vec<RegattaEvent>>> {
+    let limit = query.limit.unwrap_or(50).clamp(1, 500);
+    let events = regatta_service::recent_events(&state.db, &tenant_id, limit)?;
+    Ok(Json(events))
+}
+
+/// Blacklists `track`'s current regatta seed and rolls a replacement.
+pub async fn reroll(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(track): Path<String>,
+) -> AppResult<Json<Regatta>> {
+    let week_key = run_service::current_week_key();
+    let regatta = regatta_service::blacklist_and_reroll(&state.db, &tenant_id, &week_key, &track)?;
+    Ok(Json(regatta))
+}
@@ -0,0 +1,24 @@
+use axum::{extract::State, Json};
+use booty_hunt_core::{CreateRulesetRequest, Ruleset};
+
+use crate::error::AppResult;
+use crate::services::ruleset_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Creates a ruleset for the caller's tenant. There's no admin auth layer
+/// yet (see `routes::admin`), so this carries the same caveat: gate
+/// `/api/rulesets` at the reverse proxy until one exists.
+pub async fn create(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<CreateRulesetRequest>,
+) -> AppResult<Json<Ruleset>> {
+    let ruleset = ruleset_service::create(&state.db, &tenant_id, req)?;
+    Ok(Json(ruleset))
+}
+
+pub async fn list(State(state): State<AppState>, TenantId(tenant_id): TenantId) -> AppResult<Json<Vec<Ruleset>>> {
+    let rulesets = ruleset_service::list(&state.db, &tenant_id)?;
+    Ok(Json(rulesets))
+}
@@ -0,0 +1,214 @@
+use std::net::SocketAddr;
+
+use axum::{
+    extract::{ConnectInfo, Multipart, Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use booty_hunt_core::{
+    AttachGhostTapeRequest, AttachGhostTapeResult, IssueNonceRequest, IssueNonceResponse, RunBundle, RunDetail, RunSubmission,
+    RunSubmissionResult, SubmitAppealRequest, ValidationReport,
+};
+
+use base64::Engine;
+
+use crate::error::AppResult;
+use crate::extractors::RunSubmissionBody;
+use crate::geo;
+use crate::services::run_service::RunPipelineExtensions;
+use crate::services::{appeal_service, bundle_service, nonce_service, run_card_service, run_service};
+use crate::state::AppState;
+use crate::tenant::DEFAULT_TENANT;
+
+fn region_header(headers: &HeaderMap) -> Option<&str> {
+    headers.get("x-region").and_then(|v| v.to_str().ok())
+}
+
+fn tenant_header(headers: &HeaderMap) -> &str {
+    headers.get("x-tenant-id").and_then(|v| v.to_str().ok()).filter(|v| !v.is_empty()).unwrap_or(DEFAULT_TENANT)
+}
+
+/// Issues a single-use nonce for `player_id`/`seed`, to be echoed back in
+/// the run submission it gates — see `nonce_service::issue`. Always
+/// available regardless of `Config::submission_nonce_required` so a client
+/// can adopt the flow ahead of the server actually requiring it.
+pub async fn issue_nonce(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<IssueNonceRequest>,
+) -> AppResult<Json<IssueNonceResponse>> {
+    let tenant_id = tenant_header(&headers);
+    let submission_nonce = nonce_service::issue(&state.db, tenant_id, &req.player_id, req.seed)?;
+    Ok(Json(IssueNonceResponse { submission_nonce }))
+}
+
+pub async fn submit_run(
+    State(state): State<AppState>,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    RunSubmissionBody(submission): RunSubmissionBody,
+) -> AppResult<Json<RunSubmissionResult>> {
+    let config = state.config.current();
+    let region = geo::derive_region(&config, region_header(&headers), Some(remote.ip()));
+    let tenant_id = tenant_header(&headers);
+    let result = run_service::submit_run(
+        &state.db,
+        &config,
+        submission,
+        region,
+        tenant_id,
+        RunPipelineExtensions {
+            run_hooks: &state.run_hooks,
+            tape_blob_store: state.tape_blob_store.as_ref(),
+            notification_providers: &state.notification_providers,
+        },
+    )
+    .await?;
+    Ok(Json(result))
+}
+
+/// One run's public detail view, including its ghost-race ancestry — see
+/// `booty_hunt_core::RunDetail`.
+pub async fn detail(State(state): State<AppState>, headers: HeaderMap, Path(run_id): Path<String>) -> AppResult<Json<RunDetail>> {
+    let config = state.config.current();
+    let tenant_id = tenant_header(&headers);
+    Ok(Json(run_service::detail(&state.db, &config, tenant_id, &run_id)?))
+}
+
+/// Everything needed to reproduce or dispute a run in one document — see
+/// `bundle_service::build`.
+pub async fn bundle(State(state): State<AppState>, headers: HeaderMap, Path(run_id): Path<String>) -> AppResult<Json<RunBundle>> {
+    let tenant_id = tenant_header(&headers);
+    Ok(Json(bundle_service::build(&state.db, state.tape_blob_store.as_ref(), tenant_id, &run_id).await?))
+}
+
+/// A shareable score card for a run, rendered server-side as SVG for social
+/// unfurls (Discord, etc.) that can't execute client-side canvas code.
+/// ETag-cached like `GET /api/tuning` — a run's card content never changes
+/// after submission, so an `If-None-Match` hit is always safe to serve as a
+/// bodyless `304`.
+pub async fn card(State(state): State<AppState>, Path(run_id): Path<String>, headers: HeaderMap) -> AppResult<impl IntoResponse> {
+    let tenant_id = tenant_header(&headers);
+    let (svg, etag) = run_card_service::render_svg(&state.db, tenant_id, &run_id)?;
+    let max_age = state.config.current().run_card_cache_max_age_secs;
+    let cache_control = format!("public, max-age={max_age}, immutable");
+
+    if headers.get(header::IF_NONE_MATCH).and_then(|v| v.to_str().ok()) == Some(etag.as_str()) {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, etag), (header::CACHE_CONTROL, cache_control)]).into_response());
+    }
+    Ok((
+        [(header::CONTENT_TYPE, "image/svg+xml".to_string()), (header::ETAG, etag), (header::CACHE_CONTROL, cache_control)],
+        svg,
+    )
+        .into_response())
+}
+
+/// Files an appeal against a hidden run, re-queuing it for moderator
+/// review — see `appeal_service::submit_appeal`. Status is visible via
+/// `detail`'s `appeal` field.
+pub async fn appeal(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(run_id): Path<String>,
+    Json(req): Json<SubmitAppealRequest>,
+) -> AppResult<StatusCode> {
+    let tenant_id = tenant_header(&headers);
+    appeal_service::submit_appeal(&state.db, tenant_id, &run_id, &req.player_id, &req.statement)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Runs the same checks `submit_run` would without inserting a row, so mod
+/// and tooling developers can test an export before a real submission.
+/// Unlike `submit_run`, this reports every violation found rather than the
+/// first one, and always returns `200` — a failing dry run is a normal
+/// result, not a validation error from calling the endpoint itself.
+pub async fn validate_run(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    RunSubmissionBody(submission): RunSubmissionBody,
+) -> AppResult<Json<ValidationReport>> {
+    let config = state.config.current();
+    let tenant_id = tenant_header(&headers);
+    let report = run_service::validate_dry_run(&state.db, &config, tenant_id, &submission)?;
+    Ok(Json(report))
+}
+
+/// Attaches a ghost tape to a run submitted without one, so a client whose
+/// tape upload failed doesn't have to resubmit the whole run to retry it.
+/// Authenticated by that run's own receipt (see `AttachGhostTapeRequest`)
+/// rather than a player session — anyone who can produce the receipt
+/// `submit_run` returned for this run is trusted to attach its tape.
+pub async fn attach_ghost(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<AttachGhostTapeRequest>,
+) -> AppResult<Json<AttachGhostTapeResult>> {
+    let config = state.config.current();
+    let tenant_id = tenant_header(&headers);
+    let result =
+        run_service::attach_ghost_tape(&state.db, &config, tenant_id, state.tape_blob_store.as_ref(), &run_id, &req).await?;
+    Ok(Json(result))
+}
+
+/// Maximum ghost tape size accepted via the multipart upload path, enforced
+/// while streaming rather than after the whole body is buffered.
+const MAX_TAPE_BYTES: usize = 8 * 1024 * 1024;
+
+pub async fn upload_run(
+    State(state): State<AppState>,
+    ConnectInfo(remote): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> AppResult<Json<RunSubmissionResult>> {
+    let mut metadata: Option<RunSubmission> = None;
+    let mut tape: Option<Vec<u8>> = None;
+
+    while let Some(field) = multipart.next_field().await.map_err(|e| crate::error::AppError::Validation(e.to_string()))? {
+        match field.name() {
+            Some("metadata") => {
+                let bytes = field.bytes().await.map_err(|e| crate::error::AppError::Validation(e.to_string()))?;
+                metadata = Some(
+                    serde_json::from_slice(&bytes)
+                        .map_err(|e| crate::error::AppError::Validation(format!("invalid metadata part: {e}")))?,
+                );
+            }
+            Some("tape") => {
+                let mut buf = Vec::new();
+                let mut field = field;
+                while let Some(chunk) = field.chunk().await.map_err(|e| crate::error::AppError::Validation(e.to_string()))? {
+                    if buf.len() + chunk.len() > MAX_TAPE_BYTES {
+                        return Err(crate::error::AppError::Validation("ghost tape exceeds size limit".into()));
+                    }
+                    buf.extend_from_slice(&chunk);
+                }
+                tape = Some(buf);
+            }
+            _ => {}
+        }
+    }
+
+    let mut submission = metadata.ok_or_else(|| crate::error::AppError::Validation("missing metadata part".into()))?;
+    if let Some(tape) = tape {
+        submission.ghost_tape = Some(base64::engine::general_purpose::STANDARD.encode(tape));
+    }
+
+    let config = state.config.current();
+    let region = geo::derive_region(&config, region_header(&headers), Some(remote.ip()));
+    let tenant_id = tenant_header(&headers);
+    let result = run_service::submit_run(
+        &state.db,
+        &config,
+        submission,
+        region,
+        tenant_id,
+        RunPipelineExtensions {
+            run_hooks: &state.run_hooks,
+            tape_blob_store: state.tape_blob_store.as_ref(),
+            notification_providers: &state.notification_providers,
+        },
+    )
+    .await?;
+    Ok(Json(result))
+}
@@ -0,0 +1,117 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use booty_hunt_core::{Page, TradeOffer};
+use serde::Deserialize;
+
+use crate::error::{AppError, AppResult};
+use crate::services::{signal_fire_service, signal_fire_trade_service};
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// Server-rendered QR code for a signal fire's redemption deep link, so the
+/// client and community sites showing a code on screen don't each need to
+/// pull in a QR library. SVG rather than PNG — vector output needs no image
+/// codec dependency and scales cleanly at whatever size the caller displays
+/// it, and nothing so far has asked for a raster format.
+pub async fn qr(State(state): State<AppState>, TenantId(tenant_id): TenantId, Path(code): Path<String>) -> AppResult<Response> {
+    if !signal_fire_service::exists(&state.db, &tenant_id, &code)? {
+        return Err(AppError::NotFound);
+    }
+
+    let deep_link = signal_fire_service::redemption_deep_link(&code);
+    let qr_code =
+        qrcode::QrCode::new(deep_link.as_bytes()).map_err(|e| AppError::Internal(format!("failed to build QR code: {e}")))?;
+    let svg = qr_code.render::<qrcode::render::svg::Color>().build();
+
+    Ok(([(header::CONTENT_TYPE, "image/svg+xml")], svg).into_response())
+}
+
+#[derive(Deserialize)]
+pub struct CreateTradeOfferRequest {
+    player_id: String,
+    code: String,
+    wanted_aid_type: String,
+}
+
+/// Posts a standing offer to trade an unredeemed signal fire for one of a
+/// different aid type. See `signal_fire_trade_service::create_offer` for the
+/// escrow and abuse-limit handling.
+pub async fn create_trade_offer(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<CreateTradeOfferRequest>,
+) -> AppResult<Json<TradeOffer>> {
+    let max_open_offers = state.config.current().max_open_trade_offers_per_player;
+    let offer = signal_fire_trade_service::create_offer(
+        &state.db,
+        &tenant_id,
+        &req.player_id,
+        &req.code,
+        &req.wanted_aid_type,
+        max_open_offers,
+    )?;
+    Ok(Json(offer))
+}
+
+#[derive(Deserialize)]
+pub struct ListTradeOffersQuery {
+    wanted_aid_type: Option<String>,
+    limit: Option<i64>,
+    /// A prior page's `next_cursor` — see `booty_hunt_core::Page`. Omitted
+    /// for the first page.
+    cursor: Option<String>,
+}
+
+/// Lists open trade offers, optionally narrowed to a wanted aid type.
+pub async fn list_trade_offers(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Query(query): Query<ListTradeOffersQuery>,
+) -> AppResult<Json<Page<TradeOffer>>> {
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    let offers = signal_fire_trade_service::list_open(
+        &state.db,
+        &tenant_id,
+        query.wanted_aid_type.as_deref(),
+        limit,
+        query.cursor.as_deref(),
+    )?;
+    Ok(Json(offers))
+}
+
+#[derive(Deserialize)]
+pub struct AcceptTradeOfferRequest {
+    player_id: String,
+    code: String,
+}
+
+/// Accepts an open trade offer, swapping the two escrowed codes atomically.
+pub async fn accept_trade_offer(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(offer_id): Path<String>,
+    Json(req): Json<AcceptTradeOfferRequest>,
+) -> AppResult<Json<TradeOffer>> {
+    let offer = signal_fire_trade_service::accept_offer(&state.db, &tenant_id, &offer_id, &req.player_id, &req.code)?;
+    Ok(Json(offer))
+}
+
+#[derive(Deserialize)]
+pub struct CancelTradeOfferRequest {
+    player_id: String,
+}
+
+/// Cancels an offer the caller posted, releasing its escrowed code.
+pub async fn cancel_trade_offer(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(offer_id): Path<String>,
+    Json(req): Json<CancelTradeOfferRequest>,
+) -> AppResult<StatusCode> {
+    signal_fire_trade_service::cancel_offer(&state.db, &tenant_id, &offer_id, &req.player_id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,19 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use booty_hunt_core::SumOfBest;
+
+use crate::error::AppResult;
+use crate::services::splits_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+pub async fn get_sum_of_best(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(seed): Path<i64>,
+) -> AppResult<Json<SumOfBest>> {
+    let sum_of_best = splits_service::fetch_sum_of_best(&state.db, &tenant_id, seed)?;
+    Ok(Json(sum_of_best))
+}
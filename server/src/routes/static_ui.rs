@@ -0,0 +1,7 @@
+use axum::response::Html;
+
+const LEADERBOARD_HTML: &str = include_str!("../../assets/leaderboard.html");
+
+pub async fn leaderboard_page() -> Html<&'static str> {
+    Html(LEADERBOARD_HTML)
+}
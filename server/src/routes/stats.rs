@@ -0,0 +1,33 @@
+use axum::extract::{Query, State};
+use axum::Json;
+use booty_hunt_core::TimeseriesPoint;
+use serde::Deserialize;
+
+use crate::error::AppResult;
+use crate::services::stats_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+#[derive(Deserialize)]
+pub struct TimeseriesQuery {
+    from: String,
+    to: String,
+    #[serde(default = "default_interval")]
+    interval: String,
+}
+
+fn default_interval() -> String {
+    "hour".to_string()
+}
+
+/// Chart-ready activity counters (submissions, victories, unique players,
+/// redemptions) bucketed by hour or day, backed by the `hourly_stats`
+/// rollup rather than a live scan of `runs`.
+pub async fn timeseries(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Query(query): Query<TimeseriesQuery>,
+) -> AppResult<Json<Vec<TimeseriesPoint>>> {
+    let points = stats_service::timeseries(&state.db, &tenant_id, &query.from, &query.to, &query.interval)?;
+    Ok(Json(points))
+}
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::error::AppResult;
+use crate::services::tape_upload_service;
+use crate::state::AppState;
+
+pub async fn start_session(
+    State(state): State<AppState>,
+    Path(run_id): Path<String>,
+) -> AppResult<Json<Value>> {
+    let session_id = tape_upload_service::start_session(&state.db, &run_id)?;
+    Ok(Json(json!({ "session_id": session_id })))
+}
+
+#[derive(Deserialize)]
+pub struct ChunkQuery {
+    offset: usize,
+}
+
+pub async fn put_chunk(
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+    Query(query): Query<ChunkQuery>,
+    body: axum::body::Bytes,
+) -> AppResult<Json<Value>> {
+    let new_len = tape_upload_service::put_chunk(&state.db, &session_id, query.offset, &body)?;
+    Ok(Json(json!({ "received_bytes": new_len })))
+}
+
+pub async fn finalize(
+    State(state): State<AppState>,
+    Path(session_id): Path<String>,
+) -> AppResult<Json<Value>> {
+    tape_upload_service::finalize(&state.db, &session_id)?;
+    Ok(Json(json!({ "finalized": true })))
+}
@@ -0,0 +1,65 @@
+use axum::extract::{Query, State};
+use axum::Json;
+use booty_hunt_core::TelemetryAggregateBucket;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::AppResult;
+use crate::services::telemetry_service::{self, TelemetryEventInput};
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+#[derive(Deserialize)]
+pub struct TelemetryEventPayload {
+    event_type: String,
+    player_id: Option<String>,
+    payload: Value,
+}
+
+#[derive(Deserialize)]
+pub struct IngestRequest {
+    events: Vec<TelemetryEventPayload>,
+}
+
+#[derive(serde::Serialize)]
+pub struct IngestResponse {
+    accepted: usize,
+}
+
+/// Ingests a batch of gameplay events (deaths per wave, upgrade picks, and
+/// whatever else `Config::telemetry_event_schemas` declares). Kept on its
+/// own route with its own body limit rather than folded into an existing
+/// endpoint, since a balance-analytics client batching hundreds of events at
+/// once has a very different size profile than a run submission.
+pub async fn ingest(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<IngestRequest>,
+) -> AppResult<Json<IngestResponse>> {
+    let config = state.config.current();
+    let events = req
+        .events
+        .into_iter()
+        .map(|e| TelemetryEventInput { event_type: e.event_type, player_id: e.player_id, payload: e.payload })
+        .collect();
+    let accepted = telemetry_service::ingest_batch(&state.db, &config, &tenant_id, events)?;
+    Ok(Json(IngestResponse { accepted }))
+}
+
+#[derive(Deserialize)]
+pub struct AggregateQuery {
+    event_type: String,
+    group_by: String,
+}
+
+/// Counts events of `event_type` grouped by one declared payload field, e.g.
+/// `?event_type=wave_death&group_by=wave` for deaths per wave.
+pub async fn aggregate(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Query(query): Query<AggregateQuery>,
+) -> AppResult<Json<Vec<TelemetryAggregateBucket>>> {
+    let config = state.config.current();
+    let buckets = telemetry_service::aggregate_by_field(&state.db, &config, &tenant_id, &query.event_type, &query.group_by)?;
+    Ok(Json(buckets))
+}
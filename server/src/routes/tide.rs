@@ -0,0 +1,43 @@
+use axum::extract::State;
+use axum::http::{header::ACCEPT_LANGUAGE, HeaderMap};
+use axum::Json;
+use booty_hunt_core::{TideContributionResult, TideMetricDefinition, TideOmen};
+use serde::Deserialize;
+
+use crate::error::AppResult;
+use crate::i18n;
+use crate::services::tide_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// The catalog of accepted `POST /api/tide/contribute` metrics, so clients
+/// discover valid metric keys, units, and this week's goals instead of
+/// guessing metric strings.
+pub async fn get_metrics(State(state): State<AppState>) -> Json<Vec<TideMetricDefinition>> {
+    Json(tide_service::metrics(&state.config.current()))
+}
+
+/// This week's tide omens, localized against the caller's `Accept-Language`.
+pub async fn get_omens(State(state): State<AppState>, headers: HeaderMap) -> Json<Vec<TideOmen>> {
+    let locale = i18n::negotiate(headers.get(ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok()));
+    Json(tide_service::omens(&state.config.current(), locale))
+}
+
+#[derive(Deserialize)]
+pub struct ContributeRequest {
+    player_id: String,
+    metric: String,
+    amount: i64,
+}
+
+/// Records one contribution toward this week's tide event, validated against
+/// the catalog `get_metrics` publishes.
+pub async fn contribute(
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Json(req): Json<ContributeRequest>,
+) -> AppResult<Json<TideContributionResult>> {
+    let config = state.config.current();
+    let result = tide_service::contribute(&state.db, &config, &tenant_id, &req.player_id, &req.metric, req.amount)?;
+    Ok(Json(result))
+}
@@ -0,0 +1,49 @@
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::Json;
+use booty_hunt_core::{SetTuningValueRequest, TuningHistoryEntry, TuningValue};
+
+use crate::error::AppResult;
+use crate::extractors::AdminAuth;
+use crate::services::tuning_service;
+use crate::state::AppState;
+use crate::tenant::TenantId;
+
+/// The full current tuning snapshot, ETag-cached on `version` — a client
+/// that already has the current version sends it back as `If-None-Match`
+/// and gets a bodyless `304` instead of re-downloading and re-parsing the
+/// whole set. `Cache-Control: no-cache` tells a CDN it may still cache the
+/// body, but must revalidate the ETag on every request rather than serving
+/// a stale snapshot after tuning changes — `version` is the cache-busting
+/// key, not the URL, since there's only ever one current snapshot.
+pub async fn get_snapshot(State(state): State<AppState>, TenantId(tenant_id): TenantId, headers: HeaderMap) -> AppResult<impl IntoResponse> {
+    let snapshot = tuning_service::snapshot(&state.db, &tenant_id)?;
+    let etag = format!("\"{}\"", snapshot.version);
+    let cache_control = "no-cache";
+
+    if headers.get(header::IF_NONE_MATCH).and_then(|v| v.to_str().ok()) == Some(etag.as_str()) {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, etag), (header::CACHE_CONTROL, cache_control.to_string())]).into_response());
+    }
+    Ok(([(header::ETAG, etag), (header::CACHE_CONTROL, cache_control.to_string())], Json(snapshot)).into_response())
+}
+
+pub async fn set_value(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(key): Path<String>,
+    Json(req): Json<SetTuningValueRequest>,
+) -> AppResult<Json<TuningValue>> {
+    let value = tuning_service::set_value(&state.db, &tenant_id, &key, req)?;
+    Ok(Json(value))
+}
+
+pub async fn get_history(
+    _admin: AdminAuth,
+    State(state): State<AppState>,
+    TenantId(tenant_id): TenantId,
+    Path(key): Path<String>,
+) -> AppResult<Json<Vec<TuningHistoryEntry>>> {
+    Ok(Json(tuning_service::history(&state.db, &tenant_id, &key)?))
+}
@@ -0,0 +1,421 @@
+//! Background jobs that run on a fixed interval for the lifetime of the
+//! process. Each job is spawned independently so a slow or panicking job
+//! doesn't stall the others; add new jobs here as new subsystems need
+//! periodic maintenance (session GC, weekly rollovers, exports, ...).
+//!
+//! Most jobs guard their actual work with `should_run`, which uses
+//! `scheduler_lock_service` to make sure only one server instance does that
+//! work on a given tick when several instances share the same database —
+//! see its doc comment for why. Skip the guard only for jobs that mutate
+//! purely in-memory, per-process state (see `spawn_popularity_flush` and
+//! `spawn_integrity_check`), where every instance genuinely needs to run.
+
+use std::time::Duration;
+
+use crate::services::{
+    analytics_export_service, announcement_service, community_event_service, digest_service, division_service,
+    leaderboard_finalization_service, nonce_service, public_dump_service, raid_service, replication_service, run_service,
+    scheduler_lock_service, tape_upload_service, telemetry_service,
+};
+use crate::state::AppState;
+use crate::tenant::DEFAULT_TENANT;
+
+/// Whether this instance should run `job_name`'s work this tick — `false`
+/// either because another instance currently holds the lock, or because
+/// acquiring it failed outright (treated the same as losing it: better to
+/// skip a tick than to risk double-running past a DB error).  `lease` should
+/// outlast the calling job's own tick interval by a comfortable margin, so a
+/// crashed holder's lock expires well before its next tick would've come
+/// around. Every job still calls `scheduler_status.record` regardless, so a
+/// dashboard shows the loop alive even on a tick this instance lost.
+fn should_run(state: &AppState, job_name: &str, lease: Duration) -> bool {
+    match scheduler_lock_service::try_acquire(&state.db, job_name, &state.instance_id, lease) {
+        Ok(acquired) => acquired,
+        Err(err) => {
+            tracing::error!(%err, job_name, "scheduler lock acquisition failed");
+            false
+        }
+    }
+}
+
+const TAPE_SESSION_GC_INTERVAL: Duration = Duration::from_secs(300);
+const NONCE_GC_INTERVAL: Duration = Duration::from_secs(300);
+const POPULARITY_FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+const DIGEST_ROLLOVER_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+const INTEGRITY_CHECK_INTERVAL: Duration = Duration::from_secs(7 * 24 * 3600);
+const TELEMETRY_RETENTION_INTERVAL: Duration = Duration::from_secs(3600);
+const REGATTA_COUNTDOWN_CHECK_INTERVAL: Duration = Duration::from_secs(300);
+const WEEK_ROLLOVER_ANNOUNCEMENT_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+const RAID_FINALIZATION_CHECK_INTERVAL: Duration = Duration::from_secs(300);
+const DIVISION_ASSIGNMENT_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+const LEADERBOARD_FINALIZATION_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+const EVENT_REWARD_FINALIZATION_CHECK_INTERVAL: Duration = Duration::from_secs(300);
+const PUBLIC_DUMP_GENERATION_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+const REGATTA_COUNTDOWN_WINDOW_SECS: i64 = 3600;
+
+pub fn spawn_all(state: AppState) {
+    spawn_tape_session_gc(state.clone());
+    spawn_nonce_gc(state.clone());
+    spawn_popularity_flush(state.clone());
+    spawn_digest_rollover(state.clone());
+    spawn_integrity_check(state.clone());
+    spawn_telemetry_retention(state.clone());
+    spawn_replication(state.clone());
+    spawn_analytics_export(state.clone());
+    spawn_regatta_countdown(state.clone());
+    spawn_week_rollover_announcement(state.clone());
+    spawn_raid_finalization(state.clone());
+    spawn_division_assignment(state.clone());
+    spawn_leaderboard_finalization(state.clone());
+    spawn_event_reward_finalization(state.clone());
+    spawn_public_dump_generation(state);
+}
+
+fn spawn_tape_session_gc(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(TAPE_SESSION_GC_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "tape_session_gc", TAPE_SESSION_GC_INTERVAL * 2) {
+                match tape_upload_service::gc_expired(&state.db) {
+                    Ok(count) if count > 0 => tracing::info!(count, "reaped expired tape upload sessions"),
+                    Ok(_) => {}
+                    Err(err) => tracing::error!(%err, "tape session GC failed"),
+                }
+            }
+            state.scheduler_status.record("tape_session_gc");
+        }
+    });
+}
+
+fn spawn_nonce_gc(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(NONCE_GC_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "nonce_gc", NONCE_GC_INTERVAL * 2) {
+                match nonce_service::gc_expired(&state.db) {
+                    Ok(count) if count > 0 => tracing::info!(count, "reaped expired submission nonces"),
+                    Ok(_) => {}
+                    Err(err) => tracing::error!(%err, "submission nonce GC failed"),
+                }
+            }
+            state.scheduler_status.record("nonce_gc");
+        }
+    });
+}
+
+/// Not lock-guarded like the other jobs in this file: `PopularityCounters`
+/// is in-memory per process, so each instance must flush its own pending
+/// counts itself — locking this out on a non-leader instance would silently
+/// drop the downloads it recorded rather than just delaying them.
+fn spawn_popularity_flush(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(POPULARITY_FLUSH_INTERVAL);
+        loop {
+            ticker.tick().await;
+            match state.popularity.flush(&state.db) {
+                Ok(count) if count > 0 => tracing::debug!(count, "flushed replay download counters"),
+                Ok(_) => {}
+                Err(err) => tracing::error!(%err, "popularity counter flush failed"),
+            }
+            state.scheduler_status.record("popularity_flush");
+        }
+    });
+}
+
+/// Checks hourly whether the digest for the week that just ended exists yet,
+/// generating it if not. Hourly (rather than exactly at week boundary) keeps
+/// this simple and self-healing after downtime, at the cost of the digest
+/// appearing up to an hour after the week actually rolls over.
+fn spawn_digest_rollover(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(DIGEST_ROLLOVER_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "digest_rollover", DIGEST_ROLLOVER_CHECK_INTERVAL * 2) {
+                let week_key = run_service::previous_week_key();
+                if let Err(err) = digest_service::get_or_generate(&state.db, &week_key) {
+                    tracing::error!(%err, week_key, "weekly digest generation failed");
+                }
+            }
+            state.scheduler_status.record("digest_rollover");
+        }
+    });
+}
+
+/// Weekly `PRAGMA quick_check` — fast enough to run in the background
+/// without an admin blocking traffic for it. Problems land in
+/// `Db::last_integrity_problems`, surfaced via `/api/health`; recovering
+/// from real corruption still needs the admin-triggered `repair` endpoint.
+/// Not lock-guarded: `last_integrity_problems` is per-process, so every
+/// instance needs to run its own check for its own `/api/health` to be
+/// accurate — unlike the other jobs here, running this on every instance
+/// isn't wasted duplicate work.
+fn spawn_integrity_check(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(INTEGRITY_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            match state.db.quick_check() {
+                Ok(problems) if !problems.is_empty() => {
+                    tracing::error!(?problems, "database integrity check found problems")
+                }
+                Ok(_) => {}
+                Err(err) => tracing::error!(%err, "database integrity check failed to run"),
+            }
+            state.scheduler_status.record("integrity_check");
+        }
+    });
+}
+
+/// Hourly deletion of telemetry events past `telemetry_retention_days` — the
+/// "aggressive retention" this high-volume, low-per-event-value table needs
+/// in place of real table partitioning, which SQLite doesn't offer. Reads
+/// the retention window fresh from the live config on every tick (unlike
+/// `spawn_replication`'s spawn-time snapshot) since this job runs often
+/// enough that picking up a `reload_from_env()` promptly is worth the extra
+/// `current()` call.
+fn spawn_telemetry_retention(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(TELEMETRY_RETENTION_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "telemetry_retention", TELEMETRY_RETENTION_INTERVAL * 2) {
+                let retention_days = state.config.current().telemetry_retention_days;
+                match telemetry_service::prune_expired(&state.db, retention_days) {
+                    Ok(count) if count > 0 => tracing::info!(count, "pruned expired telemetry events"),
+                    Ok(_) => {}
+                    Err(err) => tracing::error!(%err, "telemetry retention prune failed"),
+                }
+            }
+            state.scheduler_status.record("telemetry_retention");
+        }
+    });
+}
+
+/// Periodic `VACUUM INTO` snapshot for disaster recovery. Disabled unless an
+/// operator opts in with `BOOTY_HUNT_REPLICATION_ENABLED`, since it needs a
+/// destination directory that's actually worth restoring from (a different
+/// disk, ideally a different host mounted over the network).
+fn spawn_replication(state: AppState) {
+    // Read once at spawn time, matching the other jobs here — a later
+    // `reload_from_env()` can change `replication_interval_secs` or
+    // `replication_dest_dir` without a restart, but this job's ticker won't
+    // pick that up until the process restarts. Toggling `replication_enabled`
+    // off at runtime doesn't stop an already-spawned job either; both are
+    // narrower than the per-request config reads elsewhere.
+    let config = state.config.current();
+    if !config.replication_enabled {
+        return;
+    }
+    let lease = Duration::from_secs(config.replication_interval_secs * 2);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(config.replication_interval_secs));
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "replication", lease) {
+                match replication_service::snapshot(&state.db, &config.replication_dest_dir) {
+                    Ok(path) => tracing::info!(path, "wrote replication snapshot"),
+                    Err(err) => tracing::error!(%err, "replication snapshot failed"),
+                }
+            }
+            state.scheduler_status.record("replication");
+        }
+    });
+}
+
+/// Periodic incremental CSV export of `runs`/`telemetry_events`. Disabled
+/// unless an operator opts in, same as `spawn_replication`; reads config
+/// once at spawn time for the same reason that job does.
+fn spawn_analytics_export(state: AppState) {
+    let config = state.config.current();
+    if !config.analytics_export_enabled {
+        return;
+    }
+    let lease = Duration::from_secs(config.analytics_export_interval_secs * 2);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(config.analytics_export_interval_secs));
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "analytics_export", lease) {
+                match analytics_export_service::export_all(&state.db, &config.analytics_export_dest_dir) {
+                    Ok(paths) if !paths.is_empty() => tracing::info!(?paths, "wrote analytics export files"),
+                    Ok(_) => {}
+                    Err(err) => tracing::error!(%err, "analytics export failed"),
+                }
+            }
+            state.scheduler_status.record("analytics_export");
+        }
+    });
+}
+
+/// Checks every five minutes whether the current ISO week is within an hour
+/// of rolling over, posting one `regatta_ending_soon` announcement per
+/// configured track. `last_announced_week` lives in this task's own loop
+/// rather than shared state — there's only ever one instance of this job, so
+/// a local variable is enough to dedup across ticks.
+fn spawn_regatta_countdown(state: AppState) {
+    tokio::spawn(async move {
+        let http = reqwest::Client::new();
+        let mut ticker = tokio::time::interval(REGATTA_COUNTDOWN_CHECK_INTERVAL);
+        let mut last_announced_week: Option<String> = None;
+        loop {
+            ticker.tick().await;
+            let week_key = run_service::current_week_key();
+            let remaining = run_service::week_end_utc() - chrono::Utc::now();
+            let within_window = remaining.num_seconds() > 0 && remaining.num_seconds() <= REGATTA_COUNTDOWN_WINDOW_SECS;
+            if within_window
+                && last_announced_week.as_deref() != Some(week_key.as_str())
+                && should_run(&state, "regatta_countdown", REGATTA_COUNTDOWN_CHECK_INTERVAL * 2)
+            {
+                let config = state.config.current();
+                for track in &config.regatta_tracks {
+                    announcement_service::post_regatta_ending_soon(&http, &config, &week_key, track, remaining.num_seconds()).await;
+                }
+                last_announced_week = Some(week_key);
+            }
+            state.scheduler_status.record("regatta_countdown");
+        }
+    });
+}
+
+/// Hourly check for an ISO week rollover, announcing the new week and its
+/// tide omens once per rollover. `last_seen_week` is initialized at spawn
+/// time (not left as `None`) so a server restart mid-week never fires a
+/// spurious announcement for the week already in progress.
+fn spawn_week_rollover_announcement(state: AppState) {
+    tokio::spawn(async move {
+        let http = reqwest::Client::new();
+        let mut ticker = tokio::time::interval(WEEK_ROLLOVER_ANNOUNCEMENT_CHECK_INTERVAL);
+        let mut last_seen_week = run_service::current_week_key();
+        loop {
+            ticker.tick().await;
+            let week_key = run_service::current_week_key();
+            if week_key != last_seen_week && should_run(&state, "week_rollover_announcement", WEEK_ROLLOVER_ANNOUNCEMENT_CHECK_INTERVAL * 2) {
+                let config = state.config.current();
+                announcement_service::post_new_week_started(&http, &config, &week_key).await;
+                announcement_service::post_omens_revealed(&http, &config, &week_key).await;
+                last_seen_week = week_key;
+            }
+            state.scheduler_status.record("week_rollover_announcement");
+        }
+    });
+}
+
+/// Checks every five minutes whether this week's raid boss has taken enough
+/// damage to fall, finalizing and granting rewards exactly once — see
+/// `raid_service::finalize_if_felled`. Scoped to `DEFAULT_TENANT` only, the
+/// same simplification `digest_service` makes for background jobs: a
+/// deployment running isolated per-tenant raids hasn't shown up yet.
+fn spawn_raid_finalization(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(RAID_FINALIZATION_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "raid_finalization", RAID_FINALIZATION_CHECK_INTERVAL * 2) {
+                let config = state.config.current();
+                match raid_service::finalize_if_felled(&state.db, &config, DEFAULT_TENANT) {
+                    Ok(true) => tracing::info!("raid boss felled, rewards granted"),
+                    Ok(false) => {}
+                    Err(err) => tracing::error!(%err, "raid finalization check failed"),
+                }
+            }
+            state.scheduler_status.record("raid_finalization");
+        }
+    });
+}
+
+/// Checks hourly whether the current week already has promotion/relegation
+/// divisions assigned, assigning them from the previous week's standings if
+/// not — see `division_service::ensure_assigned_for_week`. Hourly rather than
+/// exactly at week boundary, self-healing after downtime, same reasoning as
+/// `spawn_digest_rollover`. Scoped to `DEFAULT_TENANT` only, the same
+/// simplification `digest_service` and `raid_service` make for background
+/// jobs: a deployment running isolated per-tenant divisions hasn't shown up
+/// yet.
+fn spawn_division_assignment(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(DIVISION_ASSIGNMENT_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "division_assignment", DIVISION_ASSIGNMENT_CHECK_INTERVAL * 2) {
+                let config = state.config.current();
+                let week_key = run_service::current_week_key();
+                match division_service::ensure_assigned_for_week(&state.db, &config, DEFAULT_TENANT, &week_key) {
+                    Ok(true) => tracing::info!(week_key, "assigned promotion/relegation divisions"),
+                    Ok(false) => {}
+                    Err(err) => tracing::error!(%err, week_key, "division assignment failed"),
+                }
+            }
+            state.scheduler_status.record("division_assignment");
+        }
+    });
+}
+
+/// Checks hourly whether the week that just ended has been finalized yet,
+/// freezing its default board into `leaderboard_finalizations` if not — see
+/// `leaderboard_finalization_service::finalize_week`. Hourly rather than
+/// exactly at week boundary, self-healing after downtime, same reasoning as
+/// `spawn_digest_rollover`. Scoped to `DEFAULT_TENANT` only, the same
+/// simplification `digest_service` and `raid_service` make for background
+/// jobs.
+fn spawn_leaderboard_finalization(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(LEADERBOARD_FINALIZATION_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "leaderboard_finalization", LEADERBOARD_FINALIZATION_CHECK_INTERVAL * 2) {
+                let week_key = run_service::previous_week_key();
+                if let Err(err) = leaderboard_finalization_service::finalize_week(&state.db, DEFAULT_TENANT, &week_key) {
+                    tracing::error!(%err, week_key, "weekly leaderboard finalization failed");
+                }
+            }
+            state.scheduler_status.record("leaderboard_finalization");
+        }
+    });
+}
+
+/// Grants commemorative rewards for community events that have ended — see
+/// `community_event_service::grant_ended_event_rewards`. Same five-minute
+/// cadence as `spawn_raid_finalization`, whose idempotent
+/// check-then-grant shape this job reuses. Scoped to `DEFAULT_TENANT`
+/// only, the same simplification `digest_service` and `raid_service` make
+/// for background jobs: a deployment running isolated per-tenant events
+/// hasn't shown up yet.
+fn spawn_event_reward_finalization(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(EVENT_REWARD_FINALIZATION_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "event_reward_finalization", EVENT_REWARD_FINALIZATION_CHECK_INTERVAL * 2) {
+                match community_event_service::grant_ended_event_rewards(&state.db, DEFAULT_TENANT) {
+                    Ok(count) if count > 0 => tracing::info!(count, "granted community event rewards"),
+                    Ok(_) => {}
+                    Err(err) => tracing::error!(%err, "community event reward finalization failed"),
+                }
+            }
+            state.scheduler_status.record("event_reward_finalization");
+        }
+    });
+}
+
+/// Generates the public dump for the week that just ended, if it doesn't
+/// exist yet — see `public_dump_service::generate_if_missing`. Hourly, same
+/// self-healing-after-downtime reasoning as `spawn_digest_rollover`.
+fn spawn_public_dump_generation(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(PUBLIC_DUMP_GENERATION_CHECK_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if should_run(&state, "public_dump_generation", PUBLIC_DUMP_GENERATION_CHECK_INTERVAL * 2) {
+                let week_key = run_service::previous_week_key();
+                let config = state.config.current();
+                if let Err(err) = public_dump_service::generate_if_missing(&state.db, &config, DEFAULT_TENANT, &week_key) {
+                    tracing::error!(%err, week_key, "public dump generation failed");
+                }
+            }
+            state.scheduler_status.record("public_dump_generation");
+        }
+    });
+}
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Tracks when each background job last completed a tick, so `/api/health`
+/// can surface "the tape session GC hasn't run in 20 minutes" instead of
+/// operators only noticing when the symptom (a full disk) shows up.
+pub struct SchedulerStatus {
+    last_run: Mutex<HashMap<&'static str, Instant>>,
+}
+
+impl SchedulerStatus {
+    pub fn new() -> Self {
+        SchedulerStatus { last_run: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn record(&self, job: &'static str) {
+        self.last_run.lock().expect("scheduler status mutex poisoned").insert(job, Instant::now());
+    }
+
+    /// Seconds since each job last ran, for jobs that have run at least once.
+    pub fn seconds_since_last_run(&self) -> HashMap<&'static str, u64> {
+        let map = self.last_run.lock().expect("scheduler status mutex poisoned");
+        map.iter().map(|(job, at)| (*job, at.elapsed().as_secs())).collect()
+    }
+}
+
+impl Default for SchedulerStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::error::AppResult;
+
+/// One entry from a decoded ghost tape. The real event set mirrors the
+/// client's scoring events (kills, combo bonuses, wave clears); we only need
+/// the point value to recompute a total server-side.
+#[derive(Debug, Deserialize)]
+struct TapeEvent {
+    #[allow(dead_code)]
+    kind: String,
+    points: i64,
+}
+
+/// Recomputes a run's score from its raw ghost tape bytes, independent of the
+/// client-reported score. Behind `Config::recompute_scores` while the scoring
+/// logic here is still catching up to the client's full ruleset — this is the
+/// anti-cheat foundation, not yet the source of truth.
+pub fn recompute_score(tape: &[u8]) -> AppResult<i64> {
+    let events: Vec<TapeEvent> = serde_json::from_slice(tape)
+        .map_err(|e| crate::error::AppError::Validation(format!("unreadable tape: {e}")))?;
+    Ok(events.iter().map(|e| e.points).sum())
+}
+
+/// Applies `Config::class_score_multipliers` to a raw score to produce the
+/// value stored in `runs.normalized_score` and used to rank the unified
+/// leaderboard category. Classes missing from the map are left unscaled.
+pub fn normalize_score(config: &Config, ship_class: &str, score: i64) -> i64 {
+    let multiplier = config.class_score_multipliers.get(ship_class).copied().unwrap_or(1.0);
+    (score as f64 * multiplier).round() as i64
+}
@@ -0,0 +1,189 @@
+//! Bulk moderation actions over runs — hide, delete, or ban the submitting
+//! player — for working through a wave of cheated scores at once instead of
+//! one `/api/admin/*` call per run. Selection and mutation both run inside
+//! one transaction so a `dry_run` sees exactly what a live run would have
+//! touched, and a live run can't partially apply.
+
+use booty_hunt_core::{BulkRunAction, BulkRunActionRequest, BulkRunActionResult};
+use rusqlite::Connection;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Resolves `req`'s selector to concrete run ids, without mutating anything.
+fn resolve_run_ids(conn: &Connection, tenant_id: &str, req: &BulkRunActionRequest) -> AppResult<Vec<String>> {
+    if let Some(run_ids) = &req.run_ids {
+        if run_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = std::iter::repeat_n("?", run_ids.len()).collect::<Vec<_>>().join(",");
+        let mut stmt = conn.prepare(&format!("SELECT id FROM runs WHERE tenant_id = ? AND id IN ({placeholders})"))?;
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&tenant_id as &dyn rusqlite::ToSql];
+        params.extend(run_ids.iter().map(|id| id as &dyn rusqlite::ToSql));
+        let ids = stmt
+            .query_map(params.as_slice(), |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        return Ok(ids);
+    }
+    let week_key = req
+        .week_key
+        .as_deref()
+        .ok_or_else(|| AppError::Validation("bulk action needs either run_ids or week_key".into()))?;
+    let min_score = req.min_score.unwrap_or(i64::MIN);
+    let mut stmt =
+        conn.prepare("SELECT id FROM runs WHERE tenant_id = ?1 AND week_key = ?2 AND score >= ?3")?;
+    let ids = stmt
+        .query_map(rusqlite::params![tenant_id, week_key, min_score], |row| row.get(0))?
+        .collect::<Result<Vec<String>, _>>()?;
+    Ok(ids)
+}
+
+/// Resolves the selection and, unless `req.dry_run`, applies `req.action` to
+/// every matched run.
+pub fn apply_bulk_action(db: &Db, tenant_id: &str, req: BulkRunActionRequest) -> AppResult<BulkRunActionResult> {
+    let affected_run_ids = db.with_tx(|conn| {
+        let run_ids = resolve_run_ids(conn, tenant_id, &req)?;
+        if req.dry_run {
+            return Ok(run_ids);
+        }
+        for run_id in &run_ids {
+            match req.action {
+                BulkRunAction::Hide => {
+                    conn.execute("UPDATE runs SET hidden = 1 WHERE id = ?1 AND tenant_id = ?2", rusqlite::params![run_id, tenant_id])?;
+                }
+                BulkRunAction::Delete => {
+                    conn.execute("DELETE FROM runs WHERE id = ?1 AND tenant_id = ?2", rusqlite::params![run_id, tenant_id])?;
+                }
+                BulkRunAction::Ban => {
+                    conn.execute("UPDATE runs SET hidden = 1 WHERE id = ?1 AND tenant_id = ?2", rusqlite::params![run_id, tenant_id])?;
+                    conn.execute(
+                        "UPDATE players SET banned = 1 WHERE id = (SELECT player_id FROM runs WHERE id = ?1 AND tenant_id = ?2)",
+                        rusqlite::params![run_id, tenant_id],
+                    )?;
+                }
+            }
+        }
+        Ok(run_ids)
+    })?;
+
+    Ok(BulkRunActionResult { action: req.action, affected_run_ids, dry_run: req.dry_run })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{insert_player, insert_run, test_db};
+
+    fn request(action: BulkRunAction, run_ids: Vec<String>, dry_run: bool) -> BulkRunActionRequest {
+        BulkRunActionRequest { action, run_ids: Some(run_ids), week_key: None, min_score: None, dry_run }
+    }
+
+    fn run_hidden(db: &Db, tenant_id: &str, run_id: &str) -> bool {
+        db.with_read_conn(|conn| {
+            conn.query_row("SELECT hidden FROM runs WHERE id = ?1 AND tenant_id = ?2", rusqlite::params![run_id, tenant_id], |row| {
+                row.get::<_, i64>(0)
+            })
+            .map(|hidden| hidden != 0)
+            .map_err(Into::into)
+        })
+        .unwrap()
+    }
+
+    fn player_banned(db: &Db, tenant_id: &str, player_id: &str) -> bool {
+        db.with_read_conn(|conn| {
+            conn.query_row("SELECT banned FROM players WHERE id = ?1 AND tenant_id = ?2", rusqlite::params![player_id, tenant_id], |row| {
+                row.get::<_, i64>(0)
+            })
+            .map(|banned| banned != 0)
+            .map_err(Into::into)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn hide_marks_the_selected_run_hidden() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-1", "player-1", "2026-w01", 100);
+
+        let result = apply_bulk_action(&db, "tenant-a", request(BulkRunAction::Hide, vec!["run-1".into()], false)).unwrap();
+
+        assert_eq!(result.affected_run_ids, vec!["run-1".to_string()]);
+        assert!(run_hidden(&db, "tenant-a", "run-1"));
+    }
+
+    #[test]
+    fn delete_removes_the_run_row() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-1", "player-1", "2026-w01", 100);
+
+        apply_bulk_action(&db, "tenant-a", request(BulkRunAction::Delete, vec!["run-1".into()], false)).unwrap();
+
+        let count: i64 = db
+            .with_read_conn(|conn| conn.query_row("SELECT COUNT(*) FROM runs WHERE id = 'run-1'", [], |row| row.get(0)).map_err(Into::into))
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn ban_hides_the_run_and_bans_its_player() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-1", "player-1", "2026-w01", 100);
+
+        apply_bulk_action(&db, "tenant-a", request(BulkRunAction::Ban, vec!["run-1".into()], false)).unwrap();
+
+        assert!(run_hidden(&db, "tenant-a", "run-1"));
+        assert!(player_banned(&db, "tenant-a", "player-1"));
+    }
+
+    #[test]
+    fn dry_run_resolves_selection_without_mutating_anything() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-1", "player-1", "2026-w01", 100);
+
+        let result = apply_bulk_action(&db, "tenant-a", request(BulkRunAction::Ban, vec!["run-1".into()], true)).unwrap();
+
+        assert_eq!(result.affected_run_ids, vec!["run-1".to_string()]);
+        assert!(result.dry_run);
+        assert!(!run_hidden(&db, "tenant-a", "run-1"));
+        assert!(!player_banned(&db, "tenant-a", "player-1"));
+    }
+
+    #[test]
+    fn a_run_id_belonging_to_another_tenant_is_not_resolved_or_mutated() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_player(&db, "tenant-b", "player-2");
+        insert_run(&db, "tenant-b", "run-victim", "player-2", "2026-w01", 100);
+
+        let result = apply_bulk_action(&db, "tenant-a", request(BulkRunAction::Ban, vec!["run-victim".into()], false)).unwrap();
+
+        assert!(result.affected_run_ids.is_empty());
+        assert!(!run_hidden(&db, "tenant-b", "run-victim"));
+        assert!(!player_banned(&db, "tenant-b", "player-2"));
+    }
+
+    #[test]
+    fn week_key_selector_only_matches_runs_at_or_above_min_score() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-low", "player-1", "2026-w01", 50);
+        insert_run(&db, "tenant-a", "run-high", "player-1", "2026-w01", 500);
+
+        let req = BulkRunActionRequest {
+            action: BulkRunAction::Hide,
+            run_ids: None,
+            week_key: Some("2026-w01".into()),
+            min_score: Some(100),
+            dry_run: false,
+        };
+        let result = apply_bulk_action(&db, "tenant-a", req).unwrap();
+
+        assert_eq!(result.affected_run_ids, vec!["run-high".to_string()]);
+        assert!(!run_hidden(&db, "tenant-a", "run-low"));
+        assert!(run_hidden(&db, "tenant-a", "run-high"));
+    }
+}
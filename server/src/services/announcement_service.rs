@@ -0,0 +1,52 @@
+//! Scheduled community announcements — regatta countdown, week rollover,
+//! omen reveal — posted as templated JSON to `Config::announcement_webhook_url`.
+//! Lets a community's own Discord/chat webhook post these automatically
+//! instead of a custom bot polling the API for the same events. Delivery
+//! failures are logged and swallowed, same as `hooks::webhook::WebhookRunHook`
+//! — a broken webhook must never take down the scheduler that calls this.
+//!
+//! Announcements are global (not per-tenant), same simplification
+//! `digest_service` makes — a deployment that needs per-tenant countdowns
+//! hasn't shown up yet.
+
+use crate::config::Config;
+use crate::services::tide_service;
+
+async fn post(http: &reqwest::Client, url: &str, payload: serde_json::Value) {
+    if let Err(err) = http.post(url).json(&payload).send().await {
+        tracing::warn!(%err, url, "announcement webhook delivery failed");
+    }
+}
+
+/// Fired once per track when the current ISO week is within an hour of
+/// rolling over.
+pub async fn post_regatta_ending_soon(http: &reqwest::Client, config: &Config, week_key: &str, track: &str, ends_in_secs: i64) {
+    let Some(url) = &config.announcement_webhook_url else { return };
+    post(
+        http,
+        url,
+        serde_json::json!({
+            "kind": "regatta_ending_soon",
+            "week_key": week_key,
+            "track": track,
+            "ends_in_secs": ends_in_secs,
+        }),
+    )
+    .await;
+}
+
+/// Fired once when the scheduler observes `run_service::current_week_key()`
+/// change from what it saw last tick.
+pub async fn post_new_week_started(http: &reqwest::Client, config: &Config, week_key: &str) {
+    let Some(url) = &config.announcement_webhook_url else { return };
+    post(http, url, serde_json::json!({ "kind": "new_week_started", "week_key": week_key })).await;
+}
+
+/// Fired alongside `post_new_week_started` with the fresh week's omen
+/// catalog, localized in English — a background job has no caller
+/// `Accept-Language` to negotiate against, unlike `GET /api/tide/omens`.
+pub async fn post_omens_revealed(http: &reqwest::Client, config: &Config, week_key: &str) {
+    let Some(url) = &config.announcement_webhook_url else { return };
+    let omens = tide_service::omens(config, "en");
+    post(http, url, serde_json::json!({ "kind": "omens_revealed", "week_key": week_key, "omens": omens })).await;
+}
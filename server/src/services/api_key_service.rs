@@ -0,0 +1,85 @@
+use base64::Engine;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// The only scope issued today: read-only access to the issuing player's own
+/// runs and stats. Kept as a string in the schema so new scopes don't need a
+/// migration, but this is the sole value `issue` will produce until community
+/// tools need something narrower or broader.
+pub const SCOPE_READ_ONLY: &str = "read_only";
+
+pub struct IssuedKey {
+    pub key_id: String,
+    pub plaintext_key: String,
+}
+
+pub struct AuthenticatedKey {
+    pub player_id: String,
+}
+
+fn generate_key() -> String {
+    let mut rng = rand::thread_rng();
+    let raw: [u8; 24] = std::array::from_fn(|_| rng.gen());
+    format!("bhk_{}", base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(raw))
+}
+
+fn hash_key(key: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(key.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Issues a new read-only API key for `player_id`. The plaintext key is
+/// returned once and never stored — only its hash is persisted, matching the
+/// recovery-code pattern in `player_service`.
+pub fn issue(db: &Db, player_id: &str, label: &str) -> AppResult<IssuedKey> {
+    let key_id = Uuid::new_v4().to_string();
+    let plaintext_key = generate_key();
+    let key_hash = hash_key(&plaintext_key);
+    let created_at = chrono::Utc::now().to_rfc3339();
+
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO api_keys (id, player_id, key_hash, scope, label, created_at, revoked_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL)",
+            rusqlite::params![key_id, player_id, key_hash, SCOPE_READ_ONLY, label, created_at],
+        )?;
+        Ok(())
+    })?;
+
+    Ok(IssuedKey { key_id, plaintext_key })
+}
+
+/// Revokes a key belonging to `player_id`. Revoking someone else's key or an
+/// already-revoked key is treated as "not found" rather than a distinct
+/// error, so callers can't probe for key ids that aren't theirs.
+pub fn revoke(db: &Db, player_id: &str, key_id: &str) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let updated = conn.execute(
+            "UPDATE api_keys SET revoked_at = ?1 WHERE id = ?2 AND player_id = ?3 AND revoked_at IS NULL",
+            rusqlite::params![chrono::Utc::now().to_rfc3339(), key_id, player_id],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    })
+}
+
+/// Verifies a bearer key presented by a community tool and returns the
+/// player it's scoped to. Used by the auth extractor rather than called from
+/// route handlers directly.
+pub fn verify(db: &Db, plaintext_key: &str) -> AppResult<AuthenticatedKey> {
+    let key_hash = hash_key(plaintext_key);
+    db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT player_id FROM api_keys WHERE key_hash = ?1 AND revoked_at IS NULL",
+            [&key_hash],
+            |row| Ok(AuthenticatedKey { player_id: row.get(0)? }),
+        )
+        .map_err(|_| AppError::Validation("api key not recognized or revoked".into()))
+    })
+}
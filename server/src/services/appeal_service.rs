@@ -0,0 +1,105 @@
+//! Lets a player contest a moderation hide on their own run. Filing an
+//! appeal re-queues the run for human review via
+//! `moderation_queue_service::flag`, same as an automated suspicion hit —
+//! a moderator works appeals from the same queue rather than a separate
+//! surface.
+
+use booty_hunt_core::{AppealStatus, RunAppeal};
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::services::moderation_queue_service;
+
+fn parse_status(raw: &str) -> AppResult<AppealStatus> {
+    match raw {
+        "pending" => Ok(AppealStatus::Pending),
+        "upheld" => Ok(AppealStatus::Upheld),
+        "reinstated" => Ok(AppealStatus::Reinstated),
+        other => Err(AppError::Internal(format!("stored run_appeals.status is unrecognized: {other}"))),
+    }
+}
+
+fn status_str(status: AppealStatus) -> &'static str {
+    match status {
+        AppealStatus::Pending => "pending",
+        AppealStatus::Upheld => "upheld",
+        AppealStatus::Reinstated => "reinstated",
+    }
+}
+
+/// Files (or refiles) an appeal against `run_id`, only accepted while the
+/// run is actually hidden and only from the run's own player — there's no
+/// session auth to check against otherwise, same caveat as
+/// `coaching_service::request_coaching`. Re-queues the run into the
+/// moderation queue with a fixed `suspicion_score` of 0 so it doesn't
+/// distort the score-sorted queue; an appeal is worth reviewing regardless
+/// of how it ranks.
+pub fn submit_appeal(db: &Db, tenant_id: &str, run_id: &str, player_id: &str, statement: &str) -> AppResult<()> {
+    let statement = statement.trim();
+    if statement.is_empty() {
+        return Err(AppError::Validation("appeal statement must not be empty".into()));
+    }
+
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        let (row_player_id, hidden): (String, i64) = conn
+            .query_row("SELECT player_id, hidden FROM runs WHERE id = ?1", [run_id], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|_| AppError::NotFound)?;
+        if row_player_id != player_id {
+            return Err(AppError::Validation("only the run's own player may appeal it".into()));
+        }
+        if hidden == 0 {
+            return Err(AppError::Validation("run is not hidden, nothing to appeal".into()));
+        }
+
+        conn.execute(
+            "INSERT INTO run_appeals (run_id, player_id, statement, status, created_at, resolved_at)
+             VALUES (?1, ?2, ?3, 'pending', ?4, NULL)
+             ON CONFLICT (run_id) DO UPDATE SET statement = excluded.statement, status = 'pending', created_at = excluded.created_at, resolved_at = NULL",
+            rusqlite::params![run_id, player_id, statement, created_at],
+        )?;
+
+        moderation_queue_service::flag(conn, tenant_id, run_id, player_id, "appeal", 0, &created_at)
+    })
+}
+
+/// The appeal filed against `run_id`, if any.
+pub fn get_for_run(conn: &Connection, run_id: &str) -> AppResult<Option<RunAppeal>> {
+    conn.query_row(
+        "SELECT run_id, player_id, statement, status, created_at, resolved_at FROM run_appeals WHERE run_id = ?1",
+        [run_id],
+        |row| {
+            let status: String = row.get(3)?;
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?, status, row.get::<_, String>(4)?, row.get::<_, Option<String>>(5)?))
+        },
+    )
+    .optional()?
+    .map(|(run_id, player_id, statement, status, created_at, resolved_at)| {
+        Ok(RunAppeal { run_id, player_id, statement, status: parse_status(&status)?, created_at, resolved_at })
+    })
+    .transpose()
+}
+
+/// A moderator's decision on an appeal — `Upheld` leaves the run hidden,
+/// `Reinstated` un-hides it. Filing a new appeal via `submit_appeal` resets
+/// an already-resolved one back to `pending`.
+pub fn resolve_appeal(db: &Db, run_id: &str, status: AppealStatus) -> AppResult<()> {
+    if status == AppealStatus::Pending {
+        return Err(AppError::Validation("resolution status must be upheld or reinstated".into()));
+    }
+    let resolved_at = chrono::Utc::now().to_rfc3339();
+    db.with_tx(|conn| {
+        let updated = conn.execute(
+            "UPDATE run_appeals SET status = ?1, resolved_at = ?2 WHERE run_id = ?3",
+            rusqlite::params![status_str(status), resolved_at, run_id],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+        if status == AppealStatus::Reinstated {
+            conn.execute("UPDATE runs SET hidden = 0 WHERE id = ?1", [run_id])?;
+        }
+        Ok(())
+    })
+}
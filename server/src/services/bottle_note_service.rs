@@ -0,0 +1,104 @@
+use booty_hunt_core::{BottleNote, Page};
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::moderation;
+use crate::pagination;
+
+/// Keeps notes short enough to skim on the pre-wave screen, not a chat log.
+const MAX_NOTE_CHARS: usize = 200;
+
+/// Attaches a moderated note to a seed. Callers should rate-limit per
+/// player before reaching this — see `routes::bottle_notes::attach`.
+pub fn attach(db: &Db, config: &Config, seed: &str, player_id: &str, text: &str) -> AppResult<()> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(AppError::Validation("note text must not be empty".into()));
+    }
+    if text.chars().count() > MAX_NOTE_CHARS {
+        return Err(AppError::Validation(format!("note exceeds {MAX_NOTE_CHARS} characters")));
+    }
+    if moderation::contains_blocked_word(text, &config.blocked_words) {
+        return Err(AppError::Validation("note contains a blocked word".into()));
+    }
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO bottle_notes (seed, player_id, text, hidden, report_count, created_at) VALUES (?1, ?2, ?3, 0, 0, ?4)",
+            rusqlite::params![seed, player_id, text, created_at],
+        )?;
+        Ok(())
+    })
+}
+
+/// Non-hidden notes for a seed, newest first, cursor-paginated per
+/// `pagination` so a popular seed's notes never come back as one unbounded
+/// list. `cursor` is a prior page's `next_cursor`; omit it for the first
+/// page.
+pub fn list(db: &Db, seed: &str, limit: i64, cursor: Option<&str>) -> AppResult<Page<BottleNote>> {
+    let before = cursor.map(pagination::decode_cursor).transpose()?;
+    db.with_read_conn(|conn| {
+        let total: i64 =
+            conn.query_row("SELECT COUNT(*) FROM bottle_notes WHERE seed = ?1 AND hidden = 0", [seed], |row| row.get(0))?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, seed, player_id, text, created_at, report_count FROM bottle_notes
+             WHERE seed = ?1 AND hidden = 0
+             AND (?2 IS NULL OR (created_at, id) < (?2, ?3))
+             ORDER BY created_at DESC, id DESC LIMIT ?4",
+        )?;
+        let (before_created_at, before_id) = match &before {
+            Some((created_at, id)) => (Some(created_at.as_str()), Some(id.as_str())),
+            None => (None, None),
+        };
+        let rows = stmt
+            .query_map(rusqlite::params![seed, before_created_at, before_id, limit], |row| {
+                Ok(BottleNote {
+                    id: row.get(0)?,
+                    seed: row.get(1)?,
+                    player_id: row.get(2)?,
+                    text: row.get(3)?,
+                    created_at: row.get(4)?,
+                    report_count: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let next_cursor = match rows.last() {
+            Some(last) if rows.len() as i64 == limit => Some(pagination::encode_cursor(&last.created_at, &last.id.to_string())),
+            _ => None,
+        };
+        Ok(Page { items: rows, next_cursor, total })
+    })
+}
+
+/// Records a report against a note, auto-hiding it once reports reach
+/// `hide_after_reports` so a bad note stops surfacing without waiting on an
+/// admin to act on every single one.
+pub fn report(db: &Db, note_id: i64, hide_after_reports: i64) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let updated = conn.execute(
+            "UPDATE bottle_notes SET report_count = report_count + 1,
+             hidden = CASE WHEN report_count + 1 >= ?2 THEN 1 ELSE hidden END
+             WHERE id = ?1",
+            rusqlite::params![note_id, hide_after_reports],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    })
+}
+
+/// Moderation hook: hides a note directly (e.g. an admin acting on a report)
+/// without deleting it.
+pub fn hide(db: &Db, note_id: i64) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let updated = conn.execute("UPDATE bottle_notes SET hidden = 1 WHERE id = ?1", [note_id])?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,126 @@
+//! Assembles a single reproducibility bundle for a run — its submitted
+//! numbers, seed, and ghost tape — for bug reports and tournament disputes
+//! that need everything a fresh reproduction of the run would need, in one
+//! document instead of several round trips against `runs::detail` and
+//! `ghost::download`.
+
+use base64::Engine;
+use booty_hunt_core::{RunBundle, WaveSplit};
+
+use crate::blob::BlobStore;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+#[allow(clippy::type_complexity)]
+pub async fn build(db: &Db, blob_store: Option<&std::sync::Arc<dyn BlobStore>>, tenant_id: &str, run_id: &str) -> AppResult<RunBundle> {
+    let row: (
+        String,
+        String,
+        i64,
+        String,
+        String,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        String,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        Option<Vec<u8>>,
+        Option<String>,
+        Option<String>,
+    ) = db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT player_id, week_key, seed, ship_class, doctrine_id, score, waves, damage_dealt, max_combo, time_played, max_heat, victory, created_at, ruleset_id, regatta_id, splits, ghost_tape, ghost_tape_ref, ghost_tape_sha256
+             FROM runs WHERE id = ?1 AND tenant_id = ?2",
+            rusqlite::params![run_id, tenant_id],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                    row.get(6)?,
+                    row.get(7)?,
+                    row.get(8)?,
+                    row.get(9)?,
+                    row.get(10)?,
+                    row.get(11)?,
+                    row.get(12)?,
+                    row.get(13)?,
+                    row.get(14)?,
+                    row.get(15)?,
+                    row.get(16)?,
+                    row.get(17)?,
+                    row.get(18)?,
+                ))
+            },
+        )
+        .map_err(|_| AppError::NotFound)
+    })?;
+
+    let (
+        player_id,
+        week_key,
+        seed,
+        ship_class,
+        doctrine_id,
+        score,
+        waves,
+        damage_dealt,
+        max_combo,
+        time_played,
+        max_heat,
+        victory,
+        created_at,
+        ruleset_id,
+        regatta_id,
+        splits_json,
+        inline_tape,
+        tape_ref,
+        ghost_tape_sha256,
+    ) = row;
+
+    let splits: Option<Vec<WaveSplit>> = splits_json
+        .map(|json| serde_json::from_str(&json))
+        .transpose()
+        .map_err(|e| AppError::Internal(format!("stored runs.splits is invalid: {e}")))?;
+
+    let tape_bytes = match tape_ref {
+        Some(key) => {
+            let store = blob_store
+                .ok_or_else(|| AppError::Internal(format!("run {run_id} has a stored tape ref but no blob store is configured")))?;
+            store.get(&key).await.map_err(|e| AppError::Internal(format!("failed to read ghost tape from blob store: {e}")))?
+        }
+        None => inline_tape,
+    };
+    let ghost_tape_base64 = tape_bytes.map(|bytes| base64::engine::general_purpose::STANDARD.encode(bytes));
+
+    Ok(RunBundle {
+        run_id: run_id.to_string(),
+        player_id,
+        week_key,
+        seed,
+        ship_class,
+        doctrine_id,
+        score,
+        waves,
+        damage_dealt,
+        max_combo,
+        time_played,
+        max_heat,
+        victory: victory != 0,
+        created_at,
+        ruleset_id,
+        regatta_id,
+        splits,
+        ghost_tape_base64,
+        ghost_tape_sha256,
+    })
+}
@@ -0,0 +1,85 @@
+use booty_hunt_core::{ClientErrorReport, ReportClientErrorRequest};
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Keeps a report's free-text message short enough to be a crash summary,
+/// not an attached log file.
+const MAX_MESSAGE_CHARS: usize = 500;
+
+/// Records a crash/desync report, folding it into any existing report with
+/// the same `stack_hash` for this tenant rather than storing a row per
+/// occurrence — `occurrence_count`/`last_seen_at` track the repeats. Callers
+/// should rate-limit per client before reaching this — see
+/// `routes::client_errors::report`.
+pub fn report(db: &Db, tenant_id: &str, req: ReportClientErrorRequest) -> AppResult<ClientErrorReport> {
+    if req.client_version.trim().is_empty() {
+        return Err(AppError::Validation("client_version must not be empty".into()));
+    }
+    if req.stack_hash.trim().is_empty() {
+        return Err(AppError::Validation("stack_hash must not be empty".into()));
+    }
+    let message = req.message.trim();
+    if message.is_empty() {
+        return Err(AppError::Validation("message must not be empty".into()));
+    }
+    if message.chars().count() > MAX_MESSAGE_CHARS {
+        return Err(AppError::Validation(format!("message exceeds {MAX_MESSAGE_CHARS} characters")));
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let id = Uuid::new_v4().to_string();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO client_error_reports
+                 (id, tenant_id, client_version, seed, wave, stack_hash, message, occurrence_count, first_seen_at, last_seen_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 1, ?8, ?8)
+             ON CONFLICT (tenant_id, stack_hash) DO UPDATE SET
+                 occurrence_count = occurrence_count + 1,
+                 last_seen_at = excluded.last_seen_at,
+                 client_version = excluded.client_version,
+                 seed = excluded.seed,
+                 wave = excluded.wave,
+                 message = excluded.message",
+            rusqlite::params![id, tenant_id, req.client_version, req.seed, req.wave, req.stack_hash, message, now],
+        )?;
+        conn.query_row(
+            "SELECT id, client_version, seed, wave, stack_hash, message, occurrence_count, first_seen_at, last_seen_at
+             FROM client_error_reports WHERE tenant_id = ?1 AND stack_hash = ?2",
+            rusqlite::params![tenant_id, req.stack_hash],
+            row_to_report,
+        )
+        .map_err(Into::into)
+    })
+}
+
+/// Every distinct `stack_hash` reported for `tenant_id`, most frequent
+/// first, for the admin dashboard to spot which client failures correlate
+/// with server-side data (a bad omen roll, a specific ship class, a wave
+/// config) rather than being noise.
+pub fn aggregate(db: &Db, tenant_id: &str, limit: i64) -> AppResult<Vec<ClientErrorReport>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, client_version, seed, wave, stack_hash, message, occurrence_count, first_seen_at, last_seen_at
+             FROM client_error_reports WHERE tenant_id = ?1
+             ORDER BY occurrence_count DESC, last_seen_at DESC LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![tenant_id, limit], row_to_report)?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    })
+}
+
+fn row_to_report(row: &rusqlite::Row) -> rusqlite::Result<ClientErrorReport> {
+    Ok(ClientErrorReport {
+        id: row.get(0)?,
+        client_version: row.get(1)?,
+        seed: row.get(2)?,
+        wave: row.get(3)?,
+        stack_hash: row.get(4)?,
+        message: row.get(5)?,
+        occurrence_count: row.get(6)?,
+        first_seen_at: row.get(7)?,
+        last_seen_at: row.get(8)?,
+    })
+}
@@ -0,0 +1,168 @@
+use booty_hunt_core::{CoachingFeedbackNote, CoachingQueueEntry};
+use rusqlite::OptionalExtension;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::moderation;
+
+/// Keeps a coaching note short enough to skim, same reasoning as
+/// `bottle_note_service::MAX_NOTE_CHARS`.
+const MAX_FEEDBACK_CHARS: usize = 500;
+
+/// Flags `run_id` as seeking feedback, opting it into the coaching queue.
+/// `player_id` is looked up from the run itself rather than trusted from the
+/// caller, so only the run's own submitter can flag it (or unflag it via
+/// `withdraw`) — there's no session auth to check against otherwise.
+pub fn request_coaching(db: &Db, run_id: &str, note: Option<&str>) -> AppResult<()> {
+    let note = note.map(str::trim).filter(|n| !n.is_empty());
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        let player_id: String =
+            conn.query_row("SELECT player_id FROM runs WHERE id = ?1", [run_id], |row| row.get(0)).map_err(|_| AppError::NotFound)?;
+        conn.execute(
+            "INSERT INTO coaching_requests (run_id, player_id, note, hidden, created_at) VALUES (?1, ?2, ?3, 0, ?4)
+             ON CONFLICT (run_id) DO UPDATE SET note = excluded.note, hidden = 0",
+            rusqlite::params![run_id, player_id, note, created_at],
+        )?;
+        Ok(())
+    })
+}
+
+/// Removes `run_id` from the coaching queue — opting back out.
+pub fn withdraw(db: &Db, run_id: &str) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let deleted = conn.execute("DELETE FROM coaching_requests WHERE run_id = ?1", [run_id])?;
+        if deleted == 0 {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    })
+}
+
+/// Non-hidden replays currently seeking feedback, oldest first — a
+/// volunteer reviewer works the queue roughly in the order requests came in.
+pub fn queue(db: &Db, limit: i64) -> AppResult<Vec<CoachingQueueEntry>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT r.id, p.display_name, r.ship_class, r.score, cr.note, cr.created_at
+             FROM coaching_requests cr
+             JOIN runs r ON r.id = cr.run_id
+             JOIN players p ON p.id = cr.player_id
+             WHERE cr.hidden = 0
+             ORDER BY cr.created_at ASC
+             LIMIT ?1",
+        )?;
+        let rows = stmt
+            .query_map([limit], |row| {
+                Ok(CoachingQueueEntry {
+                    run_id: row.get(0)?,
+                    player_name: row.get(1)?,
+                    ship_class: row.get(2)?,
+                    score: row.get(3)?,
+                    note: row.get(4)?,
+                    created_at: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })
+}
+
+/// Attaches one reviewer's structured feedback note to `run_id`. A reviewer
+/// may leave at most one note per run — the `UNIQUE (run_id,
+/// reviewer_player_id)` constraint on `coaching_feedback` turns a repeat
+/// attempt into a validation error instead of a pile of duplicate notes.
+pub fn attach_feedback(
+    db: &Db,
+    config: &Config,
+    run_id: &str,
+    reviewer_player_id: &str,
+    text: &str,
+    focus_area: Option<&str>,
+) -> AppResult<()> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(AppError::Validation("feedback text must not be empty".into()));
+    }
+    if text.chars().count() > MAX_FEEDBACK_CHARS {
+        return Err(AppError::Validation(format!("feedback exceeds {MAX_FEEDBACK_CHARS} characters")));
+    }
+    if moderation::contains_blocked_word(text, &config.blocked_words) {
+        return Err(AppError::Validation("feedback contains a blocked word".into()));
+    }
+
+    let id = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.query_row("SELECT 1 FROM runs WHERE id = ?1", [run_id], |row| row.get::<_, i64>(0))
+            .optional()?
+            .ok_or(AppError::NotFound)?;
+        conn.execute(
+            "INSERT INTO coaching_feedback (id, run_id, reviewer_player_id, text, focus_area, hidden, report_count, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, 0, 0, ?6)",
+            rusqlite::params![id, run_id, reviewer_player_id, text, focus_area, created_at],
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::SqliteFailure(err, _) if err.code == rusqlite::ErrorCode::ConstraintViolation => {
+                AppError::Validation("you've already left feedback on this run".into())
+            }
+            other => other.into(),
+        })?;
+        Ok(())
+    })
+}
+
+/// Non-hidden feedback notes left on a run, oldest first — the order the
+/// player would naturally read coaching in.
+pub fn feedback_for_run(db: &Db, run_id: &str) -> AppResult<Vec<CoachingFeedbackNote>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, run_id, reviewer_player_id, text, focus_area, created_at, report_count
+             FROM coaching_feedback WHERE run_id = ?1 AND hidden = 0 ORDER BY created_at ASC",
+        )?;
+        let rows = stmt
+            .query_map([run_id], |row| {
+                Ok(CoachingFeedbackNote {
+                    id: row.get(0)?,
+                    run_id: row.get(1)?,
+                    reviewer_player_id: row.get(2)?,
+                    text: row.get(3)?,
+                    focus_area: row.get(4)?,
+                    created_at: row.get(5)?,
+                    report_count: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })
+}
+
+/// Records a report against a feedback note, auto-hiding it once reports
+/// reach `hide_after_reports` — same pattern as `bottle_note_service::report`.
+pub fn report_feedback(db: &Db, feedback_id: &str, hide_after_reports: i64) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let updated = conn.execute(
+            "UPDATE coaching_feedback SET report_count = report_count + 1,
+             hidden = CASE WHEN report_count + 1 >= ?2 THEN 1 ELSE hidden END
+             WHERE id = ?1",
+            rusqlite::params![feedback_id, hide_after_reports],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    })
+}
+
+/// Direct admin hide, bypassing the report threshold.
+pub fn hide_feedback(db: &Db, feedback_id: &str) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let updated = conn.execute("UPDATE coaching_feedback SET hidden = 1 WHERE id = ?1", [feedback_id])?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,175 @@
+//! Admin-scheduled limited-time events — a banner and a bag of opaque
+//! modifier JSON active for a fixed window, layered on top of the weekly
+//! omens `tide_service` already exposes. The server never interprets
+//! `modifiers` itself; it's merged into the effective modifier set
+//! entirely client-side, same as how `Ruleset::omen_override` is advisory
+//! rather than enforced.
+
+use booty_hunt_core::{CommunityEvent, CreateCommunityEventRequest, EventParticipation};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::services::cosmetics_service;
+
+fn row_to_event(
+    id: String,
+    name: String,
+    banner_text: String,
+    modifiers_json: String,
+    starts_at: String,
+    ends_at: String,
+    reward_item_id: Option<String>,
+) -> AppResult<CommunityEvent> {
+    let modifiers = serde_json::from_str(&modifiers_json)
+        .map_err(|e| AppError::Internal(format!("stored community_events.modifiers_json is invalid: {e}")))?;
+    Ok(CommunityEvent { id, name, banner_text, modifiers, starts_at, ends_at, reward_item_id })
+}
+
+/// Schedules a new event. `starts_at`/`ends_at` are stored exactly as
+/// given — RFC3339 strings compare correctly as text as long as both ends
+/// use the same offset, same convention as every other timestamp column in
+/// this schema.
+pub fn create(db: &Db, tenant_id: &str, req: CreateCommunityEventRequest) -> AppResult<CommunityEvent> {
+    if req.starts_at >= req.ends_at {
+        return Err(AppError::Validation("starts_at must be before ends_at".into()));
+    }
+    let modifiers_json = serde_json::to_string(&req.modifiers)
+        .map_err(|e| AppError::Internal(format!("failed to serialize modifiers: {e}")))?;
+
+    let id = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO community_events (id, tenant_id, name, banner_text, modifiers_json, starts_at, ends_at, reward_item_id, rewards_granted, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 0, ?9)",
+            rusqlite::params![id, tenant_id, req.name, req.banner_text, modifiers_json, req.starts_at, req.ends_at, req.reward_item_id, created_at],
+        )?;
+        Ok(())
+    })?;
+
+    Ok(CommunityEvent {
+        id,
+        name: req.name,
+        banner_text: req.banner_text,
+        modifiers: req.modifiers,
+        starts_at: req.starts_at,
+        ends_at: req.ends_at,
+        reward_item_id: req.reward_item_id,
+    })
+}
+
+/// Events whose window contains right now, for `GET /api/events/active` —
+/// a client merges every returned event's `modifiers` into its effective
+/// set, so more than one can be live at once.
+pub fn active(db: &Db, tenant_id: &str) -> AppResult<Vec<CommunityEvent>> {
+    let now = chrono::Utc::now().to_rfc3339();
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, name, banner_text, modifiers_json, starts_at, ends_at, reward_item_id
+             FROM community_events WHERE tenant_id = ?1 AND starts_at <= ?2 AND ends_at > ?2
+             ORDER BY starts_at ASC",
+        )?;
+        let rows = stmt
+            .query_map(rusqlite::params![tenant_id, now], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, Option<String>>(6)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter()
+            .map(|(id, name, banner_text, modifiers_json, starts_at, ends_at, reward_item_id)| {
+                row_to_event(id, name, banner_text, modifiers_json, starts_at, ends_at, reward_item_id)
+            })
+            .collect()
+    })
+}
+
+/// Records `player_id` as having submitted a qualifying run (`run_id`,
+/// timestamped `created_at`) during any event whose window was open at
+/// that instant. Called from inside `run_service::submit_run`'s
+/// transaction, so it takes `&Connection` rather than `&Db`. One
+/// participation record per event per player — resubmitting during the
+/// same event doesn't grant a second reward later.
+pub fn record_participation(conn: &Connection, tenant_id: &str, player_id: &str, run_id: &str, created_at: &str) -> AppResult<()> {
+    let mut stmt = conn.prepare(
+        "SELECT id FROM community_events WHERE tenant_id = ?1 AND starts_at <= ?2 AND ends_at > ?2",
+    )?;
+    let event_ids: Vec<String> = stmt.query_map(rusqlite::params![tenant_id, created_at], |row| row.get(0))?.collect::<Result<_, _>>()?;
+    for event_id in event_ids {
+        conn.execute(
+            "INSERT OR IGNORE INTO event_participation (tenant_id, event_id, player_id, run_id, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![tenant_id, event_id, player_id, run_id, created_at],
+        )?;
+    }
+    Ok(())
+}
+
+/// Ids of every event whose window was open at `at`, for snapshotting the
+/// effective modifier set onto a run at submission time — see
+/// `run_service::submit_run`. Takes `&Connection` for the same reason
+/// `record_participation` does.
+pub fn active_ids_conn(conn: &Connection, tenant_id: &str, at: &str) -> AppResult<Vec<String>> {
+    let mut stmt =
+        conn.prepare("SELECT id FROM community_events WHERE tenant_id = ?1 AND starts_at <= ?2 AND ends_at > ?2 ORDER BY id ASC")?;
+    let ids = stmt.query_map(rusqlite::params![tenant_id, at], |row| row.get(0))?.collect::<Result<Vec<_>, _>>()?;
+    Ok(ids)
+}
+
+/// A player's event participation history, most recent first.
+pub fn history_for_player(db: &Db, tenant_id: &str, player_id: &str) -> AppResult<Vec<EventParticipation>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT ep.event_id, ce.name, ep.run_id, ep.created_at
+             FROM event_participation ep JOIN community_events ce ON ce.id = ep.event_id
+             WHERE ep.tenant_id = ?1 AND ep.player_id = ?2
+             ORDER BY ep.created_at DESC",
+        )?;
+        let rows = stmt
+            .query_map(rusqlite::params![tenant_id, player_id], |row| {
+                Ok(EventParticipation { event_id: row.get(0)?, event_name: row.get(1)?, run_id: row.get(2)?, created_at: row.get(3)? })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })
+}
+
+/// Grants `reward_item_id` to every participant of any event that has
+/// ended (`ends_at` in the past) and hasn't had its rewards granted yet.
+/// Idempotent — `rewards_granted` gates the grant loop, same pattern as
+/// `raid_service::finalize_if_felled`. Safe to call on every scheduler
+/// tick.
+pub fn grant_ended_event_rewards(db: &Db, tenant_id: &str) -> AppResult<usize> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let newly_finalized = db.with_write_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, reward_item_id FROM community_events
+             WHERE tenant_id = ?1 AND ends_at <= ?2 AND rewards_granted = 0",
+        )?;
+        let ended: Vec<(String, Option<String>)> =
+            stmt.query_map(rusqlite::params![tenant_id, now], |row| Ok((row.get(0)?, row.get(1)?)))?.collect::<Result<_, _>>()?;
+
+        let mut finalized = Vec::new();
+        for (event_id, reward_item_id) in ended {
+            conn.execute("UPDATE community_events SET rewards_granted = 1 WHERE id = ?1", [&event_id])?;
+            let Some(reward_item_id) = reward_item_id else { continue };
+            let mut participant_stmt =
+                conn.prepare("SELECT player_id FROM event_participation WHERE tenant_id = ?1 AND event_id = ?2")?;
+            let participants: Vec<String> =
+                participant_stmt.query_map(rusqlite::params![tenant_id, event_id], |row| row.get(0))?.collect::<Result<_, _>>()?;
+            for player_id in &participants {
+                cosmetics_service::grant_item_conn(conn, player_id, &reward_item_id, "event_reward")?;
+            }
+            finalized.push(event_id);
+        }
+        Ok(finalized)
+    })?;
+    Ok(newly_finalized.len())
+}
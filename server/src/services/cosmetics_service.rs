@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use booty_hunt_core::{CosmeticItem, InventoryEntry};
+use rusqlite::Connection;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Items granted to `player_id`, joined against their current equip state.
+/// Takes a `&Connection` directly (rather than `&Db`) so it can be called
+/// from inside another service's `with_conn` closure, e.g. the leaderboard
+/// query attaching equipped cosmetics per entry.
+pub fn list_inventory(conn: &Connection, player_id: &str) -> AppResult<Vec<InventoryEntry>> {
+    let mut stmt = conn.prepare(
+        "SELECT ci.id, ci.slot, ci.name, pc.granted_at, pc.source,
+                EXISTS(SELECT 1 FROM player_equipped_cosmetics pe WHERE pe.player_id = pc.player_id AND pe.item_id = pc.item_id)
+         FROM player_cosmetics pc JOIN cosmetic_items ci ON ci.id = pc.item_id
+         WHERE pc.player_id = ?1
+         ORDER BY pc.granted_at ASC",
+    )?;
+    let rows = stmt.query_map([player_id], |row| {
+        Ok(InventoryEntry {
+            item: CosmeticItem { id: row.get(0)?, slot: row.get(1)?, name: row.get(2)? },
+            granted_at: row.get(3)?,
+            source: row.get(4)?,
+            equipped: row.get::<_, i64>(5)? != 0,
+        })
+    })?;
+    rows.map(|r| r.map_err(AppError::from)).collect()
+}
+
+pub fn equipped_items(conn: &Connection, player_id: &str) -> AppResult<HashMap<String, String>> {
+    let mut stmt = conn.prepare(
+        "SELECT slot, item_id FROM player_equipped_cosmetics WHERE player_id = ?1",
+    )?;
+    let rows = stmt.query_map([player_id], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+    let mut map = HashMap::new();
+    for row in rows {
+        let (slot, item_id) = row?;
+        map.insert(slot, item_id);
+    }
+    Ok(map)
+}
+
+pub fn grant_item(db: &Db, player_id: &str, item_id: &str, source: &str) -> AppResult<()> {
+    db.with_write_conn(|conn| grant_item_conn(conn, player_id, item_id, source))
+}
+
+/// Same as `grant_item`, but takes `&Connection` directly so it can be
+/// called from inside another service's own `with_write_conn`/`with_tx`
+/// closure, e.g. `progression_service::claim_tier` granting the tier's
+/// reward item as part of the same transaction as the claim record.
+pub fn grant_item_conn(conn: &Connection, player_id: &str, item_id: &str, source: &str) -> AppResult<()> {
+    let granted_at = chrono::Utc::now().to_rfc3339();
+    conn.execute(
+        "INSERT OR IGNORE INTO player_cosmetics (player_id, item_id, source, granted_at) VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![player_id, item_id, source, granted_at],
+    )?;
+    Ok(())
+}
+
+/// Equips `item_id` in its slot, unequipping whatever previously held that
+/// slot. Fails validation if the player hasn't been granted the item.
+pub fn equip_item(db: &Db, player_id: &str, item_id: &str) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let owned: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM player_cosmetics WHERE player_id = ?1 AND item_id = ?2)",
+            rusqlite::params![player_id, item_id],
+            |row| row.get(0),
+        )?;
+        if !owned {
+            return Err(AppError::Validation("item not owned".into()));
+        }
+        let slot: String = conn.query_row(
+            "SELECT slot FROM cosmetic_items WHERE id = ?1",
+            [item_id],
+            |row| row.get(0),
+        )?;
+        conn.execute(
+            "INSERT INTO player_equipped_cosmetics (player_id, slot, item_id) VALUES (?1, ?2, ?3)
+             ON CONFLICT(player_id, slot) DO UPDATE SET item_id = excluded.item_id",
+            rusqlite::params![player_id, slot, item_id],
+        )?;
+        Ok(())
+    })
+}
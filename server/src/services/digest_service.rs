@@ -0,0 +1,104 @@
+use booty_hunt_core::WeeklyDigest;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::services::leaderboard_service::{self, LeaderboardFilters, LeaderboardSort};
+use crate::tenant::DEFAULT_TENANT;
+
+const PODIUM_SIZE: i64 = 3;
+
+/// `(podium_json, stealth_podium_json, biggest_upset_json, ships_destroyed, generated_at)`
+type DigestRow = (String, Option<String>, Option<String>, i64, String);
+
+/// Returns the stored digest for `week_key`, generating and persisting it
+/// first if this is the first time it's been requested. Once generated a
+/// digest never changes, even if new runs are submitted for that week
+/// afterward — it's a snapshot of "how the week ended", not a live view.
+pub fn get_or_generate(db: &Db, week_key: &str) -> AppResult<WeeklyDigest> {
+    if let Some(existing) = load(db, week_key)? {
+        return Ok(existing);
+    }
+    generate(db, week_key)
+}
+
+fn load(db: &Db, week_key: &str) -> AppResult<Option<WeeklyDigest>> {
+    db.with_read_conn(|conn| {
+        let row: Option<DigestRow> = conn
+            .query_row(
+                "SELECT podium_json, stealth_podium_json, biggest_upset_json, ships_destroyed, generated_at FROM weekly_digests WHERE week_key = ?1",
+                [week_key],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
+            )
+            .ok();
+
+        let Some((podium_json, stealth_podium_json, biggest_upset_json, ships_destroyed, generated_at)) = row else {
+            return Ok(None);
+        };
+        let podium = serde_json::from_str(&podium_json).map_err(|e| AppError::Internal(e.to_string()))?;
+        // Digests generated before the stealth podium existed have no
+        // column value yet; treat that as an empty podium rather than
+        // failing to load the whole digest.
+        let stealth_podium = stealth_podium_json
+            .map(|s| serde_json::from_str(&s))
+            .transpose()
+            .map_err(|e| AppError::Internal(e.to_string()))?
+            .unwrap_or_default();
+        let biggest_upset = biggest_upset_json
+            .map(|s| serde_json::from_str(&s))
+            .transpose()
+            .map_err(|e| AppError::Internal(e.to_string()))?;
+        Ok(Some(WeeklyDigest {
+            week_key: week_key.to_string(),
+            podium,
+            stealth_podium,
+            ships_destroyed,
+            biggest_upset,
+            generated_at,
+        }))
+    })
+}
+
+pub fn generate(db: &Db, week_key: &str) -> AppResult<WeeklyDigest> {
+    // Digests aren't tenant-scoped yet — they cover the default tenant only,
+    // matching how single-community deployments run today.
+    // Digests summarize the tenant's default board — rulesets get their own
+    // leaderboard reads, not a digest of their own, until one is requested.
+    let podium = leaderboard_service::fetch_leaderboard(
+        db,
+        DEFAULT_TENANT,
+        week_key,
+        PODIUM_SIZE,
+        LeaderboardFilters::default(),
+        LeaderboardSort::Score,
+    )?;
+    let stealth_podium = leaderboard_service::fetch_leaderboard(
+        db,
+        DEFAULT_TENANT,
+        week_key,
+        PODIUM_SIZE,
+        LeaderboardFilters::default(),
+        LeaderboardSort::Stealth,
+    )?;
+    let ships_destroyed: i64 = db.with_read_conn(|conn| {
+        Ok(conn.query_row(
+            "SELECT COUNT(*) FROM runs WHERE week_key = ?1 AND victory = 1",
+            [week_key],
+            |row| row.get(0),
+        )?)
+    })?;
+    let generated_at = chrono::Utc::now().to_rfc3339();
+    let podium_json = serde_json::to_string(&podium).map_err(|e| AppError::Internal(e.to_string()))?;
+    let stealth_podium_json = serde_json::to_string(&stealth_podium).map_err(|e| AppError::Internal(e.to_string()))?;
+
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO weekly_digests (week_key, podium_json, stealth_podium_json, biggest_upset_json, ships_destroyed, generated_at)
+             VALUES (?1, ?2, ?3, NULL, ?4, ?5)
+             ON CONFLICT(week_key) DO NOTHING",
+            rusqlite::params![week_key, podium_json, stealth_podium_json, ships_destroyed, generated_at],
+        )?;
+        Ok(())
+    })?;
+
+    Ok(WeeklyDigest { week_key: week_key.to_string(), podium, stealth_podium, ships_destroyed, biggest_upset: None, generated_at })
+}
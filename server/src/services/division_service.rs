@@ -0,0 +1,155 @@
+use rusqlite::OptionalExtension;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::services::run_service;
+
+/// Top/bottom slice size of a division that promotes/relegates at rollover,
+/// e.g. a division of 20 moves its top 4 up and bottom 4 down.
+fn promotion_relegation_zone_size(division_size: i64) -> i64 {
+    (division_size / 5).max(1)
+}
+
+/// Assigns `week_key`'s divisions if they don't already exist, deriving them
+/// from how each player's division fared the previous week. No-ops (returns
+/// `false`) once `week_key` has any `player_divisions` rows for `tenant_id`,
+/// so this is safe to call on every scheduler tick — see
+/// `scheduler::spawn_division_assignment`.
+///
+/// Players who held a division the previous week move within one step of it:
+/// the top `promotion_relegation_zone_size` performers (by score achieved
+/// during that week) move to `division - 1`, the bottom zone moves to
+/// `division + 1`, everyone else stays put. Division `1` can't promote
+/// further and the bottom division can't relegate further. Players who
+/// submitted a run the previous week but held no division yet are bucketed
+/// into fresh divisions appended after the current maximum, ordered by their
+/// best score that week and chunked into groups of `config.division_size`.
+pub fn ensure_assigned_for_week(db: &Db, config: &Config, tenant_id: &str, week_key: &str) -> AppResult<bool> {
+    db.with_write_conn(|conn| {
+        let already_assigned: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM player_divisions WHERE tenant_id = ?1 AND week_key = ?2)",
+            rusqlite::params![tenant_id, week_key],
+            |row| row.get(0),
+        )?;
+        if already_assigned {
+            return Ok(false);
+        }
+
+        let previous_week_key: Option<String> = conn
+            .query_row(
+                "SELECT week_key FROM player_divisions WHERE tenant_id = ?1 AND week_key < ?2 ORDER BY week_key DESC LIMIT 1",
+                rusqlite::params![tenant_id, week_key],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        let scored_week_key = run_service::previous_week_key();
+
+        let Some(previous_week_key) = previous_week_key else {
+            // Divisions have never run for this tenant: bucket everyone who
+            // played the previous week into fresh divisions starting at 1.
+            assign_newcomers(conn, tenant_id, week_key, &scored_week_key, 1, config.division_size)?;
+            return Ok(true);
+        };
+
+        let max_division: i64 = conn
+            .query_row(
+                "SELECT MAX(division) FROM player_divisions WHERE tenant_id = ?1 AND week_key = ?2",
+                rusqlite::params![tenant_id, previous_week_key],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten()
+            .unwrap_or(0);
+
+        let zone_size = promotion_relegation_zone_size(config.division_size);
+        let mut stmt = conn.prepare(
+            "SELECT pd.player_id, pd.division, COALESCE(MAX(r.score), 0) AS best_score
+             FROM player_divisions pd
+             LEFT JOIN runs r ON r.player_id = pd.player_id AND r.tenant_id = pd.tenant_id AND r.week_key = pd.week_key
+             WHERE pd.tenant_id = ?1 AND pd.week_key = ?2
+             GROUP BY pd.player_id, pd.division
+             ORDER BY pd.division ASC, best_score DESC",
+        )?;
+        let rows: Vec<(String, i64, i64)> = stmt
+            .query_map(rusqlite::params![tenant_id, previous_week_key], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<Result<_, _>>()?;
+        drop(stmt);
+
+        let mut members_by_division: std::collections::BTreeMap<i64, Vec<String>> = std::collections::BTreeMap::new();
+        for (player_id, division, _) in rows {
+            members_by_division.entry(division).or_default().push(player_id);
+        }
+
+        let mut insert =
+            conn.prepare("INSERT INTO player_divisions (tenant_id, week_key, player_id, division) VALUES (?1, ?2, ?3, ?4)")?;
+        for (division, members) in &members_by_division {
+            let zone = zone_size.min(members.len() as i64) as usize;
+            for (rank, player_id) in members.iter().enumerate() {
+                let new_division = if rank < zone {
+                    (*division - 1).max(1)
+                } else if rank >= members.len() - zone {
+                    (*division + 1).min(max_division)
+                } else {
+                    *division
+                };
+                insert.execute(rusqlite::params![tenant_id, week_key, player_id, new_division])?;
+            }
+        }
+        drop(insert);
+
+        assign_newcomers(conn, tenant_id, week_key, &scored_week_key, max_division + 1, config.division_size)?;
+        Ok(true)
+    })
+}
+
+/// Buckets players who submitted a run in `scored_week_key` but hold no
+/// division assignment for `week_key` yet into fresh divisions starting at
+/// `starting_division`, ordered by their best score that week and chunked
+/// into groups of `division_size` — new players always land at the bottom of
+/// the ladder rather than displacing an existing division.
+fn assign_newcomers(
+    conn: &rusqlite::Connection,
+    tenant_id: &str,
+    week_key: &str,
+    scored_week_key: &str,
+    starting_division: i64,
+    division_size: i64,
+) -> AppResult<()> {
+    let mut stmt = conn.prepare(
+        "SELECT r.player_id, MAX(r.score) AS best_score
+         FROM runs r
+         WHERE r.tenant_id = ?1 AND r.week_key = ?2
+         AND r.player_id NOT IN (SELECT player_id FROM player_divisions WHERE tenant_id = ?1 AND week_key = ?3)
+         GROUP BY r.player_id
+         ORDER BY best_score DESC",
+    )?;
+    let newcomers: Vec<String> =
+        stmt.query_map(rusqlite::params![tenant_id, scored_week_key, week_key], |row| row.get(0))?.collect::<Result<_, _>>()?;
+    drop(stmt);
+
+    let mut insert =
+        conn.prepare("INSERT INTO player_divisions (tenant_id, week_key, player_id, division) VALUES (?1, ?2, ?3, ?4)")?;
+    for (index, player_id) in newcomers.iter().enumerate() {
+        let division = starting_division + (index as i64 / division_size.max(1));
+        insert.execute(rusqlite::params![tenant_id, week_key, player_id, division])?;
+    }
+    Ok(())
+}
+
+/// A player's division across every week they've been assigned one, most
+/// recent first.
+pub fn history(db: &Db, tenant_id: &str, player_id: &str) -> AppResult<Vec<booty_hunt_core::PlayerDivisionRecord>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT week_key, division FROM player_divisions WHERE tenant_id = ?1 AND player_id = ?2 ORDER BY week_key DESC",
+        )?;
+        let records = stmt
+            .query_map(rusqlite::params![tenant_id, player_id], |row| {
+                Ok(booty_hunt_core::PlayerDivisionRecord { week_key: row.get(0)?, division: row.get(1)? })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(records)
+    })
+}
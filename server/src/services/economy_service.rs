@@ -0,0 +1,21 @@
+use booty_hunt_core::EconomyAudit;
+
+use crate::db::Db;
+use crate::error::AppResult;
+
+/// Builds the week's economy audit. Only `heat_spent_total` reflects real
+/// data today — `aid_flows` and `aid_expiry_waste` stay empty/zero because
+/// signal fire generation and redemption aren't implemented anywhere in this
+/// server yet (see `EconomyAudit`'s doc comment). Wire those in here once
+/// that lands instead of adding a second audit endpoint.
+pub fn audit(db: &Db, week_key: &str) -> AppResult<EconomyAudit> {
+    let heat_spent_total: i64 = db.with_read_conn(|conn| {
+        Ok(conn.query_row(
+            "SELECT COALESCE(SUM(max_heat), 0) FROM runs WHERE week_key = ?1",
+            [week_key],
+            |row| row.get(0),
+        )?)
+    })?;
+
+    Ok(EconomyAudit { week_key: week_key.to_string(), aid_flows: Vec::new(), heat_spent_total, aid_expiry_waste: 0 })
+}
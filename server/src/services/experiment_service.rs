@@ -0,0 +1,97 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use booty_hunt_core::{ExperimentAssignment, ExperimentVariantReport};
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+fn find_experiment<'a>(config: &'a Config, experiment_key: &str) -> AppResult<&'a booty_hunt_core::ExperimentDefinition> {
+    config
+        .experiments
+        .iter()
+        .find(|e| e.key == experiment_key)
+        .ok_or_else(|| AppError::Validation(format!("unknown experiment: {experiment_key}")))
+}
+
+/// Deterministically assigns `player_id` to one of `experiment_key`'s
+/// variants for `week_key`, by the same "hash the tuple, no coordination
+/// needed" approach `regatta_service::derive_seed` uses for weekly seeds.
+/// Every server in a deployment (and every repeat call) agrees on the same
+/// variant for that player/experiment/week without persisting the
+/// assignment anywhere.
+pub fn assign(config: &Config, experiment_key: &str, week_key: &str, player_id: &str) -> AppResult<ExperimentAssignment> {
+    let experiment = find_experiment(config, experiment_key)?;
+    if experiment.variants.is_empty() {
+        return Err(AppError::Validation(format!("experiment {experiment_key} has no variants configured")));
+    }
+
+    let mut hasher = DefaultHasher::new();
+    (experiment_key, week_key, player_id).hash(&mut hasher);
+    let index = (hasher.finish() as usize) % experiment.variants.len();
+
+    Ok(ExperimentAssignment {
+        experiment_key: experiment_key.to_string(),
+        week_key: week_key.to_string(),
+        variant: experiment.variants[index].clone(),
+    })
+}
+
+/// One outcome sample as reported by a client, bundled together since it's
+/// always sourced straight off the request body's fields — mirrors
+/// `run_service::RunPipelineExtensions`'s reasoning.
+pub struct OutcomeSample<'a> {
+    pub player_id: &'a str,
+    pub metric: &'a str,
+    pub value: f64,
+}
+
+/// Records one outcome sample for the variant `sample.player_id` is assigned
+/// to this week, re-deriving the variant from `assign` rather than trusting a
+/// caller-supplied one, so a client can't skew the report by claiming a
+/// variant it wasn't actually assigned.
+pub fn record_outcome(
+    db: &Db,
+    config: &Config,
+    tenant_id: &str,
+    experiment_key: &str,
+    week_key: &str,
+    sample: OutcomeSample<'_>,
+) -> AppResult<()> {
+    let OutcomeSample { player_id, metric, value } = sample;
+    let assignment = assign(config, experiment_key, week_key, player_id)?;
+    let id = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now().to_rfc3339();
+
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO experiment_outcomes (id, tenant_id, experiment_key, week_key, variant, player_id, metric, value, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            rusqlite::params![id, tenant_id, experiment_key, week_key, assignment.variant, player_id, metric, value, created_at],
+        )?;
+        Ok(())
+    })
+}
+
+/// Per-variant sample count, sum, and average for one experiment/week/metric
+/// — the admin report designers use to see which variant is winning.
+pub fn report(db: &Db, tenant_id: &str, experiment_key: &str, week_key: &str, metric: &str) -> AppResult<Vec<ExperimentVariantReport>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT variant, COUNT(*), COALESCE(SUM(value), 0.0)
+             FROM experiment_outcomes
+             WHERE tenant_id = ?1 AND experiment_key = ?2 AND week_key = ?3 AND metric = ?4
+             GROUP BY variant
+             ORDER BY variant ASC",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![tenant_id, experiment_key, week_key, metric], |row| {
+            let sample_count: i64 = row.get(1)?;
+            let metric_sum: f64 = row.get(2)?;
+            let metric_avg = if sample_count > 0 { metric_sum / sample_count as f64 } else { 0.0 };
+            Ok(ExperimentVariantReport { variant: row.get(0)?, sample_count, metric_sum, metric_avg })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    })
+}
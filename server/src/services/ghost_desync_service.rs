@@ -0,0 +1,42 @@
+use rusqlite::OptionalExtension;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::AppResult;
+
+/// Records a client-reported desync between a downloaded ghost and its
+/// recorded outcome, then auto-flags the run's tape as corrupt once its
+/// desync rate against `replay_downloads` clears both
+/// `ghost_desync_min_downloads` (so one bad frame on a rarely-downloaded
+/// ghost doesn't flag it) and `ghost_desync_flag_ratio`. Idempotent past
+/// that point — a run already flagged just accumulates more reports without
+/// re-running the threshold check.
+pub fn report(db: &Db, config: &Config, tenant_id: &str, run_id: &str, frame: i64, divergence: &serde_json::Value) -> AppResult<()> {
+    let divergence_json = serde_json::to_string(divergence)
+        .map_err(|e| crate::error::AppError::Internal(format!("failed to serialize divergence detail: {e}")))?;
+    let reported_at = chrono::Utc::now().to_rfc3339();
+    let id = Uuid::new_v4().to_string();
+
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO ghost_desync_reports (id, tenant_id, run_id, frame, divergence, reported_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![id, tenant_id, run_id, frame, divergence_json, reported_at],
+        )?;
+
+        let report_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM ghost_desync_reports WHERE run_id = ?1", [run_id], |row| row.get(0))?;
+        let download_count: i64 = conn
+            .query_row("SELECT download_count FROM replay_downloads WHERE run_id = ?1", [run_id], |row| row.get(0))
+            .optional()?
+            .unwrap_or(0);
+
+        if download_count >= config.ghost_desync_min_downloads
+            && (report_count as f64 / download_count as f64) >= config.ghost_desync_flag_ratio
+        {
+            conn.execute("UPDATE runs SET ghost_corrupt = 1 WHERE id = ?1", [run_id])?;
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,81 @@
+use booty_hunt_core::{GhostHighlight, GhostHighlights, WaveSplit};
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Builds a "best moments" summary for a run from metadata already recorded
+/// at submission time (`max_combo`, `waves`, `splits`) rather than parsing
+/// the tape's event stream — there's no server-side schema for that stream,
+/// so a real sub-tape extraction isn't possible here. Malformed `splits`
+/// JSON is treated the same as absent splits, matching
+/// `splits_service::fetch_sum_of_best`'s leniency.
+pub fn highlights(db: &Db, tenant_id: &str, run_id: &str) -> AppResult<GhostHighlights> {
+    let (max_combo, waves, victory, splits_json): (i64, i64, bool, Option<String>) = db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT max_combo, waves, victory, splits FROM runs WHERE id = ?1 AND tenant_id = ?2",
+            rusqlite::params![run_id, tenant_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get::<_, i64>(2)? != 0, row.get(3)?)),
+        )
+        .map_err(|_| AppError::NotFound)
+    })?;
+
+    let mut highlights = Vec::new();
+    highlights.push(GhostHighlight {
+        label: "biggest_combo".to_string(),
+        wave: None,
+        time_ms: None,
+        score: None,
+        description: format!("Peaked at a {max_combo}x combo."),
+    });
+
+    let splits: Vec<WaveSplit> = splits_json.and_then(|json| serde_json::from_str(&json).ok()).unwrap_or_default();
+    match splits.last() {
+        Some(final_split) => highlights.push(GhostHighlight {
+            label: "final_wave".to_string(),
+            wave: Some(final_split.wave),
+            time_ms: Some(final_split.time_ms),
+            score: Some(final_split.score),
+            description: if victory {
+                format!("Cleared wave {} to win the run.", final_split.wave)
+            } else {
+                format!("Fell on wave {}.", final_split.wave)
+            },
+        }),
+        None => highlights.push(GhostHighlight {
+            label: "final_wave".to_string(),
+            wave: Some(waves),
+            time_ms: None,
+            score: None,
+            description: if victory { format!("Cleared all {waves} waves to win the run.") } else { format!("Fell on wave {waves}.") },
+        }),
+    }
+
+    Ok(GhostHighlights { run_id: run_id.to_string(), highlights })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{insert_player, insert_run, test_db};
+
+    #[test]
+    fn highlights_returns_a_summary_for_the_owning_tenant() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-1", "player-1", "2026-w01", 100);
+
+        let result = highlights(&db, "tenant-a", "run-1").unwrap();
+        assert_eq!(result.run_id, "run-1");
+        assert!(!result.highlights.is_empty());
+    }
+
+    #[test]
+    fn highlights_rejects_a_run_belonging_to_another_tenant() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-1", "player-1", "2026-w01", 100);
+
+        let result = highlights(&db, "tenant-b", "run-1");
+        assert!(matches!(result, Err(AppError::NotFound)));
+    }
+}
@@ -0,0 +1,111 @@
+use booty_hunt_core::PopularReplay;
+
+use crate::blob::BlobStore;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Fetches a run's ghost tape bytes for download. Popularity counting
+/// happens in the route handler via `PopularityCounters`, not here, so this
+/// stays a plain read with no side effects.
+///
+/// Reads `ghost_tape_ref` first — if set, the tape lives in `blob_store`
+/// (required in that case) rather than the `ghost_tape` BLOB column. Runs
+/// submitted before external storage was configured, or while it's off,
+/// have no ref and fall back to the inline column unchanged.
+///
+/// Also returns the server-computed checksum stored alongside the tape, if
+/// any — runs submitted before checksums existed have neither a claim to
+/// verify nor a stored digest, so `None` here just means "predates this".
+pub async fn fetch_tape(
+    db: &Db,
+    blob_store: Option<&std::sync::Arc<dyn BlobStore>>,
+    tenant_id: &str,
+    run_id: &str,
+) -> AppResult<(Vec<u8>, Option<String>)> {
+    let (inline_tape, tape_ref, sha256): (Option<Vec<u8>>, Option<String>, Option<String>) = db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT ghost_tape, ghost_tape_ref, ghost_tape_sha256 FROM runs WHERE id = ?1 AND tenant_id = ?2",
+            rusqlite::params![run_id, tenant_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .map_err(|_| AppError::NotFound)
+    })?;
+
+    if let Some(key) = tape_ref {
+        let store = blob_store
+            .ok_or_else(|| AppError::Internal(format!("run {run_id} has a stored tape ref but no blob store is configured")))?;
+        let tape = store
+            .get(&key)
+            .await
+            .map_err(|e| AppError::Internal(format!("failed to read ghost tape from blob store: {e}")))?
+            .ok_or(AppError::NotFound)?;
+        return Ok((tape, sha256));
+    }
+
+    Ok((inline_tape.ok_or(AppError::NotFound)?, sha256))
+}
+
+/// Most-downloaded replays for a week, joined against the batched
+/// `replay_downloads` counters flushed by the scheduler. Excludes tapes
+/// `ghost_desync_service` has auto-flagged as corrupt, so a broken ghost
+/// doesn't keep surfacing as a featured/rival replay just because it was
+/// popular before it was flagged.
+pub fn most_popular(db: &Db, week_key: &str, limit: i64) -> AppResult<Vec<PopularReplay>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT r.id, p.display_name, r.ship_class, r.score, d.download_count
+             FROM replay_downloads d
+             JOIN runs r ON r.id = d.run_id
+             JOIN players p ON p.id = r.player_id
+             WHERE r.week_key = ?1 AND r.ghost_corrupt = 0
+             ORDER BY d.download_count DESC
+             LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![week_key, limit], |row| {
+            Ok(PopularReplay {
+                run_id: row.get(0)?,
+                player_name: row.get(1)?,
+                ship_class: row.get(2)?,
+                score: row.get(3)?,
+                download_count: row.get(4)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{insert_player, insert_run, test_db};
+
+    fn set_inline_tape(db: &Db, run_id: &str, tape: &[u8]) {
+        db.with_write_conn(|conn| {
+            conn.execute("UPDATE runs SET ghost_tape = ?1 WHERE id = ?2", rusqlite::params![tape, run_id])?;
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn fetch_tape_returns_the_tape_for_the_owning_tenant() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-1", "player-1", "2026-w01", 100);
+        set_inline_tape(&db, "run-1", b"tape-bytes");
+
+        let (tape, _sha256) = fetch_tape(&db, None, "tenant-a", "run-1").await.unwrap();
+        assert_eq!(tape, b"tape-bytes");
+    }
+
+    #[tokio::test]
+    async fn fetch_tape_rejects_a_run_belonging_to_another_tenant() {
+        let db = test_db();
+        insert_player(&db, "tenant-a", "player-1");
+        insert_run(&db, "tenant-a", "run-1", "player-1", "2026-w01", 100);
+        set_inline_tape(&db, "run-1", b"tape-bytes");
+
+        let result = fetch_tape(&db, None, "tenant-b", "run-1").await;
+        assert!(matches!(result, Err(AppError::NotFound)));
+    }
+}
@@ -0,0 +1,133 @@
+use booty_hunt_core::{CreateGoalRequest, PersonalGoal, RunSubmission};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Recognized `goal_type` values. `reach_wave` and `score_at_least` track the
+/// best single run seen so far; `victories_count` accumulates across runs.
+pub const GOAL_TYPES: &[&str] = &["reach_wave", "score_at_least", "victories_count"];
+
+fn row_to_goal(row: &rusqlite::Row) -> rusqlite::Result<PersonalGoal> {
+    Ok(PersonalGoal {
+        id: row.get(0)?,
+        player_id: row.get(1)?,
+        goal_type: row.get(2)?,
+        ship_class: row.get(3)?,
+        target: row.get(4)?,
+        progress: row.get(5)?,
+        completed: row.get::<_, i64>(6)? != 0,
+        created_at: row.get(7)?,
+        completed_at: row.get(8)?,
+    })
+}
+
+const GOAL_COLUMNS: &str = "id, player_id, goal_type, ship_class, target, progress, completed, created_at, completed_at";
+
+/// Creates a new personal goal for `player_id`. Rejects an unrecognized
+/// `goal_type` or a non-positive target up front, rather than letting it sit
+/// unevaluated forever.
+pub fn create(db: &Db, tenant_id: &str, player_id: &str, req: CreateGoalRequest) -> AppResult<PersonalGoal> {
+    if !GOAL_TYPES.contains(&req.goal_type.as_str()) {
+        return Err(AppError::Validation(format!("unknown goal_type: {}", req.goal_type)));
+    }
+    if req.target <= 0 {
+        return Err(AppError::Validation("target must be positive".into()));
+    }
+
+    let id = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO player_goals (id, tenant_id, player_id, goal_type, ship_class, target, progress, completed, created_at, completed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, 0, ?7, NULL)",
+            rusqlite::params![id, tenant_id, player_id, req.goal_type, req.ship_class, req.target, created_at],
+        )?;
+        Ok(())
+    })?;
+
+    Ok(PersonalGoal {
+        id,
+        player_id: player_id.to_string(),
+        goal_type: req.goal_type,
+        ship_class: req.ship_class,
+        target: req.target,
+        progress: 0,
+        completed: false,
+        created_at,
+        completed_at: None,
+    })
+}
+
+/// Every goal `player_id` has ever set, most recent first.
+pub fn list(db: &Db, tenant_id: &str, player_id: &str) -> AppResult<Vec<PersonalGoal>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {GOAL_COLUMNS} FROM player_goals WHERE tenant_id = ?1 AND player_id = ?2 ORDER BY created_at DESC"
+        ))?;
+        let goals = stmt.query_map(rusqlite::params![tenant_id, player_id], row_to_goal)?.collect::<Result<Vec<_>, _>>()?;
+        Ok(goals)
+    })
+}
+
+/// The candidate progress value this submission contributes toward
+/// `goal_type`, or `None` if the submission's `ship_class` doesn't match a
+/// goal scoped to one.
+fn candidate_progress(goal_type: &str, ship_class: &Option<String>, submission: &RunSubmission) -> Option<i64> {
+    if let Some(wanted) = ship_class {
+        if wanted != &submission.ship_class {
+            return None;
+        }
+    }
+    match goal_type {
+        "reach_wave" => Some(submission.waves),
+        "score_at_least" => Some(submission.score),
+        "victories_count" => submission.victory.then_some(1),
+        _ => None,
+    }
+}
+
+/// Updates progress on every incomplete goal `player_id` holds against this
+/// submission, returning the goals that just newly completed. Takes
+/// `&Connection` so `run_service::submit_run` can call this from inside its
+/// own write transaction, right alongside the run's own INSERT.
+/// `reach_wave`/`score_at_least` progress is the best single run seen so far
+/// (`MAX`); `victories_count` accumulates by 1 per qualifying victory.
+pub fn evaluate_for_submission(
+    conn: &Connection,
+    tenant_id: &str,
+    player_id: &str,
+    submission: &RunSubmission,
+) -> AppResult<Vec<PersonalGoal>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {GOAL_COLUMNS} FROM player_goals WHERE tenant_id = ?1 AND player_id = ?2 AND completed = 0"
+    ))?;
+    let goals: Vec<PersonalGoal> =
+        stmt.query_map(rusqlite::params![tenant_id, player_id], row_to_goal)?.collect::<Result<_, _>>()?;
+    drop(stmt);
+
+    let mut completed = Vec::new();
+    let completed_at = chrono::Utc::now().to_rfc3339();
+    for mut goal in goals {
+        let Some(candidate) = candidate_progress(&goal.goal_type, &goal.ship_class, submission) else {
+            continue;
+        };
+        let new_progress = if goal.goal_type == "victories_count" { goal.progress + candidate } else { goal.progress.max(candidate) };
+        if new_progress == goal.progress {
+            continue;
+        }
+        let newly_completed = new_progress >= goal.target;
+        conn.execute(
+            "UPDATE player_goals SET progress = ?1, completed = ?2, completed_at = ?3 WHERE id = ?4",
+            rusqlite::params![new_progress, newly_completed as i64, newly_completed.then(|| completed_at.clone()), goal.id],
+        )?;
+        goal.progress = new_progress;
+        if newly_completed {
+            goal.completed = true;
+            goal.completed_at = Some(completed_at.clone());
+            completed.push(goal);
+        }
+    }
+    Ok(completed)
+}
@@ -0,0 +1,30 @@
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::identity::{IdentityError, IdentityProvider};
+
+pub async fn link(
+    db: &Db,
+    providers: &[Box<dyn IdentityProvider>],
+    player_id: &str,
+    provider_name: &str,
+    proof: &str,
+) -> AppResult<()> {
+    let provider = providers
+        .iter()
+        .find(|p| p.name() == provider_name)
+        .ok_or_else(|| crate::error::AppError::Validation(format!("unknown identity provider {provider_name}")))?;
+
+    let identity = provider
+        .verify(proof)
+        .await
+        .map_err(|IdentityError::Rejected(reason)| crate::error::AppError::Validation(reason))?;
+
+    let linked_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO player_identities (player_id, provider, external_id, linked_at) VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![player_id, provider_name, identity.external_id, linked_at],
+        )?;
+        Ok(())
+    })
+}
@@ -0,0 +1,59 @@
+use rusqlite::Connection;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Maximum length of an optional kudos comment, to keep moderation queues
+/// bounded and discourage using this as a chat channel.
+const MAX_COMMENT_CHARS: usize = 240;
+
+/// Records one player's kudos (and optional comment) on a run. The primary
+/// key on `(run_id, player_id)` enforces "one per player per run" — a second
+/// call is a validation error rather than silently overwriting the first.
+pub fn give(db: &Db, run_id: &str, player_id: &str, comment: Option<&str>) -> AppResult<()> {
+    if let Some(comment) = comment {
+        if comment.chars().count() > MAX_COMMENT_CHARS {
+            return Err(AppError::Validation(format!("comment exceeds {MAX_COMMENT_CHARS} characters")));
+        }
+    }
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO run_kudos (run_id, player_id, comment, hidden, created_at) VALUES (?1, ?2, ?3, 0, ?4)",
+            rusqlite::params![run_id, player_id, comment, created_at],
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::SqliteFailure(err, _) if err.code == rusqlite::ErrorCode::ConstraintViolation => {
+                AppError::Validation("kudos already given by this player for this run".into())
+            }
+            other => other.into(),
+        })?;
+        Ok(())
+    })
+}
+
+/// Moderation hook: hides a kudos comment (e.g. flagged as abusive) without
+/// deleting it, so the count still reflects genuine engagement.
+pub fn hide(db: &Db, run_id: &str, player_id: &str) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let updated = conn.execute(
+            "UPDATE run_kudos SET hidden = 1 WHERE run_id = ?1 AND player_id = ?2",
+            rusqlite::params![run_id, player_id],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+        Ok(())
+    })
+}
+
+/// Count of non-hidden kudos for a run. Takes `&Connection` rather than
+/// `&Db` so it can be called from within `leaderboard_service`'s `with_read_conn`
+/// closure without deadlocking the shared connection mutex.
+pub fn count(conn: &Connection, run_id: &str) -> AppResult<i64> {
+    Ok(conn.query_row(
+        "SELECT COUNT(*) FROM run_kudos WHERE run_id = ?1 AND hidden = 0",
+        [run_id],
+        |row| row.get(0),
+    )?)
+}
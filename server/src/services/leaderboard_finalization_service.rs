@@ -0,0 +1,61 @@
+use booty_hunt_core::FinalizedLeaderboard;
+use rusqlite::OptionalExtension;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::services::leaderboard_service::{self, LeaderboardFilters, LeaderboardSort};
+
+/// Wide enough to cover any board the scheduler will realistically finalize;
+/// unlike a live `/api/leaderboard` poll there's no client asking for a
+/// specific page, so this just captures the whole ranked board once.
+const FINALIZED_BOARD_SIZE: i64 = 1000;
+
+/// Returns the frozen final-rank board for `week_key`, if `finalize_week` has
+/// already run for it. `None` before finalization happens (typically a few
+/// minutes to an hour after the week ends, per the scheduler's check
+/// interval) or for a week that hasn't ended yet.
+pub fn get_finalized(db: &Db, tenant_id: &str, week_key: &str) -> AppResult<Option<FinalizedLeaderboard>> {
+    db.with_read_conn(|conn| {
+        let row: Option<(String, String)> = conn
+            .query_row(
+                "SELECT entries_json, finalized_at FROM leaderboard_finalizations WHERE tenant_id = ?1 AND week_key = ?2",
+                rusqlite::params![tenant_id, week_key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let Some((entries_json, finalized_at)) = row else { return Ok(None) };
+        let entries = serde_json::from_str(&entries_json).map_err(|e| AppError::Internal(e.to_string()))?;
+        Ok(Some(FinalizedLeaderboard { week_key: week_key.to_string(), entries, finalized_at }))
+    })
+}
+
+/// Freezes `week_key`'s default board (unfiltered, raw score) into
+/// `leaderboard_finalizations` with each entry's final rank, so a
+/// late-arriving submission or a clock skewed into the wrong week can't
+/// rewrite standings that rewards have already been handed out against.
+/// Idempotent, like `digest_service::get_or_generate` — once a week is
+/// finalized it never changes, even if called again with new runs in place.
+pub fn finalize_week(db: &Db, tenant_id: &str, week_key: &str) -> AppResult<FinalizedLeaderboard> {
+    if let Some(existing) = get_finalized(db, tenant_id, week_key)? {
+        return Ok(existing);
+    }
+    let entries = leaderboard_service::fetch_leaderboard(
+        db,
+        tenant_id,
+        week_key,
+        FINALIZED_BOARD_SIZE,
+        LeaderboardFilters::default(),
+        LeaderboardSort::Score,
+    )?;
+    let finalized_at = chrono::Utc::now().to_rfc3339();
+    let entries_json = serde_json::to_string(&entries).map_err(|e| AppError::Internal(e.to_string()))?;
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO leaderboard_finalizations (tenant_id, week_key, entries_json, finalized_at) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (tenant_id, week_key) DO NOTHING",
+            rusqlite::params![tenant_id, week_key, entries_json, finalized_at],
+        )?;
+        Ok(())
+    })?;
+    Ok(FinalizedLeaderboard { week_key: week_key.to_string(), entries, finalized_at })
+}
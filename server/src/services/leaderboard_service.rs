@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use booty_hunt_core::LeaderboardEntry;
+
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::services::{cosmetics_service, kudos_service};
+
+/// Which column ranks the board. `Score` is the default per-class raw
+/// ordering; `Unified` ranks by `normalized_score` so no single ship class
+/// dominates the cross-class category; `Speedrun` ranks victorious runs only,
+/// fastest `time_played` first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaderboardSort {
+    Score,
+    Unified,
+    Speedrun,
+    Stealth,
+}
+
+impl LeaderboardSort {
+    fn column(self) -> &'static str {
+        match self {
+            LeaderboardSort::Score => "r.score",
+            LeaderboardSort::Unified => "r.normalized_score",
+            LeaderboardSort::Speedrun => "r.time_played",
+            LeaderboardSort::Stealth => "r.max_heat",
+        }
+    }
+
+    fn direction(self) -> &'static str {
+        match self {
+            LeaderboardSort::Score | LeaderboardSort::Unified => "DESC",
+            LeaderboardSort::Speedrun | LeaderboardSort::Stealth => "ASC",
+        }
+    }
+
+    fn victory_only(self) -> bool {
+        matches!(self, LeaderboardSort::Speedrun | LeaderboardSort::Stealth)
+    }
+
+    /// Secondary sort applied when the primary column ties — e.g. two
+    /// stealth runs finishing at the same `max_heat` are broken by whoever
+    /// got there faster, then by submission order. `r.id` is appended as a
+    /// final tie-break in every case so the ordering is a true total order:
+    /// `created_at` alone can still tie (two runs submitted within the same
+    /// second, or backfilled with an identical timestamp), and without a
+    /// tiebreaker on a column that's actually unique, SQLite is free to
+    /// return equally-ranked rows in a different order from one query to the
+    /// next, which reads to a client as ranks flickering between polls.
+    fn tie_break(self) -> &'static str {
+        match self {
+            LeaderboardSort::Stealth => "r.time_played ASC, r.created_at ASC, r.id ASC",
+            _ => "r.created_at ASC, r.id ASC",
+        }
+    }
+}
+
+/// Optional narrowing dimensions shared by `fetch_leaderboard` and
+/// `fetch_around` — bundled into one struct because most callers leave every
+/// one of them unset (`LeaderboardFilters::default()`) and passing four
+/// separate `None`s at every call site was more noise than signal.
+#[derive(Default, Clone, Copy)]
+pub struct LeaderboardFilters<'a> {
+    pub region: Option<&'a str>,
+    pub ruleset_id: Option<&'a str>,
+    /// Resolved against `player_divisions` for the same
+    /// `tenant_id`/`week_key` rather than a column on `runs`, since a
+    /// player's division is assigned independently of any one run.
+    pub division: Option<i64>,
+    pub omen_id: Option<&'a str>,
+}
+
+/// Fetches the top `limit` runs for `week_key` within `tenant_id`, narrowed
+/// by `filters` (unset fields leave that dimension unfiltered), ordered by
+/// `sort`. Filters are bound as named parameters so adding one doesn't
+/// multiply the branches here.
+pub fn fetch_leaderboard(
+    db: &Db,
+    tenant_id: &str,
+    week_key: &str,
+    limit: i64,
+    filters: LeaderboardFilters,
+    sort: LeaderboardSort,
+) -> AppResult<Vec<LeaderboardEntry>> {
+    let LeaderboardFilters { region, ruleset_id, division, omen_id } = filters;
+    db.with_read_conn(|conn| {
+        // `omen_id` drops the single-week restriction rather than adding to
+        // it — the whole point is comparing scores achieved under the same
+        // omen across every week it's appeared in, not just this one.
+        let mut sql = "SELECT r.id, r.player_id, p.display_name, r.ship_class, r.score, r.victory, r.created_at, r.region, r.normalized_score
+             FROM runs r JOIN players p ON p.id = r.player_id
+             WHERE r.tenant_id = :tenant_id AND (:omen_id IS NOT NULL OR r.week_key = :week_key) AND r.hidden = 0
+             AND (:region IS NULL OR r.region = :region)
+             AND (:ruleset_id IS NULL OR r.ruleset_id = :ruleset_id)
+             AND (:omen_id IS NULL OR EXISTS (SELECT 1 FROM json_each(r.modifier_omen_ids) WHERE value = :omen_id))
+             AND (:division IS NULL OR r.player_id IN (
+                 SELECT player_id FROM player_divisions
+                 WHERE tenant_id = :tenant_id AND week_key = :week_key AND division = :division
+             ))"
+            .to_string();
+        if sort.victory_only() {
+            sql.push_str(" AND r.victory = 1");
+        }
+        sql.push_str(&format!(
+            " ORDER BY {} {}, {} LIMIT :limit",
+            sort.column(),
+            sort.direction(),
+            sort.tie_break()
+        ));
+
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(
+                rusqlite::named_params! {
+                    ":tenant_id": tenant_id,
+                    ":week_key": week_key,
+                    ":region": region,
+                    ":ruleset_id": ruleset_id,
+                    ":division": division,
+                    ":omen_id": omen_id,
+                    ":limit": limit,
+                },
+                row_to_tuple,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut entries = Vec::new();
+        for (rank, (run_id, player_id, player_name, ship_class, score, victory, created_at, region, normalized_score)) in
+            rows.into_iter().enumerate()
+        {
+            let equipped_cosmetics: HashMap<String, String> =
+                cosmetics_service::equipped_items(conn, &player_id)?;
+            let kudos_count = kudos_service::count(conn, &run_id)?;
+            entries.push(LeaderboardEntry {
+                rank: rank as i64 + 1,
+                run_id,
+                player_id,
+                player_name,
+                ship_class,
+                score,
+                victory,
+                created_at,
+                equipped_cosmetics,
+                region,
+                kudos_count,
+                normalized_score,
+            });
+        }
+        Ok(entries)
+    })
+}
+
+/// Fetches `context` entries above and below `run_id` (inclusive of `run_id`
+/// itself) within the same tenant/week/region/ruleset/sort as
+/// `fetch_leaderboard`, so a client can render "you are #1,482" without
+/// downloading the whole board. Ranks a `ROW_NUMBER()` window over the same
+/// filtered set `fetch_leaderboard` would return, rather than reusing that
+/// function and slicing in Rust, since the whole point is to avoid pulling
+/// every row above the target run over the wire.
+pub fn fetch_around(
+    db: &Db,
+    tenant_id: &str,
+    week_key: &str,
+    run_id: &str,
+    context: i64,
+    filters: LeaderboardFilters,
+    sort: LeaderboardSort,
+) -> AppResult<Vec<LeaderboardEntry>> {
+    let LeaderboardFilters { region, ruleset_id, division, omen_id } = filters;
+    db.with_read_conn(|conn| {
+        let mut sql = format!(
+            "WITH ranked AS (
+                SELECT r.id, r.player_id, p.display_name, r.ship_class, r.score, r.victory, r.created_at, r.region, r.normalized_score,
+                       ROW_NUMBER() OVER (ORDER BY {column} {direction}, {tie_break}) AS rn
+                FROM runs r JOIN players p ON p.id = r.player_id
+                WHERE r.tenant_id = :tenant_id AND (:omen_id IS NOT NULL OR r.week_key = :week_key) AND r.hidden = 0
+                AND (:region IS NULL OR r.region = :region)
+                AND (:ruleset_id IS NULL OR r.ruleset_id = :ruleset_id)
+                AND (:omen_id IS NULL OR EXISTS (SELECT 1 FROM json_each(r.modifier_omen_ids) WHERE value = :omen_id))
+                AND (:division IS NULL OR r.player_id IN (
+                    SELECT player_id FROM player_divisions
+                    WHERE tenant_id = :tenant_id AND week_key = :week_key AND division = :division
+                ))",
+            column = sort.column(),
+            direction = sort.direction(),
+            tie_break = sort.tie_break(),
+        );
+        if sort.victory_only() {
+            sql.push_str(" AND r.victory = 1");
+        }
+        sql.push_str(
+            "
+            )
+            SELECT id, player_id, display_name, ship_class, score, victory, created_at, region, normalized_score, rn
+            FROM ranked
+            WHERE rn BETWEEN (SELECT rn FROM ranked WHERE id = :run_id) - :context
+                         AND (SELECT rn FROM ranked WHERE id = :run_id) + :context
+            ORDER BY rn",
+        );
+
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(
+                rusqlite::named_params! {
+                    ":tenant_id": tenant_id,
+                    ":week_key": week_key,
+                    ":region": region,
+                    ":ruleset_id": ruleset_id,
+                    ":division": division,
+                    ":omen_id": omen_id,
+                    ":run_id": run_id,
+                    ":context": context,
+                },
+                |row| {
+                    let tuple = row_to_tuple(row)?;
+                    let rank: i64 = row.get(9)?;
+                    Ok((tuple, rank))
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if rows.is_empty() {
+            return Err(crate::error::AppError::NotFound);
+        }
+
+        let mut entries = Vec::new();
+        for ((run_id, player_id, player_name, ship_class, score, victory, created_at, region, normalized_score), rank) in rows {
+            let equipped_cosmetics: HashMap<String, String> = cosmetics_service::equipped_items(conn, &player_id)?;
+            let kudos_count = kudos_service::count(conn, &run_id)?;
+            entries.push(LeaderboardEntry {
+                rank,
+                run_id,
+                player_id,
+                player_name,
+                ship_class,
+                score,
+                victory,
+                created_at,
+                equipped_cosmetics,
+                region,
+                kudos_count,
+                normalized_score,
+            });
+        }
+        Ok(entries)
+    })
+}
+
+type LeaderboardRow = (String, String, String, String, i64, bool, String, Option<String>, i64);
+
+fn row_to_tuple(row: &rusqlite::Row) -> rusqlite::Result<LeaderboardRow> {
+    Ok((
+        row.get(0)?,
+        row.get(1)?,
+        row.get(2)?,
+        row.get(3)?,
+        row.get(4)?,
+        row.get::<_, i64>(5)? != 0,
+        row.get(6)?,
+        row.get(7)?,
+        row.get(8)?,
+    ))
+}
@@ -0,0 +1,49 @@
+pub mod cosmetics_service;
+pub mod leaderboard_service;
+pub mod run_service;
+pub mod tape_upload_service;
+pub mod notification_service;
+pub mod player_service;
+pub mod identity_service;
+pub mod api_key_service;
+pub mod ghost_service;
+pub mod kudos_service;
+pub mod digest_service;
+pub mod leaderboard_finalization_service;
+pub mod rating_service;
+pub mod splits_service;
+pub mod ruleset_service;
+pub mod progression_service;
+pub mod economy_service;
+pub mod regatta_service;
+pub mod replication_service;
+pub mod overtake_service;
+pub mod signal_fire_service;
+pub mod signal_fire_trade_service;
+pub mod tide_service;
+pub mod experiment_service;
+pub mod telemetry_service;
+pub mod analytics_export_service;
+pub mod stats_service;
+pub mod announcement_service;
+pub mod bottle_note_service;
+pub mod raid_service;
+pub mod division_service;
+pub mod goal_service;
+pub mod coaching_service;
+pub mod nonce_service;
+pub mod moderation_queue_service;
+pub mod suspicion_service;
+pub mod admin_action_service;
+pub mod appeal_service;
+pub mod community_event_service;
+pub mod news_service;
+pub mod tuning_service;
+pub mod bundle_service;
+pub mod client_error_service;
+pub mod ghost_desync_service;
+pub mod ghost_signed_url_service;
+pub mod ghost_highlight_service;
+pub mod public_dump_service;
+pub mod run_card_service;
+pub mod scheduler_lock_service;
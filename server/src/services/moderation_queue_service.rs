@@ -0,0 +1,75 @@
+//! Generic review queue for submissions worth a human's attention. Callers
+//! decide *why* a run belongs here — canary hits and high suspicion scores
+//! both go through `flag` (see `suspicion_service`) — this module only
+//! owns the queue itself.
+
+use booty_hunt_core::FlaggedSubmission;
+use rusqlite::Connection;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::AppResult;
+
+/// Records `run_id` in the review queue with `reason` and the suspicion
+/// score it carried at flag time. Called from inside
+/// `run_service::submit_run`'s transaction so a flag never outlives the run
+/// it describes — if the insert rolls back, so does the flag.
+pub fn flag(
+    conn: &Connection,
+    tenant_id: &str,
+    run_id: &str,
+    player_id: &str,
+    reason: &str,
+    suspicion_score: i64,
+    created_at: &str,
+) -> AppResult<()> {
+    let id = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO flagged_submissions (id, tenant_id, run_id, player_id, reason, suspicion_score, created_at, resolved_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, NULL)",
+        rusqlite::params![id, tenant_id, run_id, player_id, reason, suspicion_score, created_at],
+    )?;
+    Ok(())
+}
+
+/// Open (unresolved) flags, highest suspicion score first (ties broken
+/// oldest-first) — an admin works the most concerning entries before the
+/// merely old ones.
+pub fn queue(db: &Db, tenant_id: &str, limit: i64) -> AppResult<Vec<FlaggedSubmission>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, run_id, player_id, reason, suspicion_score, created_at, resolved_at
+             FROM flagged_submissions WHERE tenant_id = ?1 AND resolved_at IS NULL
+             ORDER BY suspicion_score DESC, created_at ASC LIMIT ?2",
+        )?;
+        let rows = stmt
+            .query_map(rusqlite::params![tenant_id, limit], |row| {
+                Ok(FlaggedSubmission {
+                    id: row.get(0)?,
+                    run_id: row.get(1)?,
+                    player_id: row.get(2)?,
+                    reason: row.get(3)?,
+                    suspicion_score: row.get(4)?,
+                    created_at: row.get(5)?,
+                    resolved_at: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })
+}
+
+/// Marks a flag as reviewed, so it drops out of `queue`.
+pub fn resolve(db: &Db, tenant_id: &str, id: &str) -> AppResult<()> {
+    let resolved_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        let updated = conn.execute(
+            "UPDATE flagged_submissions SET resolved_at = ?1 WHERE id = ?2 AND tenant_id = ?3 AND resolved_at IS NULL",
+            rusqlite::params![resolved_at, id, tenant_id],
+        )?;
+        if updated == 0 {
+            return Err(crate::error::AppError::NotFound);
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,141 @@
+//! Admin-authored in-game news/MOTD entries — maintenance notices, event
+//! callouts, that sort of thing. Purely a scheduled bulletin board: the
+//! server stores `body` opaquely and exposes whatever is currently inside
+//! its publish/expiry window, same simplification `community_event_service`
+//! makes for `modifiers`.
+
+use booty_hunt_core::{CreateNewsItemRequest, NewsItem, NewsSeverity, UpdateNewsItemRequest};
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+fn severity_str(severity: NewsSeverity) -> &'static str {
+    match severity {
+        NewsSeverity::Info => "info",
+        NewsSeverity::Warning => "warning",
+        NewsSeverity::Critical => "critical",
+    }
+}
+
+fn parse_severity(raw: &str) -> AppResult<NewsSeverity> {
+    match raw {
+        "info" => Ok(NewsSeverity::Info),
+        "warning" => Ok(NewsSeverity::Warning),
+        "critical" => Ok(NewsSeverity::Critical),
+        other => Err(AppError::Internal(format!("stored news_items.severity is invalid: {other}"))),
+    }
+}
+
+/// `(id, title, body, severity, publish_at, expires_at, created_at, updated_at)`
+type NewsItemRow = (String, String, String, String, String, Option<String>, String, String);
+
+fn row_to_news_item(row: &rusqlite::Row) -> rusqlite::Result<NewsItemRow> {
+    Ok((
+        row.get(0)?,
+        row.get(1)?,
+        row.get(2)?,
+        row.get(3)?,
+        row.get(4)?,
+        row.get(5)?,
+        row.get(6)?,
+        row.get(7)?,
+    ))
+}
+
+fn build_news_item((id, title, body, severity, publish_at, expires_at, created_at, updated_at): NewsItemRow) -> AppResult<NewsItem> {
+    Ok(NewsItem { id, title, body, severity: parse_severity(&severity)?, publish_at, expires_at, created_at, updated_at })
+}
+
+pub fn create(db: &Db, tenant_id: &str, req: CreateNewsItemRequest) -> AppResult<NewsItem> {
+    if req.title.trim().is_empty() {
+        return Err(AppError::Validation("title must not be empty".into()));
+    }
+    let id = Uuid::new_v4().to_string();
+    let now = chrono::Utc::now().to_rfc3339();
+    let severity = severity_str(req.severity);
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO news_items (id, tenant_id, title, body, severity, publish_at, expires_at, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8)",
+            rusqlite::params![id, tenant_id, req.title, req.body, severity, req.publish_at, req.expires_at, now],
+        )?;
+        Ok(())
+    })?;
+    Ok(NewsItem {
+        id,
+        title: req.title,
+        body: req.body,
+        severity: req.severity,
+        publish_at: req.publish_at,
+        expires_at: req.expires_at,
+        created_at: now.clone(),
+        updated_at: now,
+    })
+}
+
+pub fn update(db: &Db, tenant_id: &str, news_id: &str, req: UpdateNewsItemRequest) -> AppResult<NewsItem> {
+    if req.title.trim().is_empty() {
+        return Err(AppError::Validation("title must not be empty".into()));
+    }
+    let now = chrono::Utc::now().to_rfc3339();
+    let severity = severity_str(req.severity);
+    let updated = db.with_write_conn(|conn| {
+        Ok(conn.execute(
+            "UPDATE news_items SET title = ?1, body = ?2, severity = ?3, publish_at = ?4, expires_at = ?5, updated_at = ?6
+             WHERE id = ?7 AND tenant_id = ?8",
+            rusqlite::params![req.title, req.body, severity, req.publish_at, req.expires_at, now, news_id, tenant_id],
+        )?)
+    })?;
+    if updated == 0 {
+        return Err(AppError::NotFound);
+    }
+    Ok(NewsItem {
+        id: news_id.to_string(),
+        title: req.title,
+        body: req.body,
+        severity: req.severity,
+        publish_at: req.publish_at,
+        expires_at: req.expires_at,
+        created_at: now.clone(),
+        updated_at: now,
+    })
+}
+
+pub fn delete(db: &Db, tenant_id: &str, news_id: &str) -> AppResult<()> {
+    let deleted = db.with_write_conn(|conn| {
+        Ok(conn.execute("DELETE FROM news_items WHERE id = ?1 AND tenant_id = ?2", rusqlite::params![news_id, tenant_id])?)
+    })?;
+    if deleted == 0 {
+        return Err(AppError::NotFound);
+    }
+    Ok(())
+}
+
+/// Every news item for `tenant_id`, published or not, for the admin list
+/// view — most recently created first.
+pub fn list_all(db: &Db, tenant_id: &str) -> AppResult<Vec<NewsItem>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, title, body, severity, publish_at, expires_at, created_at, updated_at
+             FROM news_items WHERE tenant_id = ?1 ORDER BY created_at DESC",
+        )?;
+        let rows = stmt.query_map([tenant_id], row_to_news_item)?.collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter().map(build_news_item).collect()
+    })
+}
+
+/// News items currently inside their publish/expiry window, for
+/// `GET /api/news` — newest publish time first.
+pub fn active(db: &Db, tenant_id: &str) -> AppResult<Vec<NewsItem>> {
+    let now = chrono::Utc::now().to_rfc3339();
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, title, body, severity, publish_at, expires_at, created_at, updated_at
+             FROM news_items WHERE tenant_id = ?1 AND publish_at <= ?2 AND (expires_at IS NULL OR expires_at > ?2)
+             ORDER BY publish_at DESC",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![tenant_id, now], row_to_news_item)?.collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter().map(build_news_item).collect()
+    })
+}
@@ -0,0 +1,140 @@
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// How long an issued nonce stays valid for. Long enough to cover a full
+/// run (see `run_service::MAX_VICTORY_TIME_PLAYED_SECS`) plus whatever time
+/// the client spends loading in and queuing the submission, short enough
+/// that a nonce leaked from a network capture can't be replayed hours later.
+const NONCE_TTL_SECS: i64 = 8 * 60 * 60;
+
+/// Issues a single-use nonce scoped to `player_id`/`seed`, to be echoed back
+/// as `RunSubmission::submission_nonce` on the eventual run submission. Not
+/// tied to a specific run id — none exists yet at this point — only to the
+/// (player, seed) pair the client says it's about to play, so a nonce
+/// obtained for one seed can't be spent submitting a run against another.
+pub fn issue(db: &Db, tenant_id: &str, player_id: &str, seed: i64) -> AppResult<String> {
+    let nonce = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now();
+    let expires_at_epoch = (created_at + chrono::Duration::seconds(NONCE_TTL_SECS)).timestamp();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO submission_nonces (nonce, tenant_id, player_id, seed, created_at, expires_at_epoch, consumed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL)",
+            rusqlite::params![nonce, tenant_id, player_id, seed, created_at.to_rfc3339(), expires_at_epoch],
+        )?;
+        Ok(())
+    })?;
+    Ok(nonce)
+}
+
+/// Marks `nonce` consumed if it exists, is unexpired and unconsumed, and was
+/// issued for this exact `tenant_id`/`player_id`/`seed`, erroring otherwise.
+/// Takes `&Connection` rather than `&Db` so `run_service::submit_run` can
+/// call this inside the same `with_tx` as the run insert — the nonce should
+/// only actually be spent if the submission it gates ends up committed, and
+/// a transaction rollback on a later failure must roll this back with it.
+pub fn consume(conn: &rusqlite::Connection, tenant_id: &str, player_id: &str, seed: i64, nonce: &str) -> AppResult<()> {
+    let now = chrono::Utc::now();
+    let updated = conn.execute(
+        "UPDATE submission_nonces SET consumed_at = ?1
+         WHERE nonce = ?2 AND tenant_id = ?3 AND player_id = ?4 AND seed = ?5
+           AND consumed_at IS NULL AND expires_at_epoch >= ?6",
+        rusqlite::params![now.to_rfc3339(), nonce, tenant_id, player_id, seed, now.timestamp()],
+    )?;
+    if updated == 0 {
+        return Err(AppError::Validation(
+            "submission_nonce is missing, expired, already used, or doesn't match this player/seed".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Read-only counterpart to `consume`, for `run_service::validate_dry_run` —
+/// checks the same conditions without spending the nonce, since a dry run
+/// must be safe to call repeatedly against the same nonce a client is about
+/// to actually submit with.
+pub fn peek(db: &Db, tenant_id: &str, player_id: &str, seed: i64, nonce: &str) -> AppResult<()> {
+    let now = chrono::Utc::now().timestamp();
+    let valid: bool = db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT EXISTS(
+                 SELECT 1 FROM submission_nonces
+                 WHERE nonce = ?1 AND tenant_id = ?2 AND player_id = ?3 AND seed = ?4
+                   AND consumed_at IS NULL AND expires_at_epoch >= ?5
+             )",
+            rusqlite::params![nonce, tenant_id, player_id, seed, now],
+            |row| row.get(0),
+        )
+        .map_err(Into::into)
+    })?;
+    if !valid {
+        return Err(AppError::Validation(
+            "submission_nonce is missing, expired, already used, or doesn't match this player/seed".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Deletes nonces past their TTL, whether consumed or not. Called by the
+/// scheduler's GC job alongside `tape_upload_service::gc_expired`.
+pub fn gc_expired(db: &Db) -> AppResult<usize> {
+    let now = chrono::Utc::now().timestamp();
+    db.with_write_conn(|conn| Ok(conn.execute("DELETE FROM submission_nonces WHERE expires_at_epoch < ?1", [now])?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    #[test]
+    fn consume_accepts_a_freshly_issued_nonce() {
+        let db = test_db();
+        let nonce = issue(&db, "tenant-a", "player-1", 42).unwrap();
+        db.with_write_conn(|conn| consume(conn, "tenant-a", "player-1", 42, &nonce)).unwrap();
+    }
+
+    #[test]
+    fn consume_rejects_reuse() {
+        let db = test_db();
+        let nonce = issue(&db, "tenant-a", "player-1", 42).unwrap();
+        db.with_write_conn(|conn| consume(conn, "tenant-a", "player-1", 42, &nonce)).unwrap();
+        let result = db.with_write_conn(|conn| consume(conn, "tenant-a", "player-1", 42, &nonce));
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn consume_rejects_a_nonce_issued_for_a_different_tenant() {
+        let db = test_db();
+        let nonce = issue(&db, "tenant-a", "player-1", 42).unwrap();
+        let result = db.with_write_conn(|conn| consume(conn, "tenant-b", "player-1", 42, &nonce));
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn consume_rejects_a_nonce_issued_for_a_different_seed() {
+        let db = test_db();
+        let nonce = issue(&db, "tenant-a", "player-1", 42).unwrap();
+        let result = db.with_write_conn(|conn| consume(conn, "tenant-a", "player-1", 99, &nonce));
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn consume_rejects_an_unknown_nonce() {
+        let db = test_db();
+        let result = db.with_write_conn(|conn| consume(conn, "tenant-a", "player-1", 42, "not-a-real-nonce"));
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn peek_does_not_consume() {
+        let db = test_db();
+        let nonce = issue(&db, "tenant-a", "player-1", 42).unwrap();
+        peek(&db, "tenant-a", "player-1", 42, &nonce).unwrap();
+        // Still valid the second time — peek must not have spent it.
+        peek(&db, "tenant-a", "player-1", 42, &nonce).unwrap();
+        db.with_write_conn(|conn| consume(conn, "tenant-a", "player-1", 42, &nonce)).unwrap();
+    }
+}
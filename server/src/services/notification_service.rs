@@ -0,0 +1,57 @@
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::notifications::{NotificationEvent, NotificationProvider};
+
+pub fn register_device(db: &Db, player_id: &str, provider: &str, token: &str) -> AppResult<()> {
+    let registered_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT OR REPLACE INTO device_tokens (player_id, provider, token, registered_at) VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![player_id, provider, token, registered_at],
+        )?;
+        Ok(())
+    })
+}
+
+fn overtaken_enabled(db: &Db, player_id: &str) -> AppResult<bool> {
+    db.with_read_conn(|conn| {
+        Ok(conn
+            .query_row(
+                "SELECT overtaken FROM notification_preferences WHERE player_id = ?1",
+                [player_id],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|v| v != 0)
+            .unwrap_or(true))
+    })
+}
+
+/// Sends `event` to every device `player_id` has registered, via whichever
+/// provider each device is registered under. Best-effort: a failed delivery
+/// is logged, not surfaced to the caller, since notifications never block the
+/// action that triggered them (a run submission, a redemption).
+pub async fn dispatch(
+    db: &Db,
+    providers: &[Box<dyn NotificationProvider>],
+    player_id: &str,
+    event: NotificationEvent,
+) -> AppResult<()> {
+    if matches!(event, NotificationEvent::OvertakenInTop { .. }) && !overtaken_enabled(db, player_id)? {
+        return Ok(());
+    }
+
+    let devices: Vec<(String, String)> = db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare("SELECT provider, token FROM device_tokens WHERE player_id = ?1")?;
+        let rows = stmt.query_map([player_id], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.map(|r| r.map_err(crate::error::AppError::from)).collect()
+    })?;
+
+    for (provider_name, token) in devices {
+        if let Some(provider) = providers.iter().find(|p| p.name() == provider_name) {
+            if let Err(err) = provider.send(&token, &event).await {
+                tracing::warn!(provider = provider_name, %err, "notification delivery failed");
+            }
+        }
+    }
+    Ok(())
+}
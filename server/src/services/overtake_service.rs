@@ -0,0 +1,87 @@
+use booty_hunt_core::OvertakeEvent;
+use rusqlite::Connection;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::AppResult;
+
+/// A player whose run just dropped out of the top N because of a fresher,
+/// higher-scoring submission.
+pub struct DisplacedPlayer {
+    pub player_id: String,
+    pub previous_rank: i64,
+}
+
+/// If `top_n` is enabled and `new_score` beats whoever currently holds rank
+/// `top_n` for `week_key`, records an overtake event for that player and
+/// returns them so the caller can notify them. Takes `&Connection` directly
+/// so `run_service::submit_run` can call this from inside its own
+/// `with_write_conn` closure, before the new run's own INSERT — the query
+/// needs to see the leaderboard as it stood immediately before this
+/// submission to find who it's about to push out.
+pub fn find_and_record(
+    conn: &Connection,
+    tenant_id: &str,
+    week_key: &str,
+    top_n: i64,
+    new_player_id: &str,
+    new_score: i64,
+    created_at: &str,
+) -> AppResult<Option<DisplacedPlayer>> {
+    if top_n <= 0 {
+        return Ok(None);
+    }
+
+    let holder: Option<(String, i64)> = conn
+        .query_row(
+            "SELECT player_id, score FROM runs
+             WHERE tenant_id = ?1 AND week_key = ?2
+             ORDER BY score DESC, created_at ASC
+             LIMIT 1 OFFSET ?3",
+            rusqlite::params![tenant_id, week_key, top_n - 1],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .ok();
+
+    let Some((holder_player_id, holder_score)) = holder else {
+        // Fewer than `top_n` runs exist yet for this week — the board isn't
+        // full, so nobody can be pushed out of it.
+        return Ok(None);
+    };
+
+    if holder_player_id == new_player_id || new_score <= holder_score {
+        return Ok(None);
+    }
+
+    let id = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO overtake_events (id, tenant_id, player_id, displaced_by_player_id, week_key, previous_rank, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        rusqlite::params![id, tenant_id, holder_player_id, new_player_id, week_key, top_n, created_at],
+    )?;
+
+    Ok(Some(DisplacedPlayer { player_id: holder_player_id, previous_rank: top_n }))
+}
+
+/// The most recent overtakes suffered by `player_id`, newest first — backs
+/// the client's "you've been overtaken" feed for cases where the push
+/// notification never arrived (no device registered, provider down, etc).
+pub fn recent_for_player(db: &Db, tenant_id: &str, player_id: &str, limit: i64) -> AppResult<Vec<OvertakeEvent>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT player_id, displaced_by_player_id, week_key, previous_rank, created_at
+             FROM overtake_events WHERE tenant_id = ?1 AND player_id = ?2
+             ORDER BY created_at DESC LIMIT ?3",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![tenant_id, player_id, limit], |row| {
+            Ok(OvertakeEvent {
+                player_id: row.get(0)?,
+                displaced_by_player_id: row.get(1)?,
+                week_key: row.get(2)?,
+                previous_rank: row.get(3)?,
+                created_at: row.get(4)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    })
+}
@@ -0,0 +1,87 @@
+use booty_hunt_core::PlayerProfile;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+pub struct Registration {
+    pub player_id: String,
+    pub token: String,
+    pub recovery_code: String,
+}
+
+fn generate_recovery_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..10).map(|_| rng.gen_range(0..10).to_string()).collect::<Vec<_>>().join("").chars().enumerate()
+        .map(|(i, c)| if i > 0 && i % 5 == 0 { format!("-{c}") } else { c.to_string() })
+        .collect()
+}
+
+fn hash_code(code: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(code.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Registers a new player with a random token and a one-time recovery code.
+/// Since accounts have no email, the recovery code is the only way to
+/// reclaim a lost token — the caller must show it to the player exactly once.
+pub fn register(db: &Db, display_name: &str, tenant_id: &str) -> AppResult<Registration> {
+    let player_id = Uuid::new_v4().to_string();
+    let token = Uuid::new_v4().to_string();
+    let recovery_code = generate_recovery_code();
+    let recovery_code_hash = hash_code(&recovery_code);
+    let created_at = chrono::Utc::now().to_rfc3339();
+
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO players (id, token, display_name, created_at, recovery_code_hash, tenant_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![player_id, token, display_name, created_at, recovery_code_hash, tenant_id],
+        )?;
+        Ok(())
+    })?;
+
+    Ok(Registration { player_id, token, recovery_code })
+}
+
+/// Exchanges a recovery code for a fresh token, invalidating the old token
+/// and issuing a new recovery code (the old one is single-use).
+pub fn recover(db: &Db, recovery_code: &str) -> AppResult<Registration> {
+    let code_hash = hash_code(recovery_code);
+    let new_token = Uuid::new_v4().to_string();
+    let new_recovery_code = generate_recovery_code();
+    let new_recovery_hash = hash_code(&new_recovery_code);
+
+    db.with_write_conn(|conn| {
+        let player_id: String = conn
+            .query_row(
+                "SELECT id FROM players WHERE recovery_code_hash = ?1",
+                [&code_hash],
+                |row| row.get(0),
+            )
+            .map_err(|_| AppError::Validation("recovery code not recognized".into()))?;
+
+        conn.execute(
+            "UPDATE players SET token = ?1, recovery_code_hash = ?2 WHERE id = ?3",
+            rusqlite::params![new_token, new_recovery_hash, player_id],
+        )?;
+
+        Ok(Registration { player_id, token: new_token, recovery_code: new_recovery_code })
+    })
+}
+
+/// Fetches a player's public profile, including their skill rating. Ratings
+/// default to 1500 for a player who has never had a rated head-to-head, so
+/// this never fails just because `ratings` has no row for them yet.
+pub fn profile(db: &Db, player_id: &str) -> AppResult<PlayerProfile> {
+    db.with_read_conn(|conn| {
+        let display_name: String = conn
+            .query_row("SELECT display_name FROM players WHERE id = ?1", [player_id], |row| row.get(0))
+            .map_err(|_| AppError::NotFound)?;
+        let rating: f64 =
+            conn.query_row("SELECT rating FROM ratings WHERE player_id = ?1", [player_id], |row| row.get(0)).unwrap_or(1500.0);
+        Ok(PlayerProfile { player_id: player_id.to_string(), display_name, rating })
+    })
+}
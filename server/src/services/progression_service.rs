@@ -0,0 +1,110 @@
+use booty_hunt_core::{ClaimTierResult, SeasonProgress, SeasonTierStatus};
+use rusqlite::Connection;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::services::cosmetics_service;
+
+/// XP granted for one validated run, scaled by how far it got and how well
+/// it scored. Victory carries a flat bonus on top so clearing the run always
+/// beats grinding partial waves for score alone.
+pub fn xp_for_run(waves: i64, score: i64, victory: bool) -> i64 {
+    let base = waves * 50 + score / 100;
+    if victory {
+        base + 500
+    } else {
+        base
+    }
+}
+
+/// Credits `xp_gain` toward `tenant_id`/`player_id`'s progress in
+/// `season_id`. Takes `&Connection` directly so it can be called from inside
+/// `run_service`'s own `with_write_conn` closure, alongside the rating update.
+pub fn record_run(conn: &Connection, tenant_id: &str, player_id: &str, season_id: &str, xp_gain: i64) -> AppResult<()> {
+    conn.execute(
+        "INSERT INTO season_progress (tenant_id, player_id, season_id, xp) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(tenant_id, player_id, season_id) DO UPDATE SET xp = xp + excluded.xp",
+        rusqlite::params![tenant_id, player_id, season_id, xp_gain],
+    )?;
+    Ok(())
+}
+
+/// Current season progress for a player: total XP banked, plus every
+/// configured tier's unlock/claim status.
+pub fn get_progress(db: &Db, config: &Config, tenant_id: &str, player_id: &str) -> AppResult<SeasonProgress> {
+    let season_id = &config.current_season_id;
+    db.with_read_conn(|conn| {
+        let xp: i64 = conn
+            .query_row(
+                "SELECT xp FROM season_progress WHERE tenant_id = ?1 AND player_id = ?2 AND season_id = ?3",
+                rusqlite::params![tenant_id, player_id, season_id],
+                |row| row.get(0),
+            )
+            .unwrap_or(0);
+
+        let mut stmt = conn.prepare(
+            "SELECT tier FROM season_tier_claims WHERE tenant_id = ?1 AND player_id = ?2 AND season_id = ?3",
+        )?;
+        let claimed: std::collections::HashSet<i64> = stmt
+            .query_map(rusqlite::params![tenant_id, player_id, season_id], |row| row.get(0))?
+            .collect::<Result<_, _>>()?;
+
+        let tiers = config
+            .season_tiers
+            .iter()
+            .map(|t| SeasonTierStatus {
+                tier: t.tier,
+                xp_required: t.xp_required,
+                reward_item_id: t.reward_item_id.clone(),
+                unlocked: xp >= t.xp_required,
+                claimed: claimed.contains(&t.tier),
+            })
+            .collect();
+
+        Ok(SeasonProgress { season_id: season_id.clone(), xp, tiers })
+    })
+}
+
+/// Claims a tier's reward, granting `reward_item_id` to the player's
+/// cosmetic inventory. Idempotent: claiming an already-claimed tier just
+/// returns the same reward again rather than erroring, so a client retrying
+/// after a dropped response doesn't need special-case handling.
+pub fn claim_tier(db: &Db, config: &Config, tenant_id: &str, player_id: &str, tier: i64) -> AppResult<ClaimTierResult> {
+    let season_id = &config.current_season_id;
+    let tier_def = config
+        .season_tiers
+        .iter()
+        .find(|t| t.tier == tier)
+        .ok_or_else(|| AppError::Validation(format!("unknown season tier: {tier}")))?
+        .clone();
+
+    let claimed_at = chrono::Utc::now().to_rfc3339();
+    // The claim record and its reward grant must land together — a crash or
+    // error between the two would otherwise let a player retry the claim
+    // (the `INSERT OR IGNORE` is a no-op the second time) without ever
+    // getting the item, since `grant_item_conn` would never run again.
+    db.with_tx(|conn| {
+        let xp: i64 = conn
+            .query_row(
+                "SELECT xp FROM season_progress WHERE tenant_id = ?1 AND player_id = ?2 AND season_id = ?3",
+                rusqlite::params![tenant_id, player_id, season_id],
+                |row| row.get(0),
+            )
+            .unwrap_or(0);
+        if xp < tier_def.xp_required {
+            return Err(AppError::Validation(format!("tier {tier} not yet unlocked")));
+        }
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO season_tier_claims (tenant_id, player_id, season_id, tier, claimed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![tenant_id, player_id, season_id, tier, claimed_at],
+        )?;
+        if inserted > 0 {
+            cosmetics_service::grant_item_conn(conn, player_id, &tier_def.reward_item_id, "season_pass")?;
+        }
+        Ok(())
+    })?;
+
+    Ok(ClaimTierResult { reward_item_id: tier_def.reward_item_id })
+}
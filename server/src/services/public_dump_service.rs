@@ -0,0 +1,73 @@
+use booty_hunt_core::PublicWeeklyDump;
+use rusqlite::OptionalExtension;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::services::leaderboard_service::{self, LeaderboardFilters, LeaderboardSort};
+use crate::services::regatta_service;
+
+const TOP_RUNS_LIMIT: i64 = 1000;
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// The stored dump for `week_key`, plus its checksum, or `None` if it hasn't
+/// been generated yet.
+pub fn get(db: &Db, tenant_id: &str, week_key: &str) -> AppResult<Option<(String, String)>> {
+    db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT dump_json, checksum_sha256 FROM public_dumps WHERE tenant_id = ?1 AND week_key = ?2",
+            rusqlite::params![tenant_id, week_key],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()
+        .map_err(Into::into)
+    })
+}
+
+/// The week keys with a generated dump, newest first — backs the sitemap
+/// index so a mirror can discover what's available without guessing.
+pub fn list_available_weeks(db: &Db, tenant_id: &str) -> AppResult<Vec<String>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT week_key FROM public_dumps WHERE tenant_id = ?1 ORDER BY week_key DESC",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![tenant_id], |row| row.get(0))?;
+        rows.collect::<rusqlite::Result<Vec<String>>>().map_err(Into::into)
+    })
+}
+
+/// Generates and stores `week_key`'s public dump if it doesn't exist yet.
+/// Idempotent like `leaderboard_finalization_service::finalize_week` — once
+/// a week's dump is generated it's never recomputed, even if called again
+/// after new runs land (which shouldn't happen for a week that's already
+/// over, but the guard costs nothing).
+pub fn generate_if_missing(db: &Db, config: &Config, tenant_id: &str, week_key: &str) -> AppResult<()> {
+    if get(db, tenant_id, week_key)?.is_some() {
+        return Ok(());
+    }
+
+    let top_runs =
+        leaderboard_service::fetch_leaderboard(db, tenant_id, week_key, TOP_RUNS_LIMIT, LeaderboardFilters::default(), LeaderboardSort::Score)?;
+    let regattas = regatta_service::list_current(db, config, tenant_id, week_key)?;
+    let generated_at = chrono::Utc::now().to_rfc3339();
+
+    let dump = PublicWeeklyDump { week_key: week_key.to_string(), top_runs, regattas, omens: config.omens.clone(), generated_at };
+    let dump_json = serde_json::to_string(&dump)
+        .map_err(|e| crate::error::AppError::Internal(format!("failed to serialize public dump: {e}")))?;
+    let checksum = sha256_hex(dump_json.as_bytes());
+
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT OR IGNORE INTO public_dumps (tenant_id, week_key, dump_json, checksum_sha256, generated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![tenant_id, week_key, dump_json, checksum, dump.generated_at],
+        )?;
+        Ok(())
+    })
+}
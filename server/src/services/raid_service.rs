@@ -0,0 +1,104 @@
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::services::cosmetics_service;
+use crate::services::run_service::current_week_key;
+
+/// Adds `damage_dealt` to `player_id`'s running total against this week's
+/// raid boss. Called from `run_service::submit_run` for any submission
+/// reporting `config.raid_seed`, inside the same write transaction as the
+/// run insert. Takes `&Connection` rather than `&Db` for that reason.
+pub fn record_contribution(conn: &Connection, tenant_id: &str, week_key: &str, player_id: &str, damage_dealt: i64) -> AppResult<()> {
+    conn.execute(
+        "INSERT INTO raid_weeks (tenant_id, week_key, felled_at, rewards_granted) VALUES (?1, ?2, NULL, 0)
+         ON CONFLICT (tenant_id, week_key) DO NOTHING",
+        rusqlite::params![tenant_id, week_key],
+    )?;
+    conn.execute(
+        "INSERT INTO raid_contributions (tenant_id, week_key, player_id, damage_dealt) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (tenant_id, week_key, player_id) DO UPDATE SET damage_dealt = damage_dealt + excluded.damage_dealt",
+        rusqlite::params![tenant_id, week_key, player_id, damage_dealt],
+    )?;
+    Ok(())
+}
+
+/// This week's raid progress: total community damage against `boss_hp`,
+/// contributor count, and whether/when the boss fell.
+pub fn status(db: &Db, config: &Config, tenant_id: &str) -> AppResult<booty_hunt_core::RaidStatus> {
+    let week_key = current_week_key();
+    db.with_read_conn(|conn| {
+        let (damage_dealt, contributors_count): (i64, i64) = conn.query_row(
+            "SELECT COALESCE(SUM(damage_dealt), 0), COUNT(*) FROM raid_contributions WHERE tenant_id = ?1 AND week_key = ?2",
+            rusqlite::params![tenant_id, week_key],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let felled_at: Option<String> = conn
+            .query_row(
+                "SELECT felled_at FROM raid_weeks WHERE tenant_id = ?1 AND week_key = ?2",
+                rusqlite::params![tenant_id, week_key],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        Ok(booty_hunt_core::RaidStatus {
+            week_key: week_key.clone(),
+            seed: config.raid_seed,
+            boss_hp: config.raid_boss_hp,
+            damage_dealt,
+            contributors_count,
+            felled: felled_at.is_some(),
+            felled_at,
+        })
+    })
+}
+
+/// Checks whether this week's boss has taken enough damage to fall and, if
+/// so and it hasn't already been recorded, marks it felled and grants
+/// `config.raid_reward_item_id` to every contributor. Idempotent — safe to
+/// call on every scheduler tick, since `rewards_granted` gates the grant
+/// loop and `felled_at` gates the fall check itself.
+pub fn finalize_if_felled(db: &Db, config: &Config, tenant_id: &str) -> AppResult<bool> {
+    let week_key = current_week_key();
+    let contributors = db.with_write_conn(|conn| {
+        let already_felled: bool = conn
+            .query_row(
+                "SELECT felled_at IS NOT NULL FROM raid_weeks WHERE tenant_id = ?1 AND week_key = ?2",
+                rusqlite::params![tenant_id, week_key],
+                |row| row.get(0),
+            )
+            .optional()?
+            .unwrap_or(false);
+        if already_felled {
+            return Ok(None);
+        }
+        let damage_dealt: i64 = conn.query_row(
+            "SELECT COALESCE(SUM(damage_dealt), 0) FROM raid_contributions WHERE tenant_id = ?1 AND week_key = ?2",
+            rusqlite::params![tenant_id, week_key],
+            |row| row.get(0),
+        )?;
+        if damage_dealt < config.raid_boss_hp {
+            return Ok(None);
+        }
+        let felled_at = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "UPDATE raid_weeks SET felled_at = ?3, rewards_granted = 1 WHERE tenant_id = ?1 AND week_key = ?2",
+            rusqlite::params![tenant_id, week_key, felled_at],
+        )?;
+        let mut stmt = conn.prepare(
+            "SELECT player_id FROM raid_contributions WHERE tenant_id = ?1 AND week_key = ?2",
+        )?;
+        let contributors: Vec<String> =
+            stmt.query_map(rusqlite::params![tenant_id, week_key], |row| row.get(0))?.collect::<Result<_, _>>()?;
+        Ok(Some(contributors))
+    })?;
+
+    let Some(contributors) = contributors else { return Ok(false) };
+    if let Some(item_id) = &config.raid_reward_item_id {
+        for player_id in &contributors {
+            cosmetics_service::grant_item(db, player_id, item_id, "raid_reward")?;
+        }
+    }
+    Ok(true)
+}
@@ -0,0 +1,98 @@
+use booty_hunt_core::RatingEntry;
+use rusqlite::Connection;
+
+use crate::db::Db;
+use crate::error::AppResult;
+
+const DEFAULT_RATING: f64 = 1500.0;
+const K_FACTOR: f64 = 24.0;
+
+fn expected_score(rating: f64, opponent_rating: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+fn current_rating(conn: &Connection, player_id: &str) -> AppResult<f64> {
+    let rating = conn
+        .query_row("SELECT rating FROM ratings WHERE player_id = ?1", [player_id], |row| row.get(0))
+        .unwrap_or(DEFAULT_RATING);
+    Ok(rating)
+}
+
+fn upsert_rating(conn: &Connection, player_id: &str, rating: f64, updated_at: &str) -> AppResult<()> {
+    conn.execute(
+        "INSERT INTO ratings (player_id, rating, updated_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT(player_id) DO UPDATE SET rating = excluded.rating, updated_at = excluded.updated_at",
+        rusqlite::params![player_id, rating, updated_at],
+    )?;
+    Ok(())
+}
+
+/// Treats every other player's best run on `seed` (within the same tenant) as
+/// a head-to-head comparison against the just-submitted run, and applies a
+/// standard Elo update for each. All comparisons use each player's rating as
+/// of the start of this submission — not re-fetched mid-loop — so the result
+/// doesn't depend on iteration order over opponents. Returns the submitter's
+/// new rating.
+pub fn record_seed_result(
+    conn: &Connection,
+    tenant_id: &str,
+    player_id: &str,
+    seed: i64,
+    score: i64,
+    updated_at: &str,
+) -> AppResult<f64> {
+    let mut stmt = conn.prepare(
+        "SELECT player_id, MAX(score) FROM runs
+         WHERE tenant_id = ?1 AND seed = ?2 AND player_id != ?3
+         GROUP BY player_id",
+    )?;
+    let opponents: Vec<(String, i64)> = stmt
+        .query_map(rusqlite::params![tenant_id, seed, player_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let self_rating_before = current_rating(conn, player_id)?;
+    if opponents.is_empty() {
+        upsert_rating(conn, player_id, self_rating_before, updated_at)?;
+        return Ok(self_rating_before);
+    }
+
+    let mut self_delta = 0.0;
+    for (opponent_id, opponent_score) in &opponents {
+        let opponent_rating = current_rating(conn, opponent_id)?;
+        let actual = match score.cmp(opponent_score) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Less => 0.0,
+        };
+        let expected_self = expected_score(self_rating_before, opponent_rating);
+        self_delta += K_FACTOR * (actual - expected_self);
+
+        let expected_opponent = expected_score(opponent_rating, self_rating_before);
+        let opponent_new = opponent_rating + K_FACTOR * ((1.0 - actual) - expected_opponent);
+        upsert_rating(conn, opponent_id, opponent_new, updated_at)?;
+    }
+
+    let self_new = self_rating_before + self_delta;
+    upsert_rating(conn, player_id, self_new, updated_at)?;
+    Ok(self_new)
+}
+
+/// Fetches the top `limit` players by rating within `tenant_id`.
+pub fn fetch_rating_leaderboard(db: &Db, tenant_id: &str, limit: i64) -> AppResult<Vec<RatingEntry>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT r.player_id, p.display_name, r.rating
+             FROM ratings r JOIN players p ON p.id = r.player_id
+             WHERE p.tenant_id = ?1
+             ORDER BY r.rating DESC LIMIT ?2",
+        )?;
+        let rows: Vec<(String, String, f64)> = stmt
+            .query_map(rusqlite::params![tenant_id, limit], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, (player_id, player_name, rating))| RatingEntry { rank: i as i64 + 1, player_id, player_name, rating })
+            .collect())
+    })
+}
@@ -0,0 +1,187 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use booty_hunt_core::{Regatta, RegattaEvent};
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Derives a track's default seed from `(tenant_id, week_key, track)` alone,
+/// so every server in a deployment agrees on it without coordinating — the
+/// first call for a given week/track always produces the same seed.
+/// Rerolling picks a fresh seed instead of calling this again, since calling
+/// it again would just reproduce the blacklisted one.
+fn derive_seed(tenant_id: &str, week_key: &str, track: &str) -> i64 {
+    let mut hasher = DefaultHasher::new();
+    (tenant_id, week_key, track).hash(&mut hasher);
+    hasher.finish() as i64
+}
+
+fn row_to_regatta(row: &rusqlite::Row) -> rusqlite::Result<Regatta> {
+    Ok(Regatta {
+        id: row.get(0)?,
+        week_key: row.get(1)?,
+        track: row.get(2)?,
+        seed: row.get(3)?,
+        ruleset_id: row.get(4)?,
+        blacklisted: row.get::<_, i64>(5)? != 0,
+        created_at: row.get(6)?,
+    })
+}
+
+/// Returns the current (non-blacklisted) regatta for `week_key`/`track`,
+/// creating it with the hash-derived default seed on first request.
+/// Gets or creates the current regatta for `week_key`/`track`. This is a
+/// select-then-insert, so it goes through `with_tx` rather than
+/// `with_write_conn`: both already serialize against other callers via
+/// `Db`'s single connection mutex, but `with_tx` additionally guarantees
+/// this call's own statements commit or roll back as a unit.
+pub fn current(db: &Db, tenant_id: &str, week_key: &str, track: &str) -> AppResult<Regatta> {
+    db.with_tx(|conn| {
+        let existing = conn
+            .query_row(
+                "SELECT id, week_key, track, seed, ruleset_id, blacklisted, created_at FROM regattas
+                 WHERE tenant_id = ?1 AND week_key = ?2 AND track = ?3 AND blacklisted = 0
+                 ORDER BY created_at DESC, id DESC LIMIT 1",
+                rusqlite::params![tenant_id, week_key, track],
+                row_to_regatta,
+            )
+            .ok();
+        if let Some(regatta) = existing {
+            return Ok(regatta);
+        }
+
+        let id = Uuid::new_v4().to_string();
+        let seed = derive_seed(tenant_id, week_key, track);
+        let created_at = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO regattas (id, tenant_id, week_key, track, seed, ruleset_id, blacklisted, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, NULL, 0, ?6)",
+            rusqlite::params![id, tenant_id, week_key, track, seed, created_at],
+        )?;
+        Ok(Regatta { id, week_key: week_key.to_string(), track: track.to_string(), seed, ruleset_id: None, blacklisted: false, created_at })
+    })
+}
+
+/// Returns every configured track's current regatta for `week_key`,
+/// creating any that haven't been requested yet.
+pub fn list_current(db: &Db, config: &Config, tenant_id: &str, week_key: &str) -> AppResult<Vec<Regatta>> {
+    config.regatta_tracks.iter().map(|track| current(db, tenant_id, week_key, track)).collect()
+}
+
+/// The regattas actually generated for `week_key`, without creating any —
+/// unlike `list_current`, doesn't need `Config::regatta_tracks` since it's
+/// not conjuring up tracks that were never requested. Backs the archived
+/// (long-cacheable) view of a week that's already over; for the current
+/// week, prefer `list_current` so every configured track shows up even
+/// before its first request.
+pub fn list_for_week(db: &Db, tenant_id: &str, week_key: &str) -> AppResult<Vec<Regatta>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, week_key, track, seed, ruleset_id, blacklisted, created_at FROM regattas
+             WHERE tenant_id = ?1 AND week_key = ?2 AND blacklisted = 0
+             ORDER BY track ASC",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![tenant_id, week_key], row_to_regatta)?;
+        rows.collect::<rusqlite::Result<Vec<Regatta>>>().map_err(Into::into)
+    })
+}
+
+/// How many runs have been submitted against `regatta_id` so far — backed
+/// by `idx_runs_regatta_id`, so this is an index range scan rather than a
+/// scan of `runs`. Used by `admin::overview`.
+pub fn participation(db: &Db, tenant_id: &str, regatta_id: &str) -> AppResult<i64> {
+    db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT COUNT(*) FROM runs WHERE tenant_id = ?1 AND regatta_id = ?2",
+            rusqlite::params![tenant_id, regatta_id],
+            |row| row.get(0),
+        )
+        .map_err(Into::into)
+    })
+}
+
+/// Loads a single regatta by id, scoped to `tenant_id`. Takes `&Connection`
+/// directly so it can be called from inside `run_service`'s own `with_write_conn`
+/// closure.
+pub fn get(conn: &rusqlite::Connection, tenant_id: &str, regatta_id: &str) -> AppResult<Regatta> {
+    conn.query_row(
+        "SELECT id, week_key, track, seed, ruleset_id, blacklisted, created_at FROM regattas
+         WHERE tenant_id = ?1 AND id = ?2",
+        rusqlite::params![tenant_id, regatta_id],
+        row_to_regatta,
+    )
+    .map_err(|_| AppError::Validation(format!("unknown regatta: {regatta_id}")))
+}
+
+/// Blacklists the current seed for `week_key`/`track` and rolls a
+/// replacement, recording the swap in the event feed so clients that polled
+/// the old seed can notice it changed. Existing submissions keep the
+/// `regatta_id` (and seed) they actually played — this only affects
+/// `current()` going forward.
+pub fn blacklist_and_reroll(db: &Db, tenant_id: &str, week_key: &str, track: &str) -> AppResult<Regatta> {
+    let previous = current(db, tenant_id, week_key, track)?;
+    db.with_write_conn(|conn| {
+        let updated = conn.execute("UPDATE regattas SET blacklisted = 1 WHERE id = ?1", [&previous.id])?;
+        if updated == 0 {
+            return Err(AppError::NotFound);
+        }
+
+        let new_id = Uuid::new_v4().to_string();
+        // Salt with the blacklisted regatta's own id so a reroll can never
+        // reproduce the seed it's replacing.
+        let mut hasher = DefaultHasher::new();
+        (tenant_id, week_key, track, &previous.id).hash(&mut hasher);
+        let new_seed = hasher.finish() as i64;
+        let created_at = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO regattas (id, tenant_id, week_key, track, seed, ruleset_id, blacklisted, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, ?7)",
+            rusqlite::params![new_id, tenant_id, week_key, track, new_seed, previous.ruleset_id, created_at],
+        )?;
+
+        let event_id = Uuid::new_v4().to_string();
+        let payload = serde_json::json!({
+            "week_key": week_key,
+            "track": track,
+            "blacklisted_seed": previous.seed,
+            "new_seed": new_seed,
+        });
+        conn.execute(
+            "INSERT INTO regatta_events (id, tenant_id, kind, payload, created_at) VALUES (?1, ?2, 'seed_rerolled', ?3, ?4)",
+            rusqlite::params![event_id, tenant_id, payload.to_string(), created_at],
+        )?;
+
+        Ok(Regatta {
+            id: new_id,
+            week_key: week_key.to_string(),
+            track: track.to_string(),
+            seed: new_seed,
+            ruleset_id: previous.ruleset_id,
+            blacklisted: false,
+            created_at,
+        })
+    })
+}
+
+/// The most recent regatta events for `tenant_id`, newest first. The whole
+/// "feed" — there's no push transport yet, so clients poll this.
+pub fn recent_events(db: &Db, tenant_id: &str, limit: i64) -> AppResult<Vec<RegattaEvent>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT kind, payload, created_at FROM regatta_events WHERE tenant_id = ?1 ORDER BY created_at DESC LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![tenant_id, limit], |row| {
+            let payload_str: String = row.get(1)?;
+            Ok((row.get::<_, String>(0)?, payload_str, row.get::<_, String>(2)?))
+        })?;
+        rows.map(|r| {
+            let (kind, payload_str, created_at) = r?;
+            let payload = serde_json::from_str(&payload_str).map_err(|e| AppError::Internal(e.to_string()))?;
+            Ok(RegattaEvent { kind, payload, created_at })
+        })
+        .collect()
+    })
+}
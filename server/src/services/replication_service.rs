@@ -0,0 +1,35 @@
+use std::path::{Path, PathBuf};
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Disaster recovery for self-hosters running a single SQLite file with no
+/// second server to fail over to.
+///
+/// True WAL-frame streaming (litestream-style) would need `sqlite3_wal_hook`,
+/// which rusqlite doesn't expose safely, so this takes the alternative the
+/// request explicitly allows: a periodic consistent snapshot via
+/// `VACUUM INTO`. That statement takes its own read lock and writes a
+/// compacted, checkpoint-free copy of the database in one step, so a snapshot
+/// can never observe a half-written transaction.
+///
+/// Promoting a snapshot to primary: stop the server, copy the newest file
+/// under `replication_dest_dir` over the path in `BOOTY_HUNT_DB_PATH` (or
+/// point `BOOTY_HUNT_DB_PATH` straight at it), then start the server again.
+/// Anything written after that snapshot was taken is lost — this is a
+/// point-in-time backup, not zero-data-loss replication.
+pub fn snapshot(db: &Db, dest_dir: &str) -> AppResult<String> {
+    std::fs::create_dir_all(dest_dir).map_err(|e| AppError::Internal(format!("failed to create replication dest dir: {e}")))?;
+
+    let created_at = chrono::Utc::now().to_rfc3339();
+    let file_name = format!("snapshot-{}.db", created_at.replace([':', '.'], "-"));
+    let dest_path: PathBuf = Path::new(dest_dir).join(&file_name);
+    let dest_path_str = dest_path.to_string_lossy().into_owned();
+
+    db.with_write_conn(|conn| {
+        conn.execute("VACUUM INTO ?1", [&dest_path_str])?;
+        Ok(())
+    })?;
+
+    Ok(dest_path_str)
+}
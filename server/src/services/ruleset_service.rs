@@ -0,0 +1,102 @@
+use booty_hunt_core::{CreateRulesetRequest, Ruleset, RunSubmission};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Creates a ruleset for `tenant_id`. There's no admin auth layer yet (see
+/// `routes::admin`), so this endpoint carries the same caveat: gate it at the
+/// reverse proxy until one exists.
+pub fn create(db: &Db, tenant_id: &str, req: CreateRulesetRequest) -> AppResult<Ruleset> {
+    let id = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO rulesets (id, tenant_id, name, require_ghost_tape, ship_class_lock, doctrine_lock, omen_override, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            rusqlite::params![
+                id,
+                tenant_id,
+                req.name,
+                req.require_ghost_tape as i64,
+                req.ship_class_lock,
+                req.doctrine_lock,
+                req.omen_override,
+                created_at,
+            ],
+        )?;
+        Ok(())
+    })?;
+    Ok(Ruleset {
+        id,
+        name: req.name,
+        require_ghost_tape: req.require_ghost_tape,
+        ship_class_lock: req.ship_class_lock,
+        doctrine_lock: req.doctrine_lock,
+        omen_override: req.omen_override,
+        created_at,
+    })
+}
+
+pub fn list(db: &Db, tenant_id: &str) -> AppResult<Vec<Ruleset>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, name, require_ghost_tape, ship_class_lock, doctrine_lock, omen_override, created_at
+             FROM rulesets WHERE tenant_id = ?1 ORDER BY created_at ASC",
+        )?;
+        let rows = stmt.query_map([tenant_id], row_to_ruleset)?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    })
+}
+
+/// Loads a single ruleset by id, scoped to `tenant_id` so one tenant can't
+/// submit against another's ruleset. Takes `&Connection` directly so it can
+/// be called from inside `run_service`'s own `with_write_conn` closure.
+pub fn get(conn: &Connection, tenant_id: &str, ruleset_id: &str) -> AppResult<Ruleset> {
+    conn.query_row(
+        "SELECT id, name, require_ghost_tape, ship_class_lock, doctrine_lock, omen_override, created_at
+         FROM rulesets WHERE tenant_id = ?1 AND id = ?2",
+        rusqlite::params![tenant_id, ruleset_id],
+        row_to_ruleset,
+    )
+    .map_err(|_| AppError::Validation(format!("unknown ruleset: {ruleset_id}")))
+}
+
+fn row_to_ruleset(row: &rusqlite::Row) -> rusqlite::Result<Ruleset> {
+    Ok(Ruleset {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        require_ghost_tape: row.get::<_, i64>(2)? != 0,
+        ship_class_lock: row.get(3)?,
+        doctrine_lock: row.get(4)?,
+        omen_override: row.get(5)?,
+        created_at: row.get(6)?,
+    })
+}
+
+/// Rejects a submission that doesn't satisfy `ruleset`'s constraints. The
+/// omen override isn't checked here — nothing records which omen a run was
+/// played under yet, so it stays advisory-only until that lands.
+pub fn validate_submission(ruleset: &Ruleset, submission: &RunSubmission) -> AppResult<()> {
+    if ruleset.require_ghost_tape && submission.ghost_tape.is_none() {
+        return Err(AppError::Validation(format!("ruleset {} requires a ghost tape", ruleset.id)));
+    }
+    if let Some(locked_class) = &ruleset.ship_class_lock {
+        if &submission.ship_class != locked_class {
+            return Err(AppError::Validation(format!(
+                "ruleset {} is locked to ship class {locked_class}",
+                ruleset.id
+            )));
+        }
+    }
+    if let Some(locked_doctrine) = &ruleset.doctrine_lock {
+        if &submission.doctrine_id != locked_doctrine {
+            return Err(AppError::Validation(format!(
+                "ruleset {} is locked to doctrine {locked_doctrine}",
+                ruleset.id
+            )));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Caps how much of a player's display name renders on the card, so a
+/// pathologically long name (display names aren't length-validated at
+/// registration) can't stretch the card past the fixed layout this SVG is
+/// hand-laid-out for.
+const MAX_PLAYER_NAME_CHARS: usize = 24;
+
+pub(crate) fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// The fields `render_svg` and `routes::og::run_unfurl`'s Open Graph tags
+/// both need — one query instead of two near-identical ones.
+pub struct RunCardSummary {
+    pub player_name: String,
+    pub ship_class: String,
+    pub score: i64,
+    pub victory: bool,
+}
+
+pub fn fetch_summary(db: &Db, tenant_id: &str, run_id: &str) -> AppResult<RunCardSummary> {
+    let (player_name, ship_class, score, victory): (String, String, i64, i64) = db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT p.display_name, r.ship_class, r.score, r.victory FROM runs r JOIN players p ON p.id = r.player_id WHERE r.id = ?1 AND r.tenant_id = ?2",
+            rusqlite::params![run_id, tenant_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .map_err(|_| AppError::NotFound)
+    })?;
+    Ok(RunCardSummary { player_name, ship_class, score, victory: victory != 0 })
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Renders a shareable score card for `run_id` as a plain SVG document, for
+/// Discord/social unfurls. The card's `run_id` and underlying data never
+/// change after submission (a hidden run 404s upstream of this like any
+/// other run lookup), so the caller can treat the returned ETag as
+/// permanently valid.
+pub fn render_svg(db: &Db, tenant_id: &str, run_id: &str) -> AppResult<(String, String)> {
+    let summary = fetch_summary(db, tenant_id, run_id)?;
+    let omen_ids_json: String = db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT modifier_omen_ids FROM runs WHERE id = ?1 AND tenant_id = ?2",
+            rusqlite::params![run_id, tenant_id],
+            |row| row.get(0),
+        )
+        .map_err(|_| AppError::NotFound)
+    })?;
+
+    let omen_ids: Vec<String> = serde_json::from_str(&omen_ids_json)
+        .map_err(|e| AppError::Internal(format!("stored runs.modifier_omen_ids is invalid: {e}")))?;
+    let omen_line = match omen_ids.first() {
+        Some(omen_id) => format!("Omen: {}", escape_xml(omen_id)),
+        None => String::new(),
+    };
+    let player_name = escape_xml(&truncate(&summary.player_name, MAX_PLAYER_NAME_CHARS));
+    let ship_class = escape_xml(&summary.ship_class);
+    let score = summary.score;
+    let result_line = if summary.victory { "Victory" } else { "Fallen" };
+
+    let svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="600" height="315" viewBox="0 0 600 315">
+  <rect width="600" height="315" fill="#0b1a2b"/>
+  <text x="30" y="60" font-family="serif" font-size="32" fill="#f4d58d">{player_name}</text>
+  <text x="30" y="110" font-family="serif" font-size="24" fill="#e8e8e8">{ship_class}</text>
+  <text x="30" y="160" font-family="serif" font-size="48" fill="#ffffff">{score}</text>
+  <text x="30" y="200" font-family="serif" font-size="20" fill="#a0c4d8">{result_line}</text>
+  <text x="30" y="230" font-family="serif" font-size="16" fill="#7f9db0">{omen_line}</text>
+</svg>"##
+    );
+
+    let etag = format!("\"{run_id}\"");
+    Ok((svg, etag))
+}
@@ -0,0 +1,844 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use booty_hunt_core::{AttachGhostTapeRequest, AttachGhostTapeResult, LeaderboardEntry, PlayerRunSummary, RunSubmission, RunSubmissionResult};
+use rusqlite::OptionalExtension;
+use uuid::Uuid;
+
+use crate::blob::BlobStore;
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::AppResult;
+use crate::hooks::{self, RunHook};
+use crate::notifications::{NotificationEvent, NotificationProvider};
+use crate::pagination;
+use crate::scoring;
+use crate::services::{
+    appeal_service, community_event_service, cosmetics_service, ghost_signed_url_service, goal_service, kudos_service,
+    nonce_service, notification_service, overtake_service, progression_service, raid_service, rating_service,
+    regatta_service, ruleset_service, signal_fire_service, stats_service, suspicion_service, tuning_service,
+};
+
+/// No 15-wave run has ever been cleared in under two minutes, and the
+/// speedrun leaderboard falls apart the moment one implausible outlier gets
+/// crowned fastest. `time_played` is reported in seconds.
+const MIN_VICTORY_TIME_PLAYED_SECS: i64 = 120;
+/// A generous ceiling — past this a victory is more likely a corrupted or
+/// forged submission than a genuinely slow, careful clear.
+const MAX_VICTORY_TIME_PLAYED_SECS: i64 = 6 * 60 * 60;
+
+/// Checks each numeric submission field other than `score` (checked
+/// separately, per-wave, by `hooks::anti_cheat::ImplausibleScoreHook` —
+/// always present in `run_hooks` via `hooks::from_config`) against its
+/// configured plausible range, returning every violation found rather than
+/// stopping at the first — `submit_run` only needs the first one, but
+/// `validate_dry_run` wants the whole list in one round trip.
+fn field_bounds_violations(config: &Config, submission: &RunSubmission) -> Vec<booty_hunt_core::ValidationViolation> {
+    use booty_hunt_core::ValidationViolation;
+    let mut violations = Vec::new();
+    let mut check = |field: &str, value: i64, max: i64| {
+        if value < 0 {
+            violations.push(ValidationViolation { field: field.into(), message: format!("{field} must not be negative: {value}") });
+        } else if value > max {
+            violations.push(ValidationViolation { field: field.into(), message: format!("{field} {value} exceeds configured maximum {max}") });
+        }
+    };
+    check("waves", submission.waves, config.max_submission_waves);
+    check("damage_dealt", submission.damage_dealt, config.max_submission_damage_dealt);
+    check("max_combo", submission.max_combo, config.max_submission_combo);
+    check("time_played", submission.time_played, config.max_submission_time_played_secs);
+    check("max_heat", submission.max_heat, config.max_submission_heat);
+    violations
+}
+
+/// Current ISO week key, e.g. `2026-W32`. Regattas and leaderboards are
+/// bucketed by this string.
+pub fn current_week_key() -> String {
+    use chrono::Datelike;
+    let now = chrono::Utc::now();
+    format!("{}-W{:02}", now.year(), now.iso_week().week())
+}
+
+/// The week key for seven days ago. Used by the scheduler to generate the
+/// digest for a week that just ended without waiting for a client to
+/// request it.
+pub fn previous_week_key() -> String {
+    use chrono::Datelike;
+    let a_week_ago = chrono::Utc::now() - chrono::Duration::days(7);
+    format!("{}-W{:02}", a_week_ago.year(), a_week_ago.iso_week().week())
+}
+
+/// The instant the current ISO week rolls over (Monday 00:00 UTC). Used by
+/// the scheduler to fire the T-1h regatta countdown announcement.
+pub fn week_end_utc() -> chrono::DateTime<chrono::Utc> {
+    use chrono::{Datelike, Duration};
+    let now = chrono::Utc::now();
+    let days_since_monday = now.weekday().num_days_from_monday() as i64;
+    let start_of_week = (now.date_naive() - Duration::days(days_since_monday)).and_hms_opt(0, 0, 0).unwrap().and_utc();
+    start_of_week + Duration::days(7)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Fingerprints a submission over the fields that identify "the same run
+/// reported twice" — a double-clicked submit button, or a client retrying a
+/// request it never saw the response to. Two submissions with the same
+/// fingerprint within `Config::duplicate_submission_window_secs` are treated
+/// as one run; the tape hash is folded in (rather than the tape itself) so
+/// two submissions of the identical run always fingerprint the same
+/// regardless of tape presence/absence quirks in retries.
+fn submission_fingerprint(submission: &RunSubmission, tape_sha256: Option<&str>) -> String {
+    sha256_hex(
+        format!(
+            "{}|{}|{}|{}|{}|{}",
+            submission.seed,
+            submission.score,
+            submission.waves,
+            submission.time_played,
+            tape_sha256.unwrap_or(""),
+            submission.player_id,
+        )
+        .as_bytes(),
+    )
+}
+
+/// Checks a decoded ghost tape against a client-claimed checksum, if one was
+/// given, and returns the tape's actual checksum either way — computed once
+/// here so `submit_run` and `attach_ghost_tape` always store the same
+/// server-verified value rather than trusting the client's claim outright.
+fn verify_and_hash_tape(tape: &[u8], claimed_sha256: Option<&str>) -> AppResult<String> {
+    let actual = sha256_hex(tape);
+    if let Some(claimed) = claimed_sha256 {
+        if claimed.to_ascii_lowercase() != actual {
+            return Err(crate::error::AppError::Validation(
+                "ghost_tape_sha256 does not match the decoded tape — upload looks truncated or corrupted".into(),
+            ));
+        }
+    }
+    Ok(actual)
+}
+
+/// The submission pipeline's plugin/extension points, bundled together
+/// since every caller sources all three straight off `AppState` — same
+/// reasoning as `leaderboard_service::LeaderboardFilters`.
+pub struct RunPipelineExtensions<'a> {
+    pub run_hooks: &'a [Box<dyn RunHook>],
+    pub tape_blob_store: Option<&'a Arc<dyn BlobStore>>,
+    pub notification_providers: &'a [Box<dyn NotificationProvider>],
+}
+
+pub async fn submit_run(
+    db: &Db,
+    config: &Config,
+    submission: RunSubmission,
+    region: Option<String>,
+    tenant_id: &str,
+    extensions: RunPipelineExtensions<'_>,
+) -> AppResult<RunSubmissionResult> {
+    let RunPipelineExtensions { run_hooks, tape_blob_store, notification_providers } = extensions;
+    if submission.victory && !(MIN_VICTORY_TIME_PLAYED_SECS..=MAX_VICTORY_TIME_PLAYED_SECS).contains(&submission.time_played) {
+        return Err(crate::error::AppError::Validation(format!(
+            "victory run reports implausible time_played: {}s",
+            submission.time_played
+        )));
+    }
+    if let Some(violation) = field_bounds_violations(config, &submission).into_iter().next() {
+        return Err(crate::error::AppError::Validation(violation.message));
+    }
+    if config.submission_nonce_required && submission.submission_nonce.is_none() {
+        return Err(crate::error::AppError::Validation(
+            "submission_nonce is required".into(),
+        ));
+    }
+
+    hooks::run_pre_validate(run_hooks, &submission).await?;
+
+    let run_id = Uuid::new_v4().to_string();
+    let week_key = current_week_key();
+    let created_at = chrono::Utc::now().to_rfc3339();
+    let tape = submission
+        .ghost_tape
+        .as_deref()
+        .map(|b64| base64::Engine::decode(&base64::engine::general_purpose::STANDARD, b64))
+        .transpose()
+        .map_err(|e| crate::error::AppError::Validation(format!("invalid ghost_tape: {e}")))?;
+    let ghost_tape_sha256 =
+        tape.as_deref().map(|bytes| verify_and_hash_tape(bytes, submission.ghost_tape_sha256.as_deref())).transpose()?;
+    let fingerprint = submission_fingerprint(&submission, ghost_tape_sha256.as_deref());
+
+    let score_mismatch = if config.recompute_scores {
+        match tape.as_deref().map(scoring::recompute_score) {
+            Some(Ok(recomputed)) if recomputed != submission.score => {
+                tracing::warn!(run_score = submission.score, recomputed, "score mismatch detected");
+                true
+            }
+            _ => false,
+        }
+    } else {
+        false
+    };
+    // If external tape storage is configured, write the tape there and keep
+    // only a reference in the row — otherwise fall back to the historical
+    // inline BLOB. Writing the blob before the DB insert means a failed
+    // insert can only leave an orphaned blob, never a dangling reference.
+    let (inline_tape, ghost_tape_ref) = match (&tape, tape_blob_store) {
+        (Some(bytes), Some(store)) => {
+            store
+                .put(&run_id, bytes.clone())
+                .await
+                .map_err(|e| crate::error::AppError::Internal(format!("failed to store ghost tape: {e}")))?;
+            (None, Some(run_id.clone()))
+        }
+        _ => (tape.clone(), None),
+    };
+
+    let normalized_score = scoring::normalize_score(config, &submission.ship_class, submission.score);
+    let splits_json = submission
+        .splits
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| crate::error::AppError::Internal(format!("failed to serialize splits: {e}")))?;
+
+    // Runs through `with_tx` rather than `with_write_conn`: the insert below
+    // and the derived-state writes around it (overtake tracking, raid
+    // contribution, goal evaluation, rating, progression, the rank query
+    // itself) need to land as a unit, since a later failure shouldn't leave
+    // some of them committed and the insert missing (or vice versa).
+    let (result, top_entry, displaced, completed_goals) = db.with_tx(|conn| {
+        let duplicate_cutoff =
+            (chrono::Utc::now() - chrono::Duration::seconds(config.duplicate_submission_window_secs)).to_rfc3339();
+        let existing_run_id: Option<String> = conn
+            .query_row(
+                "SELECT id FROM runs WHERE tenant_id = ?1 AND fingerprint = ?2 AND created_at >= ?3 ORDER BY created_at DESC LIMIT 1",
+                rusqlite::params![tenant_id, fingerprint, duplicate_cutoff],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if let Some(existing_run_id) = existing_run_id {
+            return Err(crate::error::AppError::Duplicate(existing_run_id));
+        }
+
+        let banned: bool = conn
+            .query_row("SELECT banned FROM players WHERE id = ?1", [&submission.player_id], |row| row.get::<_, i64>(0))
+            .optional()?
+            .map(|v| v != 0)
+            .unwrap_or(false);
+        if banned {
+            return Err(crate::error::AppError::Validation("player is banned from submitting runs".into()));
+        }
+
+        if let Some(nonce) = &submission.submission_nonce {
+            nonce_service::consume(conn, tenant_id, &submission.player_id, submission.seed, nonce)?;
+        }
+
+        if let Some(ruleset_id) = &submission.ruleset_id {
+            let ruleset = ruleset_service::get(conn, tenant_id, ruleset_id)?;
+            ruleset_service::validate_submission(&ruleset, &submission)?;
+        }
+        if let Some(regatta_id) = &submission.regatta_id {
+            // Existence check only — a regatta's own ruleset (if any) is
+            // applied via `ruleset_id` on the submission like any other.
+            regatta_service::get(conn, tenant_id, regatta_id)?;
+        }
+
+        // Must run before this submission's own INSERT below — it needs to
+        // see the leaderboard as it stood immediately before this run to
+        // find whoever is about to be pushed out of the top N.
+        let displaced = overtake_service::find_and_record(
+            conn,
+            tenant_id,
+            &week_key,
+            config.overtake_notify_top_n,
+            &submission.player_id,
+            submission.score,
+            &created_at,
+        )?;
+
+        // Effective modifier set in force right now, frozen onto the row so
+        // historical leaderboards stay interpretable after omens/events/
+        // tuning move on — see `booty_hunt_core::RunDetail::modifier_omen_ids`.
+        let modifier_omen_ids_json = serde_json::to_string(&config.omens)
+            .map_err(|e| crate::error::AppError::Internal(format!("failed to serialize active omens: {e}")))?;
+        let modifier_event_ids = community_event_service::active_ids_conn(conn, tenant_id, &created_at)?;
+        let modifier_event_ids_json = serde_json::to_string(&modifier_event_ids)
+            .map_err(|e| crate::error::AppError::Internal(format!("failed to serialize active event ids: {e}")))?;
+        let modifier_tuning_version = tuning_service::current_version_conn(conn, tenant_id)?;
+
+        conn.execute(
+            "INSERT INTO runs (id, player_id, week_key, seed, ship_class, doctrine_id, score, waves, damage_dealt, max_combo, time_played, max_heat, victory, ghost_tape, created_at, score_mismatch, region, tenant_id, normalized_score, splits, ruleset_id, regatta_id, ghost_tape_ref, ghost_tape_sha256, fingerprint, modifier_omen_ids, modifier_event_ids, modifier_tuning_version)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28)",
+            rusqlite::params![
+                run_id,
+                submission.player_id,
+                week_key,
+                submission.seed,
+                submission.ship_class,
+                submission.doctrine_id,
+                submission.score,
+                submission.waves,
+                submission.damage_dealt,
+                submission.max_combo,
+                submission.time_played,
+                submission.max_heat,
+                submission.victory as i64,
+                inline_tape,
+                created_at,
+                score_mismatch as i64,
+                region,
+                tenant_id,
+                normalized_score,
+                splits_json,
+                submission.ruleset_id,
+                submission.regatta_id,
+                ghost_tape_ref,
+                ghost_tape_sha256,
+                fingerprint,
+                modifier_omen_ids_json,
+                modifier_event_ids_json,
+                modifier_tuning_version,
+            ],
+        )?;
+
+        let canary_hit = suspicion_service::detect_canary(config, submission.seed, submission.score);
+        suspicion_service::compute_and_record(
+            conn,
+            config,
+            tenant_id,
+            &run_id,
+            &submission.player_id,
+            submission.seed,
+            score_mismatch,
+            canary_hit,
+            &created_at,
+        )?;
+
+        community_event_service::record_participation(conn, tenant_id, &submission.player_id, &run_id, &created_at)?;
+
+        if submission.seed == config.raid_seed {
+            raid_service::record_contribution(conn, tenant_id, &week_key, &submission.player_id, submission.damage_dealt)?;
+        }
+
+        let completed_goals = goal_service::evaluate_for_submission(conn, tenant_id, &submission.player_id, &submission)?;
+
+        if let Some(raced_run_id) = &submission.raced_run_id {
+            let raced_score: i64 = conn
+                .query_row("SELECT score FROM runs WHERE id = ?1", [raced_run_id], |row| row.get(0))
+                .map_err(|_| crate::error::AppError::Validation(format!("raced_run_id {raced_run_id} does not exist")))?;
+            conn.execute(
+                "INSERT INTO run_ancestry (run_id, raced_run_id, beat_ghost, created_at) VALUES (?1, ?2, ?3, ?4)",
+                rusqlite::params![run_id, raced_run_id, submission.score > raced_score, created_at],
+            )?;
+        }
+
+        // A signal fire minted against this run before it was uploaded (or
+        // before this insert landed) sits `Pending` until now — resolve it
+        // with the anti-cheat result this same submission just computed.
+        signal_fire_service::verify_pending_for_run(conn, tenant_id, &run_id, !score_mismatch)?;
+        stats_service::record_submission(conn, tenant_id, &submission.player_id, submission.victory, &created_at)?;
+
+        let rank: i64 = conn.query_row(
+            "SELECT COUNT(*) + 1 FROM runs WHERE tenant_id = ?1 AND week_key = ?2 AND score > ?3",
+            rusqlite::params![tenant_id, week_key, submission.score],
+            |row| row.get(0),
+        )?;
+
+        rating_service::record_seed_result(conn, tenant_id, &submission.player_id, submission.seed, submission.score, &created_at)?;
+
+        let xp_gain = progression_service::xp_for_run(submission.waves, submission.score, submission.victory);
+        progression_service::record_run(conn, tenant_id, &submission.player_id, &config.current_season_id, xp_gain)?;
+
+        let consistency_token = db.bump_write_version();
+
+        let top_entry = if rank == 1 {
+            let player_name: String =
+                conn.query_row("SELECT display_name FROM players WHERE id = ?1", [&submission.player_id], |row| row.get(0))?;
+            let equipped_cosmetics: HashMap<String, String> = cosmetics_service::equipped_items(conn, &submission.player_id)?;
+            let kudos_count = kudos_service::count(conn, &run_id)?;
+            Some(LeaderboardEntry {
+                rank,
+                run_id: run_id.clone(),
+                player_id: submission.player_id.clone(),
+                player_name,
+                ship_class: submission.ship_class.clone(),
+                score: submission.score,
+                victory: submission.victory,
+                created_at: created_at.clone(),
+                equipped_cosmetics,
+                region: region.clone(),
+                kudos_count,
+                normalized_score,
+            })
+        } else {
+            None
+        };
+
+        let receipt = crate::receipt::sign(&config.receipt_signing_secret, &run_id, submission.score, &week_key);
+
+        Ok((
+            RunSubmissionResult { run_id: run_id.clone(), rank, week_key: week_key.clone(), score_mismatch, consistency_token, receipt },
+            top_entry,
+            displaced,
+            completed_goals,
+        ))
+    })?;
+
+    hooks::run_post_insert(run_hooks, &submission, &result).await;
+    if let Some(entry) = &top_entry {
+        hooks::run_on_leaderboard_change(run_hooks, entry).await;
+    }
+    if let Some(displaced) = displaced {
+        let event = NotificationEvent::OvertakenInTop { rank: displaced.previous_rank, week_key: result.week_key.clone() };
+        if let Err(err) = notification_service::dispatch(db, notification_providers, &displaced.player_id, event).await {
+            tracing::warn!(%err, player_id = displaced.player_id, "overtake notification dispatch failed");
+        }
+    }
+    for goal in completed_goals {
+        let event = NotificationEvent::GoalCompleted { goal_type: goal.goal_type, target: goal.target };
+        if let Err(err) = notification_service::dispatch(db, notification_providers, &submission.player_id, event).await {
+            tracing::warn!(%err, player_id = submission.player_id, "goal completion notification dispatch failed");
+        }
+    }
+
+    Ok(result)
+}
+
+/// Attaches a ghost tape to a run that was already accepted without one,
+/// for a client whose tape upload failed (or was skipped for speed) at
+/// submission time. Authenticated by the run's own `receipt` rather than a
+/// player session, matching `receipts::verify` — anyone holding the receipt
+/// `submit_run` handed back for this run can attach its tape. Only works
+/// within `config.ghost_attach_window_secs` of submission and only once;
+/// a run that already has a tape must not have it silently replaced.
+pub async fn attach_ghost_tape(
+    db: &Db,
+    config: &Config,
+    tenant_id: &str,
+    tape_blob_store: Option<&Arc<dyn BlobStore>>,
+    run_id: &str,
+    req: &AttachGhostTapeRequest,
+) -> AppResult<AttachGhostTapeResult> {
+    let AttachGhostTapeRequest { receipt, ghost_tape: ghost_tape_b64, ghost_tape_sha256: claimed_sha256 } = req;
+    let claimed_sha256 = claimed_sha256.as_deref();
+    let (score, week_key, created_at, has_tape): (i64, String, String, bool) = db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT score, week_key, created_at, (ghost_tape IS NOT NULL OR ghost_tape_ref IS NOT NULL) FROM runs
+             WHERE id = ?1 AND tenant_id = ?2",
+            rusqlite::params![run_id, tenant_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get::<_, i64>(3)? != 0)),
+        )
+        .map_err(|_| crate::error::AppError::NotFound)
+    })?;
+
+    if !crate::receipt::verify(&config.receipt_signing_secret, run_id, score, &week_key, receipt) {
+        return Err(crate::error::AppError::Validation("invalid receipt".into()));
+    }
+    if has_tape {
+        return Err(crate::error::AppError::Validation("run already has a ghost tape attached".into()));
+    }
+
+    let submitted_at = chrono::DateTime::parse_from_rfc3339(&created_at)
+        .map_err(|e| crate::error::AppError::Internal(format!("stored run has an unparseable created_at: {e}")))?;
+    let deadline = submitted_at + chrono::Duration::seconds(config.ghost_attach_window_secs as i64);
+    if chrono::Utc::now() > deadline {
+        return Err(crate::error::AppError::Validation("ghost tape attach window has expired".into()));
+    }
+
+    let tape = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, ghost_tape_b64)
+        .map_err(|e| crate::error::AppError::Validation(format!("invalid ghost_tape: {e}")))?;
+    let ghost_tape_sha256 = verify_and_hash_tape(&tape, claimed_sha256)?;
+
+    let score_mismatch = if config.recompute_scores {
+        match scoring::recompute_score(&tape) {
+            Ok(recomputed) => recomputed != score,
+            Err(_) => false,
+        }
+    } else {
+        false
+    };
+
+    let (inline_tape, ghost_tape_ref) = match tape_blob_store {
+        Some(store) => {
+            store
+                .put(run_id, tape)
+                .await
+                .map_err(|e| crate::error::AppError::Internal(format!("failed to store ghost tape: {e}")))?;
+            (None, Some(run_id.to_string()))
+        }
+        None => (Some(tape), None),
+    };
+
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "UPDATE runs SET ghost_tape = ?1, ghost_tape_ref = ?2, score_mismatch = ?3, ghost_tape_sha256 = ?4 WHERE id = ?5 AND tenant_id = ?6",
+            rusqlite::params![inline_tape, ghost_tape_ref, score_mismatch as i64, ghost_tape_sha256, run_id, tenant_id],
+        )?;
+        signal_fire_service::verify_pending_for_run(conn, tenant_id, run_id, !score_mismatch)
+    })?;
+
+    Ok(AttachGhostTapeResult { score_mismatch })
+}
+
+/// Runs `submit_run`'s validation checks against `submission` without
+/// inserting anything, collecting every violation instead of stopping at the
+/// first one — mod/tooling developers testing an export want the whole list
+/// in one round trip, not one HTTP call per fix.
+pub fn validate_dry_run(db: &Db, config: &Config, tenant_id: &str, submission: &RunSubmission) -> AppResult<booty_hunt_core::ValidationReport> {
+    use booty_hunt_core::ValidationViolation;
+
+    let mut violations = Vec::new();
+
+    if submission.victory && !(MIN_VICTORY_TIME_PLAYED_SECS..=MAX_VICTORY_TIME_PLAYED_SECS).contains(&submission.time_played) {
+        violations.push(ValidationViolation {
+            field: "time_played".into(),
+            message: format!("victory run reports implausible time_played: {}s", submission.time_played),
+        });
+    }
+    violations.extend(field_bounds_violations(config, submission));
+    match &submission.submission_nonce {
+        None if config.submission_nonce_required => {
+            violations.push(ValidationViolation { field: "submission_nonce".into(), message: "submission_nonce is required".into() });
+        }
+        Some(nonce) => {
+            if let Err(e) = nonce_service::peek(db, tenant_id, &submission.player_id, submission.seed, nonce) {
+                violations.push(ValidationViolation { field: "submission_nonce".into(), message: e.to_string() });
+            }
+        }
+        None => {}
+    }
+
+    let tape = match submission
+        .ghost_tape
+        .as_deref()
+        .map(|b64| base64::Engine::decode(&base64::engine::general_purpose::STANDARD, b64))
+    {
+        Some(Ok(bytes)) => Some(bytes),
+        Some(Err(e)) => {
+            violations.push(ValidationViolation { field: "ghost_tape".into(), message: format!("invalid ghost_tape: {e}") });
+            None
+        }
+        None => None,
+    };
+
+    if let (Some(bytes), Some(claimed)) = (&tape, submission.ghost_tape_sha256.as_deref()) {
+        if let Err(e) = verify_and_hash_tape(bytes, Some(claimed)) {
+            violations.push(ValidationViolation { field: "ghost_tape_sha256".into(), message: e.to_string() });
+        }
+    }
+
+    if config.recompute_scores {
+        if let Some(bytes) = &tape {
+            match scoring::recompute_score(bytes) {
+                Ok(recomputed) if recomputed != submission.score => violations.push(ValidationViolation {
+                    field: "score".into(),
+                    message: format!("reported score {} does not match recomputed score {recomputed}", submission.score),
+                }),
+                Err(e) => violations.push(ValidationViolation { field: "ghost_tape".into(), message: e.to_string() }),
+                _ => {}
+            }
+        }
+    }
+
+    db.with_read_conn(|conn| {
+        if let Some(ruleset_id) = &submission.ruleset_id {
+            match ruleset_service::get(conn, tenant_id, ruleset_id) {
+                Ok(ruleset) => {
+                    if let Err(e) = ruleset_service::validate_submission(&ruleset, submission) {
+                        violations.push(ValidationViolation { field: "ruleset_id".into(), message: e.to_string() });
+                    }
+                }
+                Err(e) => violations.push(ValidationViolation { field: "ruleset_id".into(), message: e.to_string() }),
+            }
+        }
+        if let Some(regatta_id) = &submission.regatta_id {
+            if let Err(e) = regatta_service::get(conn, tenant_id, regatta_id) {
+                violations.push(ValidationViolation { field: "regatta_id".into(), message: e.to_string() });
+            }
+        }
+        if let Some(raced_run_id) = &submission.raced_run_id {
+            let exists: bool =
+                conn.query_row("SELECT EXISTS(SELECT 1 FROM runs WHERE id = ?1)", [raced_run_id], |row| row.get(0))?;
+            if !exists {
+                violations.push(ValidationViolation {
+                    field: "raced_run_id".into(),
+                    message: format!("raced_run_id {raced_run_id} does not exist"),
+                });
+            }
+        }
+        Ok(())
+    })?;
+
+    Ok(booty_hunt_core::ValidationReport { valid: violations.is_empty(), violations })
+}
+
+/// Lists a single player's own runs, newest first, cursor-paginated per
+/// `pagination`. Backs the read-only API-key-scoped endpoint community tools
+/// use to build player overlays.
+pub fn list_for_player(db: &Db, player_id: &str, limit: i64, cursor: Option<&str>) -> AppResult<booty_hunt_core::Page<PlayerRunSummary>> {
+    let before = cursor.map(pagination::decode_cursor).transpose()?;
+    db.with_read_conn(|conn| {
+        let total: i64 = conn.query_row("SELECT COUNT(*) FROM runs WHERE player_id = ?1", [player_id], |row| row.get(0))?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, week_key, ship_class, score, victory, created_at
+             FROM runs WHERE player_id = ?1
+             AND (?2 IS NULL OR (created_at, id) < (?2, ?3))
+             ORDER BY created_at DESC, id DESC LIMIT ?4",
+        )?;
+        let (before_created_at, before_id) = match &before {
+            Some((created_at, id)) => (Some(created_at.as_str()), Some(id.as_str())),
+            None => (None, None),
+        };
+        let rows = stmt
+            .query_map(rusqlite::params![player_id, before_created_at, before_id, limit], |row| {
+                Ok(PlayerRunSummary {
+                    run_id: row.get(0)?,
+                    week_key: row.get(1)?,
+                    ship_class: row.get(2)?,
+                    score: row.get(3)?,
+                    victory: row.get::<_, i64>(4)? != 0,
+                    created_at: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let next_cursor = match rows.last() {
+            Some(last) if rows.len() as i64 == limit => Some(pagination::encode_cursor(&last.created_at, &last.run_id)),
+            _ => None,
+        };
+        Ok(booty_hunt_core::Page { items: rows, next_cursor, total })
+    })
+}
+
+/// One run's public detail view, including its ghost-race ancestry —
+/// whether it raced a ghost itself, and how many other runs have raced
+/// *this* run's ghost since. Backs the beat-the-ghost meta-game's run page.
+/// Also issues a fresh short-lived signed URL for the ghost tape download on
+/// every call — see `ghost_signed_url_service::issue` — so the byte-serving
+/// can eventually move behind a CDN or blob storage without this endpoint
+/// needing to change.
+pub fn detail(db: &Db, config: &Config, tenant_id: &str, run_id: &str) -> AppResult<booty_hunt_core::RunDetail> {
+    let ghost_url = ghost_signed_url_service::issue(config, run_id);
+    db.with_read_conn(|conn| {
+        let (player_id, ship_class, doctrine_id, score, victory, created_at, modifier_omen_ids_json, modifier_event_ids_json, modifier_tuning_version): (
+            String,
+            String,
+            String,
+            i64,
+            i64,
+            String,
+            String,
+            String,
+            i64,
+        ) = conn
+            .query_row(
+                "SELECT player_id, ship_class, doctrine_id, score, victory, created_at, modifier_omen_ids, modifier_event_ids, modifier_tuning_version FROM runs WHERE id = ?1 AND tenant_id = ?2",
+                rusqlite::params![run_id, tenant_id],
+                |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get(5)?,
+                        row.get(6)?,
+                        row.get(7)?,
+                        row.get(8)?,
+                    ))
+                },
+            )
+            .map_err(|_| crate::error::AppError::NotFound)?;
+        let modifier_omen_ids: Vec<String> = serde_json::from_str(&modifier_omen_ids_json)
+            .map_err(|e| crate::error::AppError::Internal(format!("stored runs.modifier_omen_ids is invalid: {e}")))?;
+        let modifier_event_ids: Vec<String> = serde_json::from_str(&modifier_event_ids_json)
+            .map_err(|e| crate::error::AppError::Internal(format!("stored runs.modifier_event_ids is invalid: {e}")))?;
+
+        let (raced_run_id, beat_ghost): (Option<String>, Option<i64>) = conn
+            .query_row("SELECT raced_run_id, beat_ghost FROM run_ancestry WHERE run_id = ?1", [run_id], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .optional()?
+            .unwrap_or((None, None));
+
+        let ghost_races_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM run_ancestry WHERE raced_run_id = ?1", [run_id], |row| row.get(0))?;
+        let ghost_beats_count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM run_ancestry WHERE raced_run_id = ?1 AND beat_ghost = 1",
+            [run_id],
+            |row| row.get(0),
+        )?;
+
+        let appeal = appeal_service::get_for_run(conn, run_id)?;
+
+        Ok(booty_hunt_core::RunDetail {
+            run_id: run_id.to_string(),
+            player_id,
+            ship_class,
+            doctrine_id,
+            score,
+            victory: victory != 0,
+            created_at,
+            raced_run_id,
+            beat_ghost: beat_ghost.map(|v| v != 0),
+            ghost_races_count,
+            ghost_beats_count,
+            appeal,
+            modifier_omen_ids,
+            modifier_event_ids,
+            modifier_tuning_version,
+            ghost_url,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{insert_player, test_db};
+
+    const TENANT: &str = "tenant-a";
+
+    fn submission(player_id: &str, seed: i64, score: i64) -> RunSubmission {
+        RunSubmission {
+            player_id: player_id.into(),
+            seed,
+            ship_class: "sloop".into(),
+            doctrine_id: "boarding".into(),
+            score,
+            waves: 10,
+            damage_dealt: 5_000,
+            max_combo: 20,
+            time_played: 600,
+            max_heat: 100,
+            victory: false,
+            ghost_tape: None,
+            ghost_tape_sha256: None,
+            splits: None,
+            ruleset_id: None,
+            regatta_id: None,
+            raced_run_id: None,
+            submission_nonce: None,
+        }
+    }
+
+    async fn submit(db: &Db, config: &Config, submission: RunSubmission) -> AppResult<RunSubmissionResult> {
+        submit_run(
+            db,
+            config,
+            submission,
+            None,
+            TENANT,
+            RunPipelineExtensions { run_hooks: &[], tape_blob_store: None, notification_providers: &[] },
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn accepts_a_well_formed_submission_and_ranks_it_first() {
+        let db = test_db();
+        let config = Config::from_env();
+        insert_player(&db, TENANT, "player-1");
+
+        let result = submit(&db, &config, submission("player-1", 1, 1_000)).await.unwrap();
+
+        assert_eq!(result.rank, 1);
+        assert!(!result.score_mismatch);
+    }
+
+    #[tokio::test]
+    async fn rejects_a_victory_run_with_implausible_time_played() {
+        let db = test_db();
+        let config = Config::from_env();
+        insert_player(&db, TENANT, "player-1");
+
+        let mut sub = submission("player-1", 1, 1_000);
+        sub.victory = true;
+        sub.time_played = 5;
+
+        let result = submit(&db, &config, sub).await;
+        assert!(matches!(result, Err(crate::error::AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_a_field_exceeding_its_configured_bound() {
+        let db = test_db();
+        let config = Config::from_env();
+        insert_player(&db, TENANT, "player-1");
+
+        let mut sub = submission("player-1", 1, 1_000);
+        sub.waves = config.max_submission_waves + 1;
+
+        let result = submit(&db, &config, sub).await;
+        assert!(matches!(result, Err(crate::error::AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_submission_with_no_nonce_when_nonce_is_required() {
+        let db = test_db();
+        let mut config = Config::from_env();
+        config.submission_nonce_required = true;
+        insert_player(&db, TENANT, "player-1");
+
+        let result = submit(&db, &config, submission("player-1", 1, 1_000)).await;
+        assert!(matches!(result, Err(crate::error::AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_a_banned_player() {
+        let db = test_db();
+        let config = Config::from_env();
+        insert_player(&db, TENANT, "player-1");
+        db.with_write_conn(|conn| {
+            conn.execute("UPDATE players SET banned = 1 WHERE id = 'player-1'", [])?;
+            Ok(())
+        })
+        .unwrap();
+
+        let result = submit(&db, &config, submission("player-1", 1, 1_000)).await;
+        assert!(matches!(result, Err(crate::error::AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn consumes_the_submission_nonce_as_part_of_the_same_transaction() {
+        let db = test_db();
+        let config = Config::from_env();
+        insert_player(&db, TENANT, "player-1");
+        let nonce = nonce_service::issue(&db, TENANT, "player-1", 1).unwrap();
+
+        let mut sub = submission("player-1", 1, 1_000);
+        sub.submission_nonce = Some(nonce.clone());
+        submit(&db, &config, sub).await.unwrap();
+
+        let result = db.with_write_conn(|conn| nonce_service::consume(conn, TENANT, "player-1", 1, &nonce));
+        assert!(matches!(result, Err(crate::error::AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn the_default_hook_set_rejects_a_score_beyond_the_plausible_per_wave_ceiling() {
+        let db = test_db();
+        let config = Config::from_env();
+        insert_player(&db, TENANT, "player-1");
+        let run_hooks = hooks::from_config(&config);
+
+        let mut sub = submission("player-1", 1, 1_000);
+        sub.waves = 1;
+        sub.score = config.max_submission_score_per_wave + 1;
+
+        let result = submit_run(
+            &db,
+            &config,
+            sub,
+            None,
+            TENANT,
+            RunPipelineExtensions { run_hooks: &run_hooks, tape_blob_store: None, notification_providers: &[] },
+        )
+        .await;
+        assert!(matches!(result, Err(crate::error::AppError::Validation(_))));
+    }
+}
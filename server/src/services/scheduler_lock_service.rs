@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use crate::db::Db;
+use crate::error::AppResult;
+
+/// Attempts to acquire (or renew, if already held by `instance_id`) the
+/// advisory lock for `job_name`, valid until `lease` from now. Returns
+/// `true` if this instance holds the lock and should run the job's work
+/// this tick; the caller should skip the work otherwise.
+///
+/// SQLite has no real advisory lock primitive, so this is a single row per
+/// job instead: the `ON CONFLICT ... WHERE` clause is what makes
+/// acquisition atomic even across separate processes sharing the same
+/// database file, the same way `client_error_service::report`'s dedup
+/// upsert relies on `ON CONFLICT` for its atomicity. `lease` should outlast
+/// the job's own tick interval by a comfortable margin, so a crashed
+/// holder's lock expires and another instance can pick the job back up
+/// instead of it going stuck forever.
+pub fn try_acquire(db: &Db, job_name: &str, instance_id: &str, lease: Duration) -> AppResult<bool> {
+    let now = chrono::Utc::now();
+    let expires_at = (now + chrono::Duration::from_std(lease).unwrap_or(chrono::Duration::zero())).to_rfc3339();
+    let now_str = now.to_rfc3339();
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO scheduler_locks (job_name, holder_id, expires_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(job_name) DO UPDATE SET holder_id = excluded.holder_id, expires_at = excluded.expires_at
+             WHERE scheduler_locks.holder_id = excluded.holder_id OR scheduler_locks.expires_at < ?4",
+            rusqlite::params![job_name, instance_id, expires_at, now_str],
+        )?;
+        Ok(conn.changes() > 0)
+    })
+}
@@ -0,0 +1,297 @@
+use booty_hunt_core::CampaignAnalytics;
+use rand::Rng;
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// A signal fire escrowed to a `creator_run_id` starts here and is flipped
+/// to `Active` or `Rejected` by `verify_pending_for_run` once that run is
+/// inserted and its anti-cheat checks are known. A fire minted with no
+/// creator run attached skips escrow entirely and starts `Active`.
+///
+/// A future redemption endpoint must only pay out a fire whose status is
+/// `Active` — none of the other statuses must ever be honored.
+///
+/// `pub(crate)` rather than private: `signal_fire_trade_service` needs
+/// `ACTIVE`/`TRADE_ESCROW` to move a fire in and out of trade escrow.
+pub(crate) mod status {
+    pub const ACTIVE: &str = "active";
+    pub const PENDING: &str = "pending";
+    pub const REJECTED: &str = "rejected";
+    /// Locked into an open trade offer — see `signal_fire_trade_service`.
+    /// Distinct from `PENDING` (which is about creator-run verification, not
+    /// trading) so the two escrow mechanisms can't be confused for one another.
+    pub const TRADE_ESCROW: &str = "trade_escrow";
+}
+
+const CODE_LEN: usize = 8;
+/// Uppercase alphanumeric with visually ambiguous characters (0/O, 1/I/L)
+/// dropped, since these end up read aloud on stream or typed off a screen.
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+
+const MIN_VANITY_CODE_LEN: usize = 4;
+const MAX_VANITY_CODE_LEN: usize = 20;
+
+/// Conservative substring blocklist, not a full profanity API — a streamer
+/// picking a code to say on air just needs the obvious ones caught. Expand
+/// as reports come in rather than trying to enumerate everything up front.
+const PROFANITY_BLOCKLIST: &[&str] = &["FUCK", "SHIT", "NIGGER", "FAGGOT", "CUNT", "RETARD"];
+
+fn generate_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..CODE_LEN).map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char).collect()
+}
+
+/// Normalizes and validates a creator-supplied vanity code: uppercased,
+/// length-bounded, alphanumeric only (unlike the auto-generated alphabet,
+/// vanity codes are chosen on purpose so the ambiguous-character restriction
+/// doesn't apply), and checked against `PROFANITY_BLOCKLIST`. Uniqueness is
+/// left to the caller's `INSERT`, since checking it here would be a
+/// time-of-check/time-of-use race against a concurrent mint of the same code.
+fn validate_vanity_code(raw: &str) -> AppResult<String> {
+    let code = raw.trim().to_ascii_uppercase();
+    if !(MIN_VANITY_CODE_LEN..=MAX_VANITY_CODE_LEN).contains(&code.len()) {
+        return Err(AppError::Validation(format!(
+            "vanity code must be {MIN_VANITY_CODE_LEN}-{MAX_VANITY_CODE_LEN} characters, got {}",
+            code.len()
+        )));
+    }
+    if !code.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()) {
+        return Err(AppError::Validation("vanity code must be letters and digits only".into()));
+    }
+    if PROFANITY_BLOCKLIST.iter().any(|word| code.contains(word)) {
+        return Err(AppError::Validation("vanity code did not pass the profanity filter".into()));
+    }
+    Ok(code)
+}
+
+/// Looks up whether `creator_run_id` already exists for `tenant_id` and, if
+/// so, whether it passed anti-cheat (`score_mismatch = 0`). A run minted
+/// against doesn't have to exist yet — a streamer can announce a code before
+/// their qualifying run finishes uploading — so "not found" is not an error,
+/// it just means the fire starts `Pending` until `verify_pending_for_run`
+/// resolves it.
+fn escrow_status_for_run(conn: &Connection, tenant_id: &str, run_id: &str) -> AppResult<&'static str> {
+    let mismatch: Option<i64> = conn
+        .query_row(
+            "SELECT score_mismatch FROM runs WHERE tenant_id = ?1 AND id = ?2",
+            rusqlite::params![tenant_id, run_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(match mismatch {
+        None => status::PENDING,
+        Some(0) => status::ACTIVE,
+        Some(_) => status::REJECTED,
+    })
+}
+
+/// Mints one signal fire, either with a caller-chosen vanity code (validated
+/// by `validate_vanity_code`) or, if `custom_code` is `None`, a random one
+/// from `generate_code`. Rejects a vanity code that's already taken rather
+/// than silently generating a different one, since the whole point of a
+/// vanity code is that it's the specific one a creator announced.
+///
+/// `creator_run_id`, if given, escrows the fire against that run's
+/// verification: `escrow_status_for_run` decides whether it starts `Active`,
+/// `Pending`, or `Rejected`. A fire with no creator run skips escrow and
+/// starts `Active`, matching pre-escrow behavior. Returns the resulting
+/// status alongside the code so the caller can surface it.
+pub fn mint_single(
+    db: &Db,
+    tenant_id: &str,
+    custom_code: Option<&str>,
+    aid_type: &str,
+    aid_amount: i64,
+    campaign: Option<&str>,
+    creator_run_id: Option<&str>,
+) -> AppResult<(String, &'static str)> {
+    let code = match custom_code {
+        Some(raw) => validate_vanity_code(raw)?,
+        None => generate_code(),
+    };
+    let created_at = chrono::Utc::now().to_rfc3339();
+
+    let status = db.with_write_conn(|conn| {
+        let status = match creator_run_id {
+            Some(run_id) => escrow_status_for_run(conn, tenant_id, run_id)?,
+            None => status::ACTIVE,
+        };
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO signal_fires (code, tenant_id, aid_type, aid_amount, campaign, created_at, creator_run_id, status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            rusqlite::params![code, tenant_id, aid_type, aid_amount, campaign, created_at, creator_run_id, status],
+        )?;
+        if inserted == 0 {
+            return Err(AppError::Validation(format!("signal fire code already taken: {code}")));
+        }
+        Ok(status)
+    })?;
+    Ok((code, status))
+}
+
+/// Flips any signal fires still escrowed as `Pending` against `run_id` to
+/// `Active` or `Rejected`, per `verified`. Called inline from
+/// `run_service::submit_run`'s own transaction right after that run's
+/// `INSERT`, using the `score_mismatch` result it already computed —
+/// this is core, always-on server behavior rather than a pluggable
+/// `RunHook`, so it isn't wired through that extension point.
+pub fn verify_pending_for_run(conn: &Connection, tenant_id: &str, run_id: &str, verified: bool) -> AppResult<usize> {
+    let new_status = if verified { status::ACTIVE } else { status::REJECTED };
+    Ok(conn.execute(
+        "UPDATE signal_fires SET status = ?1 WHERE tenant_id = ?2 AND creator_run_id = ?3 AND status = ?4",
+        rusqlite::params![new_status, tenant_id, run_id, status::PENDING],
+    )?)
+}
+
+/// Mints `count` single-use signal fires sharing one `campaign` tag, aid
+/// type, and aid amount, returning the generated codes. Retries a code on
+/// the (astronomically unlikely) collision with an existing one rather than
+/// failing the whole batch — `CODE_ALPHABET`'s 32^8 space makes a second
+/// collision within one batch effectively impossible.
+pub fn mint_bulk(db: &Db, tenant_id: &str, campaign: &str, aid_type: &str, aid_amount: i64, count: u32) -> AppResult<Vec<String>> {
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        let mut codes = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            loop {
+                let code = generate_code();
+                let inserted = conn.execute(
+                    "INSERT OR IGNORE INTO signal_fires (code, tenant_id, aid_type, aid_amount, campaign, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    rusqlite::params![code, tenant_id, aid_type, aid_amount, campaign, created_at],
+                )?;
+                if inserted == 1 {
+                    codes.push(code);
+                    break;
+                }
+            }
+        }
+        Ok(codes)
+    })
+}
+
+/// Whether `code` has been minted for `tenant_id` — used by the QR endpoint
+/// to 404 on an unknown code rather than happily encoding a dead link.
+pub fn exists(db: &Db, tenant_id: &str, code: &str) -> AppResult<bool> {
+    db.with_read_conn(|conn| {
+        Ok(conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM signal_fires WHERE tenant_id = ?1 AND code = ?2)",
+                rusqlite::params![tenant_id, code],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|v| v != 0)?)
+    })
+}
+
+/// How many signal fires are currently redeemable for `tenant_id` — backed
+/// by `idx_signal_fires_status`, so this is an index range scan rather than
+/// a scan of the whole table. Used by `admin::overview`.
+pub fn count_active(db: &Db, tenant_id: &str) -> AppResult<i64> {
+    db.with_read_conn(|conn| {
+        conn.query_row(
+            "SELECT COUNT(*) FROM signal_fires WHERE tenant_id = ?1 AND status = ?2",
+            rusqlite::params![tenant_id, status::ACTIVE],
+            |row| row.get(0),
+        )
+        .map_err(Into::into)
+    })
+}
+
+/// The deep link a redemption QR code encodes. There's no registered app
+/// URL scheme documented anywhere else in this repo yet — `booty-hunt://`
+/// matches the crate/package name and is the obvious choice once the client
+/// registers a handler for it.
+pub fn redemption_deep_link(code: &str) -> String {
+    format!("booty-hunt://redeem?code={code}")
+}
+
+/// The aid type of `code`, for `signal_fire_trade_service` to check a wanted
+/// aid type without duplicating the query.
+pub(crate) fn aid_type_of(conn: &Connection, tenant_id: &str, code: &str) -> AppResult<String> {
+    conn.query_row(
+        "SELECT aid_type FROM signal_fires WHERE tenant_id = ?1 AND code = ?2",
+        rusqlite::params![tenant_id, code],
+        |row| row.get(0),
+    )
+    .map_err(|_| AppError::Validation(format!("unknown signal fire code: {code}")))
+}
+
+/// The current holder of `code`, for `signal_fire_trade_service` to check
+/// that a caller offering or accepting a trade actually holds the code they
+/// named before it gets locked into escrow.
+pub(crate) fn holder_of(conn: &Connection, tenant_id: &str, code: &str) -> AppResult<Option<String>> {
+    conn.query_row(
+        "SELECT holder_player_id FROM signal_fires WHERE tenant_id = ?1 AND code = ?2",
+        rusqlite::params![tenant_id, code],
+        |row| row.get(0),
+    )
+    .map_err(|_| AppError::Validation(format!("unknown signal fire code: {code}")))
+}
+
+/// Locks `code` into trade escrow so it can't be redeemed or entered into a
+/// second trade offer while one is already in flight. Fails if the code
+/// doesn't exist, isn't `Active`, or has already been redeemed.
+pub(crate) fn lock_for_trade(conn: &Connection, tenant_id: &str, code: &str) -> AppResult<()> {
+    let updated = conn.execute(
+        "UPDATE signal_fires SET status = ?1 WHERE tenant_id = ?2 AND code = ?3 AND status = ?4 AND redeemed_at IS NULL",
+        rusqlite::params![status::TRADE_ESCROW, tenant_id, code, status::ACTIVE],
+    )?;
+    if updated == 0 {
+        return Err(AppError::Validation(format!("signal fire {code} is not available to trade")));
+    }
+    Ok(())
+}
+
+/// Releases `code` from trade escrow back to `Active` — used when an offer
+/// holding it is cancelled without being accepted.
+pub(crate) fn unlock_from_trade(conn: &Connection, tenant_id: &str, code: &str) -> AppResult<()> {
+    conn.execute(
+        "UPDATE signal_fires SET status = ?1 WHERE tenant_id = ?2 AND code = ?3 AND status = ?4",
+        rusqlite::params![status::ACTIVE, tenant_id, code, status::TRADE_ESCROW],
+    )?;
+    Ok(())
+}
+
+/// Completes a trade: both codes leave escrow as `Active` again with
+/// `holder_player_id` swapped to whoever now holds each one. Called from
+/// inside `signal_fire_trade_service::accept_offer`'s own transaction, so a
+/// crash mid-swap can't leave one code transferred and the other stuck in
+/// escrow.
+pub(crate) fn swap_holders(
+    conn: &Connection,
+    tenant_id: &str,
+    code_a: &str,
+    new_holder_a: &str,
+    code_b: &str,
+    new_holder_b: &str,
+) -> AppResult<()> {
+    for (code, new_holder) in [(code_a, new_holder_a), (code_b, new_holder_b)] {
+        conn.execute(
+            "UPDATE signal_fires SET status = ?1, holder_player_id = ?2 WHERE tenant_id = ?3 AND code = ?4",
+            rusqlite::params![status::ACTIVE, new_holder, tenant_id, code],
+        )?;
+    }
+    Ok(())
+}
+
+/// Redemption analytics for one campaign tag — how many codes were minted,
+/// how many have been redeemed, and the total aid amount that's actually
+/// gone out. `redeemed`/`redeemed_amount` stay at 0 until a redemption
+/// endpoint exists to set `signal_fires.redeemed_at`; the columns and this
+/// query are in place ahead of that landing.
+pub fn campaign_analytics(db: &Db, tenant_id: &str, campaign: &str) -> AppResult<CampaignAnalytics> {
+    db.with_read_conn(|conn| {
+        let (minted, redeemed, redeemed_amount) = conn.query_row(
+            "SELECT COUNT(*),
+                    COUNT(redeemed_at),
+                    COALESCE(SUM(CASE WHEN redeemed_at IS NOT NULL THEN aid_amount ELSE 0 END), 0)
+             FROM signal_fires WHERE tenant_id = ?1 AND campaign = ?2",
+            rusqlite::params![tenant_id, campaign],
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?, row.get::<_, i64>(2)?)),
+        )?;
+        Ok(CampaignAnalytics { campaign: campaign.to_string(), minted, redeemed, redeemed_amount })
+    })
+}
@@ -0,0 +1,285 @@
+use booty_hunt_core::{Page, TradeOffer};
+use rusqlite::OptionalExtension;
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::pagination;
+use crate::services::signal_fire_service;
+
+const STATUS_OPEN: &str = "open";
+const STATUS_ACCEPTED: &str = "accepted";
+const STATUS_CANCELLED: &str = "cancelled";
+
+fn count_open_offers(conn: &rusqlite::Connection, tenant_id: &str, player_id: &str) -> AppResult<i64> {
+    Ok(conn.query_row(
+        "SELECT COUNT(*) FROM signal_fire_trade_offers WHERE tenant_id = ?1 AND offering_player_id = ?2 AND status = ?3",
+        rusqlite::params![tenant_id, player_id, STATUS_OPEN],
+        |row| row.get(0),
+    )?)
+}
+
+fn row_to_offer(row: &rusqlite::Row) -> rusqlite::Result<TradeOffer> {
+    Ok(TradeOffer {
+        id: row.get(0)?,
+        offering_player_id: row.get(1)?,
+        offering_code: row.get(2)?,
+        offering_aid_type: row.get(3)?,
+        wanted_aid_type: row.get(4)?,
+        status: row.get(5)?,
+        accepted_by_player_id: row.get(6)?,
+        accepted_code: row.get(7)?,
+        created_at: row.get(8)?,
+        resolved_at: row.get(9)?,
+    })
+}
+
+const SELECT_COLUMNS: &str = "id, offering_player_id, offering_code, offering_aid_type, wanted_aid_type, status, accepted_by_player_id, accepted_code, created_at, resolved_at";
+
+/// Posts a standing offer to trade `offering_code` for any signal fire of
+/// `wanted_aid_type`. Locks `offering_code` into trade escrow immediately —
+/// see `signal_fire_service::lock_for_trade` — so it can't be redeemed or
+/// posted to a second offer while this one is open. Capped at
+/// `max_open_offers` per player (`Config::max_open_trade_offers_per_player`)
+/// to keep one account from tying up the whole supply of a scarce aid type.
+pub fn create_offer(
+    db: &Db,
+    tenant_id: &str,
+    offering_player_id: &str,
+    offering_code: &str,
+    wanted_aid_type: &str,
+    max_open_offers: i64,
+) -> AppResult<TradeOffer> {
+    let id = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now().to_rfc3339();
+
+    db.with_write_conn(|conn| {
+        if count_open_offers(conn, tenant_id, offering_player_id)? >= max_open_offers {
+            return Err(AppError::Validation(format!(
+                "player already has the maximum of {max_open_offers} open trade offers"
+            )));
+        }
+
+        if signal_fire_service::holder_of(conn, tenant_id, offering_code)?.as_deref() != Some(offering_player_id) {
+            return Err(AppError::Validation("only the holder of a signal fire can offer it for trade".into()));
+        }
+
+        let offering_aid_type = signal_fire_service::aid_type_of(conn, tenant_id, offering_code)?;
+        signal_fire_service::lock_for_trade(conn, tenant_id, offering_code)?;
+
+        conn.execute(
+            "INSERT INTO signal_fire_trade_offers (id, tenant_id, offering_player_id, offering_code, offering_aid_type, wanted_aid_type, status, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            rusqlite::params![id, tenant_id, offering_player_id, offering_code, offering_aid_type, wanted_aid_type, STATUS_OPEN, created_at],
+        )?;
+
+        Ok(TradeOffer {
+            id,
+            offering_player_id: offering_player_id.to_string(),
+            offering_code: offering_code.to_string(),
+            offering_aid_type,
+            wanted_aid_type: wanted_aid_type.to_string(),
+            status: STATUS_OPEN.to_string(),
+            accepted_by_player_id: None,
+            accepted_code: None,
+            created_at,
+            resolved_at: None,
+        })
+    })
+}
+
+/// Accepts an open offer by putting up `accepting_code` in return. Rejects a
+/// code whose aid type doesn't match what the offer asked for, and rejects a
+/// player trying to accept their own offer. On success both codes swap
+/// `holder_player_id` and leave escrow atomically — see
+/// `signal_fire_service::swap_holders` — in the same transaction as the
+/// offer's own status update, so a crash mid-swap can't leave the offer
+/// `open` with one code already transferred.
+pub fn accept_offer(
+    db: &Db,
+    tenant_id: &str,
+    offer_id: &str,
+    accepting_player_id: &str,
+    accepting_code: &str,
+) -> AppResult<TradeOffer> {
+    let resolved_at = chrono::Utc::now().to_rfc3339();
+
+    db.with_write_conn(|conn| {
+        let (offering_player_id, offering_code, wanted_aid_type): (String, String, String) = conn
+            .query_row(
+                "SELECT offering_player_id, offering_code, wanted_aid_type FROM signal_fire_trade_offers
+                 WHERE tenant_id = ?1 AND id = ?2 AND status = ?3",
+                rusqlite::params![tenant_id, offer_id, STATUS_OPEN],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?
+            .ok_or(AppError::NotFound)?;
+
+        if offering_player_id == accepting_player_id {
+            return Err(AppError::Validation("cannot accept your own trade offer".into()));
+        }
+
+        if signal_fire_service::holder_of(conn, tenant_id, accepting_code)?.as_deref() != Some(accepting_player_id) {
+            return Err(AppError::Validation("only the holder of a signal fire can accept a trade with it".into()));
+        }
+
+        let accepting_aid_type = signal_fire_service::aid_type_of(conn, tenant_id, accepting_code)?;
+        if accepting_aid_type != wanted_aid_type {
+            return Err(AppError::Validation(format!(
+                "offer wants aid type {wanted_aid_type}, but {accepting_code} is {accepting_aid_type}"
+            )));
+        }
+
+        signal_fire_service::lock_for_trade(conn, tenant_id, accepting_code)?;
+        signal_fire_service::swap_holders(conn, tenant_id, &offering_code, accepting_player_id, accepting_code, &offering_player_id)?;
+
+        conn.execute(
+            "UPDATE signal_fire_trade_offers SET status = ?1, accepted_by_player_id = ?2, accepted_code = ?3, resolved_at = ?4
+             WHERE tenant_id = ?5 AND id = ?6",
+            rusqlite::params![STATUS_ACCEPTED, accepting_player_id, accepting_code, resolved_at, tenant_id, offer_id],
+        )?;
+
+        conn.query_row(
+            &format!("SELECT {SELECT_COLUMNS} FROM signal_fire_trade_offers WHERE tenant_id = ?1 AND id = ?2"),
+            rusqlite::params![tenant_id, offer_id],
+            row_to_offer,
+        )
+        .map_err(Into::into)
+    })
+}
+
+/// Cancels an offer the caller posted, releasing its escrowed code back to
+/// `Active`. Only the offering player can cancel; anyone else gets a
+/// validation error rather than a peek into whether the offer id exists.
+pub fn cancel_offer(db: &Db, tenant_id: &str, offer_id: &str, requesting_player_id: &str) -> AppResult<()> {
+    let resolved_at = chrono::Utc::now().to_rfc3339();
+
+    db.with_write_conn(|conn| {
+        let (offering_player_id, offering_code): (String, String) = conn
+            .query_row(
+                "SELECT offering_player_id, offering_code FROM signal_fire_trade_offers
+                 WHERE tenant_id = ?1 AND id = ?2 AND status = ?3",
+                rusqlite::params![tenant_id, offer_id, STATUS_OPEN],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?
+            .ok_or(AppError::NotFound)?;
+
+        if offering_player_id != requesting_player_id {
+            return Err(AppError::Validation("only the offering player can cancel this trade offer".into()));
+        }
+
+        signal_fire_service::unlock_from_trade(conn, tenant_id, &offering_code)?;
+        conn.execute(
+            "UPDATE signal_fire_trade_offers SET status = ?1, resolved_at = ?2 WHERE tenant_id = ?3 AND id = ?4",
+            rusqlite::params![STATUS_CANCELLED, resolved_at, tenant_id, offer_id],
+        )?;
+        Ok(())
+    })
+}
+
+/// Lists open offers, optionally narrowed to ones asking for a specific aid
+/// type — the shape a trading-board UI would page through looking for a
+/// match. Newest first, cursor-paginated per `pagination`.
+pub fn list_open(db: &Db, tenant_id: &str, wanted_aid_type: Option<&str>, limit: i64, cursor: Option<&str>) -> AppResult<Page<TradeOffer>> {
+    let before = cursor.map(pagination::decode_cursor).transpose()?;
+    db.with_read_conn(|conn| {
+        let total: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM signal_fire_trade_offers WHERE tenant_id = ?1 AND status = ?2 AND (?3 IS NULL OR wanted_aid_type = ?3)",
+            rusqlite::params![tenant_id, STATUS_OPEN, wanted_aid_type],
+            |row| row.get(0),
+        )?;
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SELECT_COLUMNS} FROM signal_fire_trade_offers
+             WHERE tenant_id = ?1 AND status = ?2 AND (?3 IS NULL OR wanted_aid_type = ?3)
+             AND (?4 IS NULL OR (created_at, id) < (?4, ?5))
+             ORDER BY created_at DESC, id DESC LIMIT ?6"
+        ))?;
+        let (before_created_at, before_id) = match &before {
+            Some((created_at, id)) => (Some(created_at.as_str()), Some(id.as_str())),
+            None => (None, None),
+        };
+        let rows = stmt
+            .query_map(rusqlite::params![tenant_id, STATUS_OPEN, wanted_aid_type, before_created_at, before_id, limit], row_to_offer)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let next_cursor = match rows.last() {
+            Some(last) if rows.len() as i64 == limit => Some(pagination::encode_cursor(&last.created_at, &last.id)),
+            _ => None,
+        };
+        Ok(Page { items: rows, next_cursor, total })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_db;
+
+    const TENANT: &str = "tenant-a";
+
+    /// Mints a signal fire and sets its `holder_player_id` directly — there's
+    /// no redemption endpoint yet to claim one through, so tests establish
+    /// holdership the same way `swap_holders` would leave it after a trade.
+    fn mint_held_by(db: &Db, aid_type: &str, holder: &str) -> String {
+        let (code, _status) = signal_fire_service::mint_single(db, TENANT, None, aid_type, 10, None, None).unwrap();
+        db.with_write_conn(|conn| {
+            conn.execute("UPDATE signal_fires SET holder_player_id = ?1 WHERE tenant_id = ?2 AND code = ?3", rusqlite::params![holder, TENANT, code])?;
+            Ok(())
+        })
+        .unwrap();
+        code
+    }
+
+    #[test]
+    fn create_offer_rejects_a_caller_who_does_not_hold_the_code() {
+        let db = test_db();
+        let code = mint_held_by(&db, "supplies", "player-owner");
+        let result = create_offer(&db, TENANT, "player-impostor", &code, "intel", 5);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn create_offer_succeeds_for_the_actual_holder() {
+        let db = test_db();
+        let code = mint_held_by(&db, "supplies", "player-owner");
+        let offer = create_offer(&db, TENANT, "player-owner", &code, "intel", 5).unwrap();
+        assert_eq!(offer.offering_player_id, "player-owner");
+        assert_eq!(offer.offering_code, code);
+    }
+
+    #[test]
+    fn accept_offer_rejects_a_caller_who_does_not_hold_the_accepting_code() {
+        let db = test_db();
+        let offering_code = mint_held_by(&db, "supplies", "player-owner");
+        let accepting_code = mint_held_by(&db, "intel", "player-owner2");
+        let offer = create_offer(&db, TENANT, "player-owner", &offering_code, "intel", 5).unwrap();
+        let result = accept_offer(&db, TENANT, &offer.id, "player-impostor", &accepting_code);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn accept_offer_swaps_holders_for_the_actual_holder() {
+        let db = test_db();
+        let offering_code = mint_held_by(&db, "supplies", "player-owner");
+        let accepting_code = mint_held_by(&db, "intel", "player-owner2");
+        let offer = create_offer(&db, TENANT, "player-owner", &offering_code, "intel", 5).unwrap();
+        let accepted = accept_offer(&db, TENANT, &offer.id, "player-owner2", &accepting_code).unwrap();
+        assert_eq!(accepted.status, STATUS_ACCEPTED);
+
+        let new_offering_holder = db.with_read_conn(|conn| signal_fire_service::holder_of(conn, TENANT, &offering_code)).unwrap();
+        let new_accepting_holder = db.with_read_conn(|conn| signal_fire_service::holder_of(conn, TENANT, &accepting_code)).unwrap();
+        assert_eq!(new_offering_holder.as_deref(), Some("player-owner2"));
+        assert_eq!(new_accepting_holder.as_deref(), Some("player-owner"));
+    }
+
+    #[test]
+    fn cancel_offer_rejects_a_non_owning_player() {
+        let db = test_db();
+        let code = mint_held_by(&db, "supplies", "player-owner");
+        let offer = create_offer(&db, TENANT, "player-owner", &code, "intel", 5).unwrap();
+        let result = cancel_offer(&db, TENANT, &offer.id, "player-impostor");
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use booty_hunt_core::{BestSplitEntry, SumOfBest, WaveSplit};
+
+use crate::db::Db;
+use crate::error::AppResult;
+
+/// Finds the fastest recorded split for each wave of `seed` across every run
+/// that reported splits, and sums them into the "sum of best" — the
+/// theoretical fastest possible clear if a single run hit every best segment.
+/// Malformed split JSON on an individual run is skipped rather than failing
+/// the whole query, since it can only have come from a client bug on that one
+/// submission.
+pub fn fetch_sum_of_best(db: &Db, tenant_id: &str, seed: i64) -> AppResult<SumOfBest> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT r.id, p.display_name, r.splits FROM runs r JOIN players p ON p.id = r.player_id
+             WHERE r.tenant_id = ?1 AND r.seed = ?2 AND r.splits IS NOT NULL",
+        )?;
+        let rows: Vec<(String, String, String)> = stmt
+            .query_map(rusqlite::params![tenant_id, seed], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut best: HashMap<i64, BestSplitEntry> = HashMap::new();
+        for (run_id, player_name, splits_json) in rows {
+            let Ok(splits) = serde_json::from_str::<Vec<WaveSplit>>(&splits_json) else { continue };
+            for split in splits {
+                let improves = match best.get(&split.wave) {
+                    Some(existing) => split.time_ms < existing.time_ms,
+                    None => true,
+                };
+                if improves {
+                    best.insert(
+                        split.wave,
+                        BestSplitEntry {
+                            wave: split.wave,
+                            time_ms: split.time_ms,
+                            run_id: run_id.clone(),
+                            player_name: player_name.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        let mut splits: Vec<BestSplitEntry> = best.into_values().collect();
+        splits.sort_by_key(|s| s.wave);
+        let sum_of_best_ms = splits.iter().map(|s| s.time_ms).sum();
+        Ok(SumOfBest { seed, splits, sum_of_best_ms })
+    })
+}
@@ -0,0 +1,110 @@
+use booty_hunt_core::TimeseriesPoint;
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// Truncates an RFC 3339 timestamp to its hour, matching `hourly_stats.hour_bucket`.
+fn hour_bucket(timestamp: &str) -> String {
+    format!("{}:00:00Z", &timestamp[..13])
+}
+
+/// Bumps the current hour's `submissions`/`victories`/`unique_players`
+/// counters for `tenant_id`. Called inline from `run_service::submit_run`'s
+/// own transaction right after the run's `INSERT`, the same way
+/// `overtake_service::find_and_record` and `progression_service::record_run`
+/// are — this is core, always-on bookkeeping, not a pluggable hook.
+///
+/// `unique_players` is maintained incrementally via `hourly_active_players`
+/// rather than recomputed with `COUNT(DISTINCT player_id)` over `runs`,
+/// which is exactly the per-request table scan this rollup exists to avoid.
+pub fn record_submission(conn: &Connection, tenant_id: &str, player_id: &str, victory: bool, created_at: &str) -> AppResult<()> {
+    let bucket = hour_bucket(created_at);
+
+    let first_this_hour = conn.execute(
+        "INSERT OR IGNORE INTO hourly_active_players (tenant_id, hour_bucket, player_id) VALUES (?1, ?2, ?3)",
+        rusqlite::params![tenant_id, bucket, player_id],
+    )? > 0;
+
+    conn.execute(
+        "INSERT INTO hourly_stats (tenant_id, hour_bucket, submissions, victories, unique_players)
+         VALUES (?1, ?2, 1, ?3, ?4)
+         ON CONFLICT(tenant_id, hour_bucket) DO UPDATE SET
+             submissions = submissions + 1,
+             victories = victories + excluded.victories,
+             unique_players = unique_players + excluded.unique_players",
+        rusqlite::params![tenant_id, bucket, victory as i64, first_this_hour as i64],
+    )?;
+    Ok(())
+}
+
+/// Bumps the current hour's `redemptions` counter. Not called from anywhere
+/// yet — there's no signal fire redemption endpoint in this server — but the
+/// counter and rollup logic are in place for that endpoint to call into when
+/// it lands, the same "columns ahead of the feature" pattern used elsewhere
+/// for signal fire economy fields.
+#[allow(dead_code)]
+pub fn record_redemption(conn: &Connection, tenant_id: &str, created_at: &str) -> AppResult<()> {
+    let bucket = hour_bucket(created_at);
+    conn.execute(
+        "INSERT INTO hourly_stats (tenant_id, hour_bucket, redemptions) VALUES (?1, ?2, 1)
+         ON CONFLICT(tenant_id, hour_bucket) DO UPDATE SET redemptions = redemptions + 1",
+        rusqlite::params![tenant_id, bucket],
+    )?;
+    Ok(())
+}
+
+/// The current hour's `submissions` counter for `tenant_id`, a single
+/// primary-key row read rather than a scan — used by
+/// `admin::overview` to derive a submissions-per-minute rate without
+/// re-aggregating `runs`. Returns `0` for an hour with no submissions yet,
+/// since `hourly_stats` only gets a row on the first submission of the hour.
+pub fn current_hour_submissions(db: &Db, tenant_id: &str) -> AppResult<i64> {
+    let bucket = hour_bucket(&chrono::Utc::now().to_rfc3339());
+    db.with_read_conn(|conn| {
+        Ok(conn
+            .query_row(
+                "SELECT submissions FROM hourly_stats WHERE tenant_id = ?1 AND hour_bucket = ?2",
+                rusqlite::params![tenant_id, bucket],
+                |row| row.get(0),
+            )
+            .optional()?
+            .unwrap_or(0))
+    })
+}
+
+/// Rolls `hourly_stats` up into `from..=to` buckets at the requested
+/// granularity. `"hour"` returns the rows as stored; `"day"` sums every
+/// hour within a calendar day into one point — which, unlike the hourly
+/// counters, no longer gives an exact `unique_players` for the day (a player
+/// active across three hours counts three times), since that would need
+/// re-deriving distinct players from `hourly_active_players` instead of
+/// summing a precomputed counter. Good enough for a chart; not exact math.
+pub fn timeseries(db: &Db, tenant_id: &str, from: &str, to: &str, interval: &str) -> AppResult<Vec<TimeseriesPoint>> {
+    let bucket_len: usize = match interval {
+        "hour" => 20,
+        "day" => 10,
+        other => return Err(AppError::Validation(format!("unknown interval: {other} (expected \"hour\" or \"day\")"))),
+    };
+
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT substr(hour_bucket, 1, ?1) AS bucket,
+                    SUM(submissions), SUM(victories), SUM(unique_players), SUM(redemptions)
+             FROM hourly_stats
+             WHERE tenant_id = ?2 AND hour_bucket >= ?3 AND hour_bucket <= ?4
+             GROUP BY bucket
+             ORDER BY bucket ASC",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![bucket_len, tenant_id, from, to], |row| {
+            Ok(TimeseriesPoint {
+                bucket: row.get(0)?,
+                submissions: row.get(1)?,
+                victories: row.get(2)?,
+                unique_players: row.get(3)?,
+                redemptions: row.get(4)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    })
+}
@@ -0,0 +1,83 @@
+//! Combines the anti-cheat signals `run_service::submit_run` already
+//! computes (tape mismatch, canary hit, submission rate) into a single
+//! per-run suspicion score, stored on `runs.suspicion_score`, and rolls it
+//! into a running per-player total in `player_suspicion`. A run clearing
+//! `Config::suspicion_flag_threshold` is routed into the moderation queue
+//! alongside explicit canary hits — see `moderation_queue_service`.
+//!
+//! This is a starting set of signals, not an exhaustive model: report
+//! counts and near-miss plausibility checks aren't wired in yet, since
+//! neither has a settled shape elsewhere in the codebase to build on.
+
+use rusqlite::Connection;
+
+use crate::config::Config;
+use crate::error::AppResult;
+use crate::services::moderation_queue_service;
+
+/// True if `seed` or `score` is one of the operator's configured canary
+/// values — see `Config::canary_seeds`/`canary_scores`.
+pub fn detect_canary(config: &Config, seed: i64, score: i64) -> bool {
+    config.canary_seeds.contains(&seed) || config.canary_scores.contains(&score)
+}
+
+/// Computes this run's suspicion score, persists it on `runs` and
+/// `player_suspicion`, and flags it into the moderation queue if it clears
+/// `suspicion_flag_threshold`. Must run inside the same transaction as the
+/// run's own INSERT, after which `runs.id = run_id` already exists.
+#[allow(clippy::too_many_arguments)]
+pub fn compute_and_record(
+    conn: &Connection,
+    config: &Config,
+    tenant_id: &str,
+    run_id: &str,
+    player_id: &str,
+    seed: i64,
+    score_mismatch: bool,
+    canary_hit: bool,
+    created_at: &str,
+) -> AppResult<i64> {
+    let rate_cutoff = (chrono::DateTime::parse_from_rfc3339(created_at)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .unwrap_or_else(|_| chrono::Utc::now())
+        - chrono::Duration::seconds(config.suspicion_rate_window_secs))
+    .to_rfc3339();
+    let recent_submissions: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM runs WHERE tenant_id = ?1 AND player_id = ?2 AND created_at >= ?3",
+        rusqlite::params![tenant_id, player_id, rate_cutoff],
+        |row| row.get(0),
+    )?;
+    let high_rate = recent_submissions >= config.suspicion_rate_threshold;
+
+    let mut score = 0i64;
+    if score_mismatch {
+        score += config.suspicion_weight_score_mismatch;
+    }
+    if canary_hit {
+        score += config.suspicion_weight_canary_hit;
+    }
+    if high_rate {
+        score += config.suspicion_weight_high_rate;
+    }
+
+    conn.execute("UPDATE runs SET suspicion_score = ?1 WHERE id = ?2", rusqlite::params![score, run_id])?;
+
+    conn.execute(
+        "INSERT INTO player_suspicion (tenant_id, player_id, score, updated_at) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (tenant_id, player_id) DO UPDATE SET score = score + ?3, updated_at = ?4",
+        rusqlite::params![tenant_id, player_id, score, created_at],
+    )?;
+
+    if score >= config.suspicion_flag_threshold {
+        let reason = if canary_hit && config.canary_seeds.contains(&seed) {
+            "canary_seed"
+        } else if canary_hit {
+            "canary_score"
+        } else {
+            "suspicion_score"
+        };
+        moderation_queue_service::flag(conn, tenant_id, run_id, player_id, reason, score, created_at)?;
+    }
+
+    Ok(score)
+}
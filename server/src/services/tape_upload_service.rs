@@ -0,0 +1,85 @@
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// How long an in-progress chunked upload session may sit idle before the
+/// scheduler's GC job reclaims it.
+const SESSION_TTL_MINUTES: i64 = 30;
+
+pub fn start_session(db: &Db, run_id: &str) -> AppResult<String> {
+    let id = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now();
+    let expires_at = created_at + chrono::Duration::minutes(SESSION_TTL_MINUTES);
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO tape_upload_sessions (id, run_id, created_at, expires_at, expires_at_epoch) VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![id, run_id, created_at.to_rfc3339(), expires_at.to_rfc3339(), expires_at.timestamp()],
+        )?;
+        Ok(())
+    })?;
+    Ok(id)
+}
+
+/// Appends `chunk` at `offset`. Chunks must arrive in order; a mismatched
+/// offset means the client needs to resume from the session's current length.
+pub fn put_chunk(db: &Db, session_id: &str, offset: usize, chunk: &[u8]) -> AppResult<usize> {
+    db.with_write_conn(|conn| {
+        let existing: Vec<u8> = conn
+            .query_row(
+                "SELECT data FROM tape_upload_sessions WHERE id = ?1",
+                [session_id],
+                |row| row.get(0),
+            )
+            .map_err(|_| AppError::NotFound)?;
+
+        if offset != existing.len() {
+            return Err(AppError::Validation(format!(
+                "expected offset {}, got {offset}",
+                existing.len()
+            )));
+        }
+
+        let mut data = existing;
+        data.extend_from_slice(chunk);
+        let new_len = data.len();
+        conn.execute(
+            "UPDATE tape_upload_sessions SET data = ?1 WHERE id = ?2",
+            rusqlite::params![data, session_id],
+        )?;
+        Ok(new_len)
+    })
+}
+
+pub fn finalize(db: &Db, session_id: &str) -> AppResult<()> {
+    db.with_write_conn(|conn| {
+        let (run_id, data): (String, Vec<u8>) = conn
+            .query_row(
+                "SELECT run_id, data FROM tape_upload_sessions WHERE id = ?1",
+                [session_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map_err(|_| AppError::NotFound)?;
+        conn.execute(
+            "UPDATE runs SET ghost_tape = ?1 WHERE id = ?2",
+            rusqlite::params![data, run_id],
+        )?;
+        conn.execute("DELETE FROM tape_upload_sessions WHERE id = ?1", [session_id])?;
+        Ok(())
+    })
+}
+
+/// Deletes sessions past their TTL. Called by the scheduler's GC job.
+///
+/// Compares against `expires_at_epoch` rather than the legacy `expires_at`
+/// text column — see migration `0032_tape_session_epoch_expiry` for why a
+/// string comparison here can silently under-reap. `expires_at` itself is
+/// kept as-is for now; this table is the first slice of a larger created_at/
+/// expires_at-to-epoch migration, not the whole thing.
+pub fn gc_expired(db: &Db) -> AppResult<usize> {
+    let now = chrono::Utc::now().timestamp();
+    db.with_write_conn(|conn| {
+        let deleted = conn.execute("DELETE FROM tape_upload_sessions WHERE expires_at_epoch < ?1", [now])?;
+        Ok(deleted)
+    })
+}
@@ -0,0 +1,121 @@
+use booty_hunt_core::TelemetryAggregateBucket;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+
+/// One event as submitted to `POST /api/telemetry`, before it's assigned an
+/// id/timestamp.
+pub struct TelemetryEventInput {
+    pub event_type: String,
+    pub player_id: Option<String>,
+    pub payload: Value,
+}
+
+fn validate_event(config: &Config, event: &TelemetryEventInput) -> AppResult<()> {
+    let schema = config
+        .telemetry_event_schemas
+        .iter()
+        .find(|s| s.event_type == event.event_type)
+        .ok_or_else(|| AppError::Validation(format!("unknown telemetry event type: {}", event.event_type)))?;
+
+    let payload = event
+        .payload
+        .as_object()
+        .ok_or_else(|| AppError::Validation("telemetry payload must be a JSON object".into()))?;
+    for field in &schema.required_fields {
+        if !payload.contains_key(field) {
+            return Err(AppError::Validation(format!(
+                "telemetry event {} is missing required field {field}",
+                event.event_type
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Validates and inserts a batch of events in one write-lock acquisition
+/// rather than one per event, so a large batch doesn't hold the shared
+/// connection open in a series of round trips. This still goes through the
+/// same single connection as `runs` — genuine write-path isolation (a
+/// dedicated connection or database file for telemetry) isn't in place yet,
+/// so a telemetry burst can still contend with a run submission. Batching is
+/// the mitigation available today; a separate connection is future work.
+///
+/// The whole batch is rejected if any single event fails validation, so a
+/// caller can't half-ingest and silently lose events it thinks succeeded.
+pub fn ingest_batch(db: &Db, config: &Config, tenant_id: &str, events: Vec<TelemetryEventInput>) -> AppResult<usize> {
+    if events.len() > config.telemetry_max_batch_size {
+        return Err(AppError::Validation(format!(
+            "batch of {} events exceeds the limit of {}",
+            events.len(),
+            config.telemetry_max_batch_size
+        )));
+    }
+    for event in &events {
+        validate_event(config, event)?;
+    }
+
+    let created_at = chrono::Utc::now().to_rfc3339();
+    db.with_write_conn(|conn| {
+        for event in &events {
+            let payload_json = serde_json::to_string(&event.payload)
+                .map_err(|e| AppError::Internal(format!("failed to serialize telemetry payload: {e}")))?;
+            conn.execute(
+                "INSERT INTO telemetry_events (id, tenant_id, event_type, player_id, payload, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                rusqlite::params![Uuid::new_v4().to_string(), tenant_id, event.event_type, event.player_id, payload_json, created_at],
+            )?;
+        }
+        Ok(())
+    })?;
+    Ok(events.len())
+}
+
+/// Counts events of `event_type`, grouped by the value of `group_by_field`
+/// in each event's JSON payload — e.g. `deaths_per_wave` groups
+/// `wave_death` events by their `wave` field. Only fields declared in that
+/// event type's schema may be grouped on, so this can't be used to probe
+/// arbitrary payload shapes callers didn't declare up front.
+pub fn aggregate_by_field(
+    db: &Db,
+    config: &Config,
+    tenant_id: &str,
+    event_type: &str,
+    group_by_field: &str,
+) -> AppResult<Vec<TelemetryAggregateBucket>> {
+    let schema = config
+        .telemetry_event_schemas
+        .iter()
+        .find(|s| s.event_type == event_type)
+        .ok_or_else(|| AppError::Validation(format!("unknown telemetry event type: {event_type}")))?;
+    if !schema.required_fields.iter().any(|f| f == group_by_field) {
+        return Err(AppError::Validation(format!("{group_by_field} is not a declared field of {event_type}")));
+    }
+
+    db.with_read_conn(|conn| {
+        let json_path = format!("$.{group_by_field}");
+        let mut stmt = conn.prepare(
+            "SELECT CAST(json_extract(payload, ?1) AS TEXT) AS group_value, COUNT(*) AS event_count
+             FROM telemetry_events
+             WHERE tenant_id = ?2 AND event_type = ?3
+             GROUP BY group_value
+             ORDER BY event_count DESC",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![json_path, tenant_id, event_type], |row| {
+            Ok(TelemetryAggregateBucket { group_value: row.get::<_, String>(0)?, event_count: row.get(1)? })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    })
+}
+
+/// Deletes events older than `retention_days`, called periodically by the
+/// scheduler. Substitutes for real table partitioning (SQLite has none) —
+/// dropping old rows outright rather than archiving them, since this is
+/// meant to be aggressive, low-value data.
+pub fn prune_expired(db: &Db, retention_days: i64) -> AppResult<usize> {
+    let cutoff = (chrono::Utc::now() - chrono::Duration::days(retention_days)).to_rfc3339();
+    db.with_write_conn(|conn| Ok(conn.execute("DELETE FROM telemetry_events WHERE created_at < ?1", rusqlite::params![cutoff])?))
+}
@@ -0,0 +1,103 @@
+use booty_hunt_core::{TideContributionResult, TideMetricDefinition, TideOmen, TideProgress};
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::db::Db;
+use crate::error::{AppError, AppResult};
+use crate::i18n;
+use crate::services::run_service::current_week_key;
+
+/// The published catalog `POST /api/tide/contribute` validates against —
+/// just `Config::tide_metrics` as-is, so the accepted keys, units, and goals
+/// a client sees are always exactly what the server will actually enforce.
+pub fn metrics(config: &Config) -> Vec<TideMetricDefinition> {
+    config.tide_metrics.clone()
+}
+
+/// This week's omens, with `name`/`description` resolved from `locale`
+/// against the embedded catalog. `locale` should already be the result of
+/// `i18n::negotiate` on the caller's `Accept-Language`.
+pub fn omens(config: &Config, locale: &str) -> Vec<TideOmen> {
+    config
+        .omens
+        .iter()
+        .map(|id| {
+            let name_key = format!("omen.{id}.name");
+            let description_key = format!("omen.{id}.description");
+            TideOmen {
+                id: id.clone(),
+                name: i18n::lookup(&name_key, locale),
+                name_key,
+                description: i18n::lookup(&description_key, locale),
+                description_key,
+            }
+        })
+        .collect()
+}
+
+fn find_metric<'a>(config: &'a Config, metric: &str) -> AppResult<&'a TideMetricDefinition> {
+    config
+        .tide_metrics
+        .iter()
+        .find(|m| m.key == metric)
+        .ok_or_else(|| AppError::Validation(format!("unknown tide metric: {metric}")))
+}
+
+/// Records one player's contribution toward this week's tide event, after
+/// checking `metric` is in the catalog and `amount` doesn't exceed that
+/// metric's `per_contribution_cap`. Returns the community's running total
+/// for the metric this week so the caller can show progress immediately.
+pub fn contribute(db: &Db, config: &Config, tenant_id: &str, player_id: &str, metric: &str, amount: i64) -> AppResult<TideContributionResult> {
+    let definition = find_metric(config, metric)?;
+    if amount <= 0 {
+        return Err(AppError::Validation("contribution amount must be positive".into()));
+    }
+    if amount > definition.per_contribution_cap {
+        return Err(AppError::Validation(format!(
+            "contribution of {amount} exceeds the per-contribution cap of {} for {metric}",
+            definition.per_contribution_cap
+        )));
+    }
+
+    let week_key = current_week_key();
+    let id = Uuid::new_v4().to_string();
+    let created_at = chrono::Utc::now().to_rfc3339();
+    let weekly_goal = definition.weekly_goal;
+
+    let week_total = db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO tide_contributions (id, tenant_id, player_id, week_key, metric, amount, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            rusqlite::params![id, tenant_id, player_id, week_key, metric, amount, created_at],
+        )?;
+        conn.query_row(
+            "SELECT COALESCE(SUM(amount), 0) FROM tide_contributions WHERE tenant_id = ?1 AND week_key = ?2 AND metric = ?3",
+            rusqlite::params![tenant_id, week_key, metric],
+            |row| row.get(0),
+        )
+        .map_err(Into::into)
+    })?;
+
+    Ok(TideContributionResult { metric: metric.to_string(), week_key, week_total, weekly_goal })
+}
+
+/// This week's running total against goal for every catalog metric, backed
+/// by the same `idx_tide_contributions_week_metric`-indexed sum `contribute`
+/// already does per metric on every write. Used by `admin::overview`.
+pub fn current_progress(db: &Db, config: &Config, tenant_id: &str) -> AppResult<Vec<TideProgress>> {
+    let week_key = current_week_key();
+    db.with_read_conn(|conn| {
+        config
+            .tide_metrics
+            .iter()
+            .map(|definition| -> AppResult<TideProgress> {
+                let week_total = conn.query_row(
+                    "SELECT COALESCE(SUM(amount), 0) FROM tide_contributions WHERE tenant_id = ?1 AND week_key = ?2 AND metric = ?3",
+                    rusqlite::params![tenant_id, week_key, definition.key],
+                    |row| row.get(0),
+                )?;
+                Ok(TideProgress { metric: definition.key.clone(), week_total, weekly_goal: definition.weekly_goal })
+            })
+            .collect()
+    })
+}
@@ -0,0 +1,97 @@
+//! Server-driven balance tuning values — enemy HP multipliers, loot rates,
+//! and the like — that a client reads at startup instead of baking them
+//! into a release. Values are opaque `serde_json::Value` on the server
+//! side, same simplification `community_event_service` makes for
+//! `modifiers`. Every write is versioned and archived to
+//! `tuning_value_history` so a bad patch can be understood (and manually
+//! reverted) after the fact.
+
+use booty_hunt_core::{SetTuningValueRequest, TuningHistoryEntry, TuningSnapshot, TuningValue};
+use rusqlite::{Connection, OptionalExtension};
+use uuid::Uuid;
+
+use crate::db::Db;
+use crate::error::AppResult;
+
+/// The overall tuning version right now — the highest per-key version, or
+/// `0` if no keys are set — for snapshotting onto a run at submission
+/// time. Takes `&Connection` so it can run inside another transaction, same
+/// as `community_event_service::active_ids_conn`.
+pub fn current_version_conn(conn: &Connection, tenant_id: &str) -> AppResult<i64> {
+    let version: Option<i64> =
+        conn.query_row("SELECT MAX(version) FROM tuning_values WHERE tenant_id = ?1", [tenant_id], |row| row.get(0))?;
+    Ok(version.unwrap_or(0))
+}
+
+fn deserialize_value(value_json: String) -> AppResult<serde_json::Value> {
+    serde_json::from_str(&value_json)
+        .map_err(|e| crate::error::AppError::Internal(format!("stored tuning_values.value_json is invalid: {e}")))
+}
+
+/// Every current key/value for `tenant_id`, with an overall `version` a
+/// client can compare against its cache before re-parsing the whole set —
+/// the highest per-key version among them, or `0` if no keys are set yet.
+pub fn snapshot(db: &Db, tenant_id: &str) -> AppResult<TuningSnapshot> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT key, value_json, version, updated_at FROM tuning_values WHERE tenant_id = ?1 ORDER BY key ASC",
+        )?;
+        let rows: Vec<(String, String, i64, String)> =
+            stmt.query_map([tenant_id], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?.collect::<Result<_, _>>()?;
+        let mut version = 0;
+        let mut values = Vec::with_capacity(rows.len());
+        for (key, value_json, key_version, updated_at) in rows {
+            version = version.max(key_version);
+            values.push(TuningValue { key, value: deserialize_value(value_json)?, version: key_version, updated_at });
+        }
+        Ok(TuningSnapshot { version, values })
+    })
+}
+
+/// Upserts `key`, bumping its version and appending the old-or-new value to
+/// `tuning_value_history` for later diffing.
+pub fn set_value(db: &Db, tenant_id: &str, key: &str, req: SetTuningValueRequest) -> AppResult<TuningValue> {
+    let value_json = serde_json::to_string(&req.value)
+        .map_err(|e| crate::error::AppError::Internal(format!("failed to serialize tuning value: {e}")))?;
+    let now = chrono::Utc::now().to_rfc3339();
+    let history_id = Uuid::new_v4().to_string();
+
+    let version = db.with_write_conn(|conn| {
+        let current_version: i64 = conn
+            .query_row(
+                "SELECT version FROM tuning_values WHERE tenant_id = ?1 AND key = ?2",
+                rusqlite::params![tenant_id, key],
+                |row| row.get(0),
+            )
+            .optional()?
+            .unwrap_or(0);
+        let version = current_version + 1;
+        conn.execute(
+            "INSERT INTO tuning_values (tenant_id, key, value_json, version, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT (tenant_id, key) DO UPDATE SET value_json = excluded.value_json, version = excluded.version, updated_at = excluded.updated_at",
+            rusqlite::params![tenant_id, key, value_json, version, now],
+        )?;
+        conn.execute(
+            "INSERT INTO tuning_value_history (id, tenant_id, key, value_json, version, changed_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![history_id, tenant_id, key, value_json, version, now],
+        )?;
+        Ok(version)
+    })?;
+
+    Ok(TuningValue { key: key.to_string(), value: req.value, version, updated_at: now })
+}
+
+/// `key`'s past values, most recent first.
+pub fn history(db: &Db, tenant_id: &str, key: &str) -> AppResult<Vec<TuningHistoryEntry>> {
+    db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT value_json, version, changed_at FROM tuning_value_history
+             WHERE tenant_id = ?1 AND key = ?2 ORDER BY version DESC",
+        )?;
+        let rows: Vec<(String, i64, String)> =
+            stmt.query_map(rusqlite::params![tenant_id, key], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?.collect::<Result<_, _>>()?;
+        rows.into_iter()
+            .map(|(value_json, version, changed_at)| Ok(TuningHistoryEntry { value: deserialize_value(value_json)?, version, changed_at }))
+            .collect()
+    })
+}
@@ -0,0 +1,104 @@
+//! Runs once at boot, after `Db::open` has applied any pending migrations,
+//! to catch the kind of drift that would otherwise surface as an opaque
+//! `AppError::Db` the first time some route happens to touch it: a table or
+//! index missing from a schema that was hand-edited outside `migrate()`, or
+//! a `BOOTY_HUNT_*` catalog env var that parses as JSON but is empty. None of
+//! these are fatal on their own — a deployment mid-migration-rollback or one
+//! that genuinely wants an empty season is legitimate — so this only ever
+//! collects human-readable problem strings for `/api/health` rather than
+//! panicking.  A future pass can promote specific checks to hard failures if
+//! operators would rather fail loudly than run degraded.
+
+use crate::config::Config;
+use crate::db::Db;
+
+/// Tables and indexes every migration up to this build is expected to have
+/// created. Kept as a flat list rather than derived from `MIGRATIONS` so a
+/// migration that's a no-op on a fresh db (e.g. one that only alters an
+/// existing table) doesn't need a matching entry here.
+const EXPECTED_TABLES: &[&str] = &[
+    "players",
+    "runs",
+    "cosmetic_items",
+    "player_cosmetics",
+    "player_equipped_cosmetics",
+    "device_tokens",
+    "notification_preferences",
+    "player_identities",
+    "api_keys",
+    "replay_downloads",
+    "run_kudos",
+    "weekly_digests",
+    "ratings",
+    "rulesets",
+    "season_progress",
+    "season_tier_claims",
+    "regattas",
+    "regatta_events",
+    "tape_upload_sessions",
+];
+
+const EXPECTED_INDEXES: &[&str] = &[
+    "idx_runs_week_score",
+    "idx_runs_player",
+    "idx_api_keys_player",
+    "idx_run_kudos_run",
+    "idx_runs_normalized_score",
+    "idx_ratings_rating",
+    "idx_runs_stealth",
+    "idx_runs_ruleset",
+    "idx_regattas_week",
+    "idx_regatta_events_tenant",
+    "idx_regattas_week_track",
+];
+
+/// Checks the live schema against `EXPECTED_TABLES`/`EXPECTED_INDEXES` and
+/// the parsed config catalogs for obvious emptiness, returning one string
+/// per problem found. Call after `Db::open` (so migrations have already had
+/// a chance to run) and log/expose whatever comes back — see
+/// `main::run_startup_checks`.
+pub fn run(db: &Db, config: &Config) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    match db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare("SELECT name FROM sqlite_master WHERE type = 'table'")?;
+        let names: Result<Vec<String>, _> = stmt.query_map([], |row| row.get(0))?.collect();
+        Ok(names?)
+    }) {
+        Ok(tables) => {
+            for expected in EXPECTED_TABLES {
+                if !tables.iter().any(|t| t == expected) {
+                    problems.push(format!("missing expected table: {expected}"));
+                }
+            }
+        }
+        Err(err) => problems.push(format!("could not read sqlite_master tables: {err}")),
+    }
+
+    match db.with_read_conn(|conn| {
+        let mut stmt = conn.prepare("SELECT name FROM sqlite_master WHERE type = 'index'")?;
+        let names: Result<Vec<String>, _> = stmt.query_map([], |row| row.get(0))?.collect();
+        Ok(names?)
+    }) {
+        Ok(indexes) => {
+            for expected in EXPECTED_INDEXES {
+                if !indexes.iter().any(|i| i == expected) {
+                    problems.push(format!("missing expected index: {expected}"));
+                }
+            }
+        }
+        Err(err) => problems.push(format!("could not read sqlite_master indexes: {err}")),
+    }
+
+    if config.season_tiers.is_empty() {
+        problems.push("season_tiers catalog is empty — season progress claims will never succeed".to_string());
+    }
+    if config.regatta_tracks.is_empty() {
+        problems.push("regatta_tracks catalog is empty — GET /api/regatta will always return no tracks".to_string());
+    }
+    if config.class_score_multipliers.is_empty() {
+        problems.push("class_score_multipliers is empty — every ship class will fall back to a 1.0 multiplier".to_string());
+    }
+
+    problems
+}
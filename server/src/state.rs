@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{
+    blob::BlobStore, config::ConfigHandle, db::Db, hooks::RunHook, identity::IdentityProvider,
+    leaderboard_delta::LeaderboardDeltaLog, notifications::NotificationProvider, popularity::PopularityCounters,
+    presence::PresenceTracker, rate_limit::PollLimiter, request_metrics::RequestMetrics, scheduler_status::SchedulerStatus,
+    transfer_metrics::TransferMetrics,
+};
+
+const PRESENCE_TTL: Duration = Duration::from_secs(120);
+
+#[derive(Clone)]
+pub struct AppState {
+    pub db: Arc<Db>,
+    pub config: Arc<ConfigHandle>,
+    pub presence: Arc<PresenceTracker>,
+    pub notification_providers: Arc<Vec<Box<dyn NotificationProvider>>>,
+    pub identity_providers: Arc<Vec<Box<dyn IdentityProvider>>>,
+    pub run_hooks: Arc<Vec<Box<dyn RunHook>>>,
+    /// Backend new ghost tapes are written to. `None` keeps them inline in
+    /// `runs.ghost_tape`, as always; set by `main` from `Config`, or
+    /// directly by deployments embedding this server as a library.
+    pub tape_blob_store: Option<Arc<dyn BlobStore>>,
+    pub popularity: Arc<PopularityCounters>,
+    pub poll_limiter: Arc<PollLimiter>,
+    pub leaderboard_deltas: Arc<LeaderboardDeltaLog>,
+    pub scheduler_status: Arc<SchedulerStatus>,
+    pub request_metrics: Arc<RequestMetrics>,
+    /// Ghost tape download counts/bytes, for `GET /api/admin/ghost-transfer-stats`.
+    pub ghost_transfer_metrics: Arc<TransferMetrics>,
+    pub started_at: Instant,
+    /// Problems found by `startup_check::run` at boot — missing tables/
+    /// indexes, empty config catalogs. Empty means the check passed (or
+    /// hasn't run, for embedders that construct `AppState` directly rather
+    /// than through `main`). Surfaced via `/api/health` rather than causing
+    /// a refusal to start, since every check here has a plausible legitimate
+    /// cause; see the module doc for the reasoning.
+    pub startup_problems: Arc<Vec<String>>,
+    /// Unique per-process id used to hold `scheduler_locks` rows — lets one
+    /// instance tell its own lease apart from another instance's when two
+    /// servers run against the same database. Regenerated on every restart;
+    /// there's no need for it to be stable across process lifetimes.
+    pub instance_id: String,
+}
+
+impl AppState {
+    pub fn new(db: Arc<Db>, config: Arc<ConfigHandle>) -> Self {
+        AppState {
+            db,
+            config,
+            instance_id: uuid::Uuid::new_v4().to_string(),
+            presence: Arc::new(PresenceTracker::new(PRESENCE_TTL)),
+            notification_providers: Arc::new(Vec::new()),
+            identity_providers: Arc::new(Vec::new()),
+            run_hooks: Arc::new(Vec::new()),
+            tape_blob_store: None,
+            popularity: Arc::new(PopularityCounters::new()),
+            poll_limiter: Arc::new(PollLimiter::new()),
+            leaderboard_deltas: Arc::new(LeaderboardDeltaLog::new()),
+            scheduler_status: Arc::new(SchedulerStatus::new()),
+            request_metrics: Arc::new(RequestMetrics::new()),
+            ghost_transfer_metrics: Arc::new(TransferMetrics::new()),
+            started_at: Instant::now(),
+            startup_problems: Arc::new(Vec::new()),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Multi-tenant support: one server instance can host isolated leaderboards
+//! for several communities (modded servers, tournaments) by scoping rows
+//! with a `tenant_id`. This is an early cut — only the run submission and
+//! leaderboard read paths are tenant-scoped so far; other subsystems still
+//! assume `DEFAULT_TENANT` until they need isolation too.
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+
+use crate::error::AppError;
+
+pub const DEFAULT_TENANT: &str = "default";
+
+/// The tenant a request belongs to, resolved from `X-Tenant-Id` (falling
+/// back to `DEFAULT_TENANT` for single-community deployments that never set
+/// the header).
+pub struct TenantId(pub String);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for TenantId {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let tenant = parts
+            .headers
+            .get("x-tenant-id")
+            .and_then(|v| v.to_str().ok())
+            .filter(|v| !v.is_empty())
+            .unwrap_or(DEFAULT_TENANT)
+            .to_string();
+        Ok(TenantId(tenant))
+    }
+}
@@ -0,0 +1,48 @@
+//! Shared fixtures for `#[cfg(test)]` modules across `services` — a real
+//! SQLite `Db` on a scratch temp-file path (WAL mode doesn't behave on
+//! `:memory:`) instead of mocking the database layer, so these tests exercise
+//! the actual queries and transactions they're meant to guard.
+
+use crate::db::Db;
+
+/// Opens a fresh `Db` backed by a unique temp file, migrated like any real
+/// deployment. The file (and its `-wal`/`-shm` siblings) is left on disk
+/// under the OS temp dir rather than cleaned up — cheap, and avoids a `Drop`
+/// racing the `Mutex<Connection>` it guards during teardown.
+pub(crate) fn test_db() -> Db {
+    let path = std::env::temp_dir().join(format!("booty-hunt-test-{}.db", uuid::Uuid::new_v4()));
+    Db::open(path.to_str().unwrap(), 200, 3, 25).expect("open test db")
+}
+
+/// Inserts the minimal `players` row `submit_run`/lookups need to resolve a
+/// player id — `display_name`/`token` content doesn't matter to any test,
+/// only that the row exists.
+pub(crate) fn insert_player(db: &Db, tenant_id: &str, player_id: &str) {
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO players (id, token, display_name, created_at, tenant_id) VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![player_id, format!("token-{player_id}"), format!("Player {player_id}"), chrono::Utc::now().to_rfc3339(), tenant_id],
+        )?;
+        Ok(())
+    })
+    .expect("insert test player");
+}
+
+/// Inserts a minimal `runs` row with `run_id`/`score` set and every other
+/// column at an arbitrary-but-valid placeholder value — for tests exercising
+/// selection/mutation over existing runs (bulk moderation, leaderboard
+/// queries) rather than the submission pipeline itself. Does not insert the
+/// owning `players` row; callers that need one should call `insert_player`
+/// first.
+pub(crate) fn insert_run(db: &Db, tenant_id: &str, run_id: &str, player_id: &str, week_key: &str, score: i64) {
+    db.with_write_conn(|conn| {
+        conn.execute(
+            "INSERT INTO runs (id, player_id, week_key, seed, ship_class, doctrine_id, score, waves, damage_dealt,
+                                max_combo, time_played, max_heat, victory, created_at, tenant_id)
+             VALUES (?1, ?2, ?3, 0, 'sloop', 'boarding', ?4, 15, 0, 0, 600, 0, 1, ?5, ?6)",
+            rusqlite::params![run_id, player_id, week_key, score, chrono::Utc::now().to_rfc3339(), tenant_id],
+        )?;
+        Ok(())
+    })
+    .expect("insert test run");
+}
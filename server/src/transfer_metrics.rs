@@ -0,0 +1,38 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Process-wide ghost tape download/byte counters, bumped once per
+/// successful `GET /api/runs/:run_id/ghost` response. Backs
+/// `GET /api/admin/ghost-transfer-stats` so an operator can see whether
+/// bandwidth is dominated by a few large mirrors before reaching for
+/// `ghost_download_ip_rate_limit_budget`. Lost on restart like every other
+/// in-memory tracker here (`PollLimiter`, `RequestMetrics`).
+pub struct TransferMetrics {
+    downloads: AtomicU64,
+    bytes: AtomicU64,
+}
+
+pub struct TransferMetricsSnapshot {
+    pub downloads: u64,
+    pub bytes: u64,
+}
+
+impl TransferMetrics {
+    pub fn new() -> Self {
+        TransferMetrics { downloads: AtomicU64::new(0), bytes: AtomicU64::new(0) }
+    }
+
+    pub fn record(&self, bytes: u64) {
+        self.downloads.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> TransferMetricsSnapshot {
+        TransferMetricsSnapshot { downloads: self.downloads.load(Ordering::Relaxed), bytes: self.bytes.load(Ordering::Relaxed) }
+    }
+}
+
+impl Default for TransferMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}